@@ -0,0 +1,75 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use ion_rs::lazy::encoder::annotate::Annotate;
+use ion_rs::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+use ion_rs::IonResult;
+
+// The number of `foo::1` values written by each benchmark.
+const NUM_VALUES: usize = 1_000_000;
+
+// The symbol ID used for the annotation `foo`.
+const FOO: usize = 10;
+
+// A global allocator that counts the allocations it makes, which allows the benchmark to report
+// how much memory the writer requests in addition to how long it takes.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES_ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES_ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// Writes `NUM_VALUES` top-level `foo::1` values without flushing until the end of the stream.
+fn write_annotated_scalars() -> IonResult<Vec<u8>> {
+    let mut writer = LazyRawBinaryWriter_1_0::new(Vec::with_capacity(NUM_VALUES * 4 + 4))?;
+    for _ in 0..NUM_VALUES {
+        writer.write(1i64.annotated_with(&[FOO]))?;
+    }
+    Ok(writer.take_buffer())
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    // Report the allocations made while writing the stream once before measuring its speed.
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes_before = BYTES_ALLOCATED.load(Ordering::Relaxed);
+    let encoded = write_annotated_scalars().unwrap();
+    println!(
+        "writing {NUM_VALUES} `foo::1` values made {} allocations totaling {} bytes",
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations_before,
+        BYTES_ALLOCATED.load(Ordering::Relaxed) - bytes_before,
+    );
+
+    let mut group = c.benchmark_group("binary 1.0 annotated scalars");
+    group.bench_function("write foo::1", |b| {
+        b.iter(|| {
+            let actual = write_annotated_scalars().unwrap();
+            assert_eq!(black_box(actual).len(), encoded.len());
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
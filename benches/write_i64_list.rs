@@ -0,0 +1,75 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::prelude::StdRng;
+use rand::{distributions::Uniform, Rng, SeedableRng};
+
+use ion_rs::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+use ion_rs::lazy::encoder::value_writer::{SequenceWriter, ValueWriter};
+use ion_rs::lazy::encoder::write_as_ion::WriteAsIonValue;
+use ion_rs::IonResult;
+
+// Rather than store a set of test values, we hardcode a seed value and generate the same set
+// on each run.
+const RNG_SEED: u64 = 1024;
+
+// The number of ints in the list written by each benchmark.
+const NUM_VALUES: usize = 1_000_000;
+
+// A list of ints that is written one value at a time.
+struct PerElement<'a>(&'a [i64]);
+
+impl<'a> WriteAsIonValue for PerElement<'a> {
+    fn write_as_ion_value<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        writer.write_list(|list| {
+            for value in self.0 {
+                list.write_i64(*value)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+// A list of ints that is written in a single call to `write_i64_slice`.
+struct Sliced<'a>(&'a [i64]);
+
+impl<'a> WriteAsIonValue for Sliced<'a> {
+    fn write_as_ion_value<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        writer.write_list(|list| {
+            list.write_i64_slice(self.0)?;
+            Ok(())
+        })
+    }
+}
+
+fn write_to_vec<V: WriteAsIonValue>(value: V) -> IonResult<Vec<u8>> {
+    let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+    writer.write(value)?;
+    Ok(writer.take_buffer())
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(RNG_SEED);
+    let range = Uniform::new(i64::MIN, i64::MAX);
+    let values: Vec<i64> = (0..NUM_VALUES).map(|_| rng.sample(range)).collect();
+
+    // Confirm that both approaches produce the same encoding before measuring them.
+    let expected = write_to_vec(PerElement(&values)).unwrap();
+    assert_eq!(expected, write_to_vec(Sliced(&values)).unwrap());
+
+    let mut group = c.benchmark_group("binary 1.0 list of i64");
+    group.bench_function("write each i64", |b| {
+        b.iter(|| {
+            let encoded = write_to_vec(black_box(PerElement(&values))).unwrap();
+            assert_eq!(encoded.len(), expected.len());
+        })
+    });
+    group.bench_function("write_i64_slice", |b| {
+        b.iter(|| {
+            let encoded = write_to_vec(black_box(Sliced(&values))).unwrap();
+            assert_eq!(encoded.len(), expected.len());
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
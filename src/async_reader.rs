@@ -0,0 +1,236 @@
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, ReadBuf};
+
+use crate::binary::non_blocking::raw_binary_reader::RawBinaryReader;
+use crate::raw_reader::BufferedRawReader;
+use crate::result::IonResult;
+use crate::text::non_blocking::raw_text_reader::RawTextReader;
+use crate::IonError;
+
+pub type AsyncRawTextReader<S> = AsyncIonReader<RawTextReader<Vec<u8>>, S>;
+pub type AsyncRawBinaryReader<S> = AsyncIonReader<RawBinaryReader<Vec<u8>>, S>;
+
+/// The AsyncIonReader wraps a non-blocking RawReader that implements the BufferedRawReader trait,
+/// feeding it bytes from a tokio [`AsyncRead`] source as they become available.
+///
+/// Each call to [`next`](Self::next) (or [`poll_next`](Self::poll_next)) advances the wrapped
+/// reader. When the wrapped reader needs more input to finish parsing the next item, the adapter
+/// reads another chunk from the source; if the source has no data ready, `poll_next` returns
+/// [`Poll::Pending`] and the reader resumes where it left off once the task is woken. The wrapped
+/// reader can be accessed via [`get_ref`](Self::get_ref) and [`get_mut`](Self::get_mut) to read
+/// the current value or step into a container.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[tokio::main(flavor = "current_thread")]
+///# async fn main() -> IonResult<()> {
+/// use ion_rs::{AsyncRawTextReader, IonReader, IonType, RawStreamItem};
+///
+/// let source: &[u8] = b"1 2 3";
+/// let mut reader = AsyncRawTextReader::new(source);
+/// let mut sum = 0;
+/// while let RawStreamItem::Value(IonType::Int) = reader.next().await? {
+///     sum += reader.get_mut().read_i64()?;
+/// }
+/// assert_eq!(sum, 6);
+///# Ok(())
+///# }
+/// ```
+pub struct AsyncIonReader<R: BufferedRawReader, S: AsyncRead + Unpin> {
+    source: S,
+    reader: R,
+    read_buffer: Vec<u8>,
+}
+
+const READER_DEFAULT_BUFFER_CAPACITY: usize = 1024 * 4;
+
+impl<R: BufferedRawReader, S: AsyncRead + Unpin> AsyncIonReader<R, S> {
+    pub fn new(source: S) -> Self {
+        Self::new_with_size(source, READER_DEFAULT_BUFFER_CAPACITY)
+    }
+
+    /// Constructs an `AsyncIonReader` that reads up to `size` bytes from `source` at a time.
+    pub fn new_with_size(source: S, size: usize) -> Self {
+        Self {
+            source,
+            reader: Vec::with_capacity(size).into(),
+            read_buffer: vec![0u8; size.max(1)],
+        }
+    }
+
+    /// Advances the wrapped reader to the next item in the stream, waiting for more bytes from
+    /// the source as needed.
+    pub async fn next(&mut self) -> IonResult<R::Item> {
+        poll_fn(|cx| self.poll_next(cx)).await
+    }
+
+    /// Attempts to advance the wrapped reader to the next item in the stream. If the reader
+    /// needs more input and the source has none ready, returns [`Poll::Pending`] and arranges
+    /// for the current task to be woken when the source becomes readable.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<IonResult<R::Item>> {
+        self.poll_until_complete(cx, |reader| reader.next())
+    }
+
+    /// Steps out of the wrapped reader's current container, waiting for more bytes from the
+    /// source as needed. (Stepping out of a text container requires reading everything that
+    /// remains in it.)
+    pub async fn step_out(&mut self) -> IonResult<()> {
+        poll_fn(|cx| self.poll_until_complete(cx, |reader| reader.step_out())).await
+    }
+
+    /// Calls `operation` on the wrapped reader, feeding it more bytes from the source and
+    /// retrying each time it reports that its input is incomplete.
+    fn poll_until_complete<T>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut operation: impl FnMut(&mut R) -> IonResult<T>,
+    ) -> Poll<IonResult<T>> {
+        loop {
+            let result = operation(&mut self.reader);
+            if !matches!(result, Err(IonError::Incomplete { .. })) {
+                return Poll::Ready(result);
+            }
+            let mut read_buffer = ReadBuf::new(&mut self.read_buffer);
+            match Pin::new(&mut self.source).poll_read(cx, &mut read_buffer) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error.into())),
+                Poll::Ready(Ok(())) => {}
+            }
+            let bytes = read_buffer.filled();
+            if bytes.is_empty() {
+                // The source is exhausted. Mark the stream as complete and retry once so the
+                // reader can surface any value it was holding back; if it was already marked
+                // complete, the incomplete data is an error.
+                if self.reader.is_stream_complete() {
+                    return Poll::Ready(result);
+                }
+                self.reader.stream_complete();
+            } else {
+                self.reader.append_bytes(bytes)?;
+            }
+        }
+    }
+
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the wrapped reader, which can be used to read the value
+    /// on which it is currently positioned.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Consumes the `AsyncIonReader`, returning the wrapped reader and the source.
+    pub fn into_inner(self) -> (R, S) {
+        (self.reader, self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use tokio::io::{AsyncRead, ReadBuf};
+
+    use super::*;
+    use crate::ion_reader::IonReader;
+    use crate::raw_reader::RawStreamItem;
+    use crate::IonType;
+
+    /// A source that yields `data` one byte at a time, returning `Poll::Pending` (and immediately
+    /// waking the task) before every byte.
+    struct TrickleSource {
+        data: Vec<u8>,
+        position: usize,
+        ready: bool,
+    }
+
+    impl TrickleSource {
+        fn new(data: &[u8]) -> Self {
+            Self {
+                data: data.to_vec(),
+                position: 0,
+                ready: false,
+            }
+        }
+    }
+
+    impl AsyncRead for TrickleSource {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            if !self.ready {
+                self.ready = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            self.ready = false;
+            if let Some(byte) = self.data.get(self.position).copied() {
+                buf.put_slice(&[byte]);
+                self.position += 1;
+            }
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn read_text_in_chunks() -> IonResult<()> {
+        let mut reader = AsyncRawTextReader::new(TrickleSource::new(b"foo [1, 2] 3"));
+        assert_eq!(reader.next().await?, RawStreamItem::Value(IonType::Symbol));
+        assert_eq!(reader.get_mut().read_symbol()?.text(), Some("foo"));
+        assert_eq!(reader.next().await?, RawStreamItem::Value(IonType::List));
+        reader.get_mut().step_in()?;
+        assert_eq!(reader.next().await?, RawStreamItem::Value(IonType::Int));
+        assert_eq!(reader.get_mut().read_i64()?, 1);
+        reader.step_out().await?;
+        assert_eq!(reader.next().await?, RawStreamItem::Value(IonType::Int));
+        assert_eq!(reader.get_mut().read_i64()?, 3);
+        assert_eq!(reader.next().await?, RawStreamItem::Nothing);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_binary_in_chunks() -> IonResult<()> {
+        let data = [0xE0, 0x01, 0x00, 0xEA, 0x21, 0x05, 0x83, 0x66, 0x6F, 0x6F];
+        let mut reader = AsyncRawBinaryReader::new_with_size(TrickleSource::new(&data), 2);
+        assert_eq!(reader.next().await?, RawStreamItem::VersionMarker(1, 0));
+        assert_eq!(reader.next().await?, RawStreamItem::Value(IonType::Int));
+        assert_eq!(reader.get_mut().read_i64()?, 5);
+        assert_eq!(reader.next().await?, RawStreamItem::Value(IonType::String));
+        assert_eq!(reader.get_mut().read_str()?, "foo");
+        assert_eq!(reader.next().await?, RawStreamItem::Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn poll_next_is_pending_until_bytes_arrive() {
+        let mut reader = AsyncRawTextReader::new(TrickleSource::new(b"1 "));
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(reader.poll_next(&mut cx).is_pending());
+        let item = loop {
+            if let Poll::Ready(item) = reader.poll_next(&mut cx) {
+                break item;
+            }
+        };
+        assert_eq!(item.unwrap(), RawStreamItem::Value(IonType::Int));
+    }
+
+    fn noop_waker() -> Waker {
+        struct NoopWaker;
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+        Arc::new(NoopWaker).into()
+    }
+}
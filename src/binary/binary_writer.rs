@@ -1,20 +1,43 @@
 use crate::binary::raw_binary_writer::{RawBinaryWriter, RawBinaryWriterBuilder};
+use crate::constants::v1_0;
 use crate::constants::v1_0::system_symbol_ids;
 use crate::ion_writer::IonWriter;
 use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
 use crate::result::{IonFailure, IonResult};
 use crate::symbol_table::SymbolTable;
-use crate::{Decimal, Int, IonType, SymbolId, Timestamp};
+use crate::{Decimal, Int, IonType, Symbol, SymbolId, Timestamp};
 use delegate::delegate;
+use std::collections::HashMap;
 use std::io::Write;
+use std::sync::Arc;
 
 pub struct BinaryWriterBuilder {
-    // Nothing yet
+    shared_symbol_table: Option<Arc<SymbolTable>>,
 }
 
 impl BinaryWriterBuilder {
     pub fn new() -> Self {
-        BinaryWriterBuilder {}
+        BinaryWriterBuilder {
+            shared_symbol_table: None,
+        }
+    }
+
+    /// Configures the writer to use `symbol_table` as the read-only base of its symbol table.
+    /// The same `Arc` can be handed to any number of writers (including writers on other threads)
+    /// without copying the table or re-interning its symbols.
+    ///
+    /// The shared table's symbol IDs are used as-is: a symbol at SID `n` in `symbol_table` is
+    /// written as `$n`. Symbols that are not in the shared table are appended to a small table
+    /// owned by the writer, and the local symbol at index `i` is assigned SID
+    /// `symbol_table.len() + i`. Because every `SymbolTable` begins with the system symbols,
+    /// this is the same numbering a reader produces when the shared symbols are declared in the
+    /// stream's first local symbol table.
+    ///
+    /// The shared table's non-system symbols are declared in the output stream the first time the
+    /// writer is flushed so that the stream can be read without out-of-band knowledge of them.
+    pub fn with_shared_symbol_table(mut self, symbol_table: Arc<SymbolTable>) -> Self {
+        self.shared_symbol_table = Some(symbol_table);
+        self
     }
 
     pub fn build<W: Write>(self, sink: W) -> IonResult<BinaryWriter<W>> {
@@ -22,10 +45,15 @@ impl BinaryWriterBuilder {
         let symbol_table_writer = RawBinaryWriterBuilder::new().build(Vec::new())?;
         // TODO: Track whether we've written an IVM and emit it at flush time instead
         raw_writer.write_ion_version_marker(1, 0)?;
+        let shared_symbol_table = self
+            .shared_symbol_table
+            .unwrap_or_else(|| Arc::new(SymbolTable::new()));
+        // Any symbols in the shared table beyond the system symbols have not been declared yet.
+        let num_pending_symbols = shared_symbol_table.len() - v1_0::SYSTEM_SYMBOLS.len();
         let binary_writer = BinaryWriter {
             raw_writer,
-            symbol_table: Default::default(),
-            num_pending_symbols: 0,
+            symbol_table: WriterSymbolTable::new(shared_symbol_table),
+            num_pending_symbols,
             symbol_table_writer,
         };
         Ok(binary_writer)
@@ -38,13 +66,72 @@ impl Default for BinaryWriterBuilder {
     }
 }
 
+/// The symbols known to a [`BinaryWriter`]: a read-only table that may be shared with other
+/// writers, followed by the symbols that this writer has interned itself.
+///
+/// SIDs `0..shared.len()` refer to the shared table. The local symbol at index `i` of
+/// `local_symbols` has SID `shared.len() + i`.
+struct WriterSymbolTable {
+    shared: Arc<SymbolTable>,
+    local_symbols: Vec<Symbol>,
+    local_ids_by_text: HashMap<Symbol, SymbolId>,
+}
+
+impl WriterSymbolTable {
+    fn new(shared: Arc<SymbolTable>) -> Self {
+        WriterSymbolTable {
+            shared,
+            local_symbols: Vec::new(),
+            local_ids_by_text: HashMap::new(),
+        }
+    }
+
+    fn sid_for(&self, text: &str) -> Option<SymbolId> {
+        self.shared
+            .sid_for(&text)
+            .or_else(|| self.local_ids_by_text.get(text).copied())
+    }
+
+    fn intern(&mut self, text: &str) -> SymbolId {
+        if let Some(sid) = self.sid_for(text) {
+            return sid;
+        }
+        let sid = self.len();
+        let symbol = Symbol::shared(Arc::from(text));
+        self.local_symbols.push(symbol.clone());
+        self.local_ids_by_text.insert(symbol, sid);
+        sid
+    }
+
+    fn sid_is_valid(&self, sid: SymbolId) -> bool {
+        sid < self.len()
+    }
+
+    fn len(&self) -> usize {
+        self.shared.len() + self.local_symbols.len()
+    }
+
+    /// Returns an iterator over the symbols whose SIDs are greater than or equal to `start`.
+    fn symbols_tail(&self, start: SymbolId) -> impl Iterator<Item = &Symbol> {
+        let shared_symbols = self.shared.symbols();
+        let shared_tail = &shared_symbols[start.min(shared_symbols.len())..];
+        let local_tail = &self.local_symbols[start.saturating_sub(shared_symbols.len())..];
+        shared_tail.iter().chain(local_tail.iter())
+    }
+}
+
 /**
  * An application-level binary Ion writer. This writer manages a symbol table and so can convert
  * symbol IDs to their corresponding text.
+ *
+ * Symbols that are interned while writing values are not defined in the output immediately.
+ * Instead, they accumulate until the next call to `flush()`, which writes a single local symbol
+ * table appending all of them followed by the values that were buffered since the previous flush.
+ * Values are never written to the output before the symbol table that defines their symbols.
  */
 pub struct BinaryWriter<W: Write> {
     raw_writer: RawBinaryWriter<W>,
-    symbol_table: SymbolTable,
+    symbol_table: WriterSymbolTable,
     // The number of symbols that have been added to the in-memory symbol table but
     // whose definitions have not yet been written to the output stream.
     num_pending_symbols: usize,
@@ -57,7 +144,7 @@ pub struct BinaryWriter<W: Write> {
 
 impl<W: Write> BinaryWriter<W> {
     fn get_or_create_symbol_id(&mut self, text: &str) -> SymbolId {
-        if let Some(symbol_id) = self.symbol_table.sid_for(&text) {
+        if let Some(symbol_id) = self.symbol_table.sid_for(text) {
             // If the provided text is in the symbol table, use the associated symbol ID...
             symbol_id
         } else {
@@ -165,6 +252,14 @@ impl<W: Write> IonWriter for BinaryWriter<W> {
     }
 
     fn flush(&mut self) -> IonResult<()> {
+        // The raw writer cannot flush its buffered values while it is in a container. Check this
+        // before writing a symbol table so that one is not emitted without the values it defines
+        // symbols for.
+        if self.depth() > 0 {
+            return IonResult::illegal_operation(
+                "Cannot call flush() while the writer is positioned within a container.",
+            );
+        }
         // Check to see if there are any pending symbols.
         if self.num_pending_symbols > 0 {
             self.write_symbol_table_for_pending_symbols()?;
@@ -201,8 +296,10 @@ impl<W: Write> IonWriter for BinaryWriter<W> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::blocking_reader::BlockingRawBinaryReader;
     use crate::ion_reader::IonReader;
     use crate::reader::ReaderBuilder;
+    use crate::system_reader::{SystemReader, SystemStreamItem};
 
     use crate::reader::StreamItem::Value;
 
@@ -262,4 +359,90 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn pending_symbols_are_batched_at_flush() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        binary_writer.write_symbol("foo")?;
+        binary_writer.set_annotations(["bar"]);
+        binary_writer.write_i64(1)?;
+        binary_writer.step_in(IonType::Struct)?;
+        binary_writer.set_field_name("baz");
+        binary_writer.write_symbol("foo")?;
+        // Flushing inside a container fails without writing a symbol table.
+        assert!(binary_writer.flush().is_err());
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+        binary_writer.write_symbol("quux")?;
+        binary_writer.flush()?;
+        drop(binary_writer);
+
+        let mut reader = SystemReader::new(BlockingRawBinaryReader::new(buffer)?);
+        let mut items = Vec::new();
+        loop {
+            match reader.next()? {
+                SystemStreamItem::Nothing => break,
+                item => items.push(item),
+            }
+        }
+        // Each flush wrote a single symbol table ahead of the values that use its symbols.
+        assert_eq!(
+            items,
+            vec![
+                SystemStreamItem::VersionMarker(1, 0),
+                SystemStreamItem::SymbolTableValue(IonType::Struct),
+                SystemStreamItem::Value(IonType::Symbol),
+                SystemStreamItem::Value(IonType::Int),
+                SystemStreamItem::Value(IonType::Struct),
+                SystemStreamItem::SymbolTableValue(IonType::Struct),
+                SystemStreamItem::Value(IonType::Symbol),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table() -> IonResult<()> {
+        let mut symbol_table = SymbolTable::new();
+        let foo_sid = symbol_table.intern("foo");
+        symbol_table.intern("bar");
+        let shared = Arc::new(symbol_table);
+
+        let local_symbols = ["baz", "quux"];
+        let mut buffers = vec![Vec::new(); local_symbols.len()];
+        let mut writers = buffers
+            .iter_mut()
+            .map(|buffer| {
+                BinaryWriterBuilder::new()
+                    .with_shared_symbol_table(Arc::clone(&shared))
+                    .build(buffer)
+            })
+            .collect::<IonResult<Vec<_>>>()?;
+        // Every writer holds a reference to the same table rather than a copy of it.
+        assert_eq!(Arc::strong_count(&shared), writers.len() + 1);
+        for writer in &writers {
+            assert!(Arc::ptr_eq(&writer.symbol_table.shared, &shared));
+        }
+
+        for (binary_writer, local_symbol) in writers.iter_mut().zip(local_symbols) {
+            // Symbol IDs from the shared table can be written directly.
+            binary_writer.write_symbol(foo_sid)?;
+            binary_writer.write_symbol("bar")?;
+            binary_writer.write_symbol(local_symbol)?;
+            binary_writer.flush()?;
+        }
+        drop(writers);
+
+        for (buffer, local_symbol) in buffers.into_iter().zip(local_symbols) {
+            let mut reader = ReaderBuilder::new().build(buffer)?;
+            for expected in ["foo", "bar", local_symbol] {
+                assert_eq!(Value(IonType::Symbol), reader.next()?);
+                assert_eq!(expected, reader.read_symbol()?);
+            }
+            // The local symbol was appended after the shared symbols.
+            assert_eq!(reader.symbol_table().sid_for(&local_symbol), Some(12));
+        }
+        Ok(())
+    }
 }
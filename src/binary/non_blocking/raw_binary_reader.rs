@@ -1320,12 +1320,16 @@ impl<'a, A: AsRef<[u8]>> TxReader<'a, A> {
         // If this value was annotated, make sure that the length declared in the header matches
         // the one that was declared in the preceding annotations wrapper.
         if let Some(expected_length) = expected_length {
-            if expected_length
-                != self.encoded_value.header_length() + self.encoded_value.value_length()
-            {
-                return IonResult::decoding_error(
-                    "annotations wrapper length did not align with value length",
-                );
+            let actual_length =
+                self.encoded_value.header_length() + self.encoded_value.value_length();
+            if expected_length != actual_length {
+                let wrapper_offset = self.encoded_value.header_offset
+                    - self.encoded_value.annotations_header_length as usize;
+                return IonResult::decoding_error(format!(
+                    "annotations wrapper length mismatch at offset {wrapper_offset}: the wrapper \
+                     declared a {expected_length}-byte value but the value was {actual_length} \
+                     bytes long"
+                ));
             }
         }
 
@@ -1388,10 +1392,16 @@ impl<'a, A: AsRef<[u8]>> TxReader<'a, A> {
             return IonResult::decoding_error("found an annotations wrapper with no annotations");
         }
 
-        // Validate that the annotated value is not missing.
+        // Validate that the annotations sequence fits within the wrapper's declared length.
         let expected_value_length = annotations_and_value_length
-            - annotations_length.size_in_bytes()
-            - annotations_length.value();
+            .checked_sub(annotations_length.size_in_bytes() + annotations_length.value())
+            .ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "annotations wrapper length mismatch at offset {initial_consumed}: the \
+                     annotations sequence is longer than the wrapper's declared length \
+                     ({annotations_and_value_length})"
+                ))
+            })?;
 
         if expected_value_length == 0 {
             return IonResult::decoding_error("found an annotation wrapper with no value");
@@ -2083,6 +2093,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_annotation_wrapper_length_mismatch() -> IonResult<()> {
+        let streams: &[&[u8]] = &[
+            // The wrapper declares a 1-byte value, but the value is 2 bytes long
+            &[0x10, 0xE3, 0x81, 0x84, 0x21, 0x01],
+            // The annotations sequence is longer than the wrapper's declared length
+            &[0x10, 0xE3, 0x85, 0x84, 0x84, 0x84, 0x84, 0x84, 0x10],
+        ];
+        for ion_data in streams {
+            let mut cursor = RawBinaryReader::new(*ion_data);
+            assert_eq!(RawStreamItem::Value(IonType::Bool), cursor.next()?);
+            let error = cursor
+                .next()
+                .expect_err("annotations wrapper should be rejected");
+            assert!(
+                error
+                    .to_string()
+                    .contains("annotations wrapper length mismatch at offset 1"),
+                "{error}"
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_incomplete_annotation_wrapper() -> IonResult<()> {
         // This test ensures that if we reach the end of the buffer while processing the annotation
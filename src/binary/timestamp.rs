@@ -5,11 +5,13 @@ use std::ops::Neg;
 
 use arrayvec::ArrayVec;
 use chrono::{Datelike, Timelike};
+use num_bigint::BigUint;
 
 use crate::binary::decimal::DecimalBinaryEncoder;
 use crate::binary::raw_binary_writer::MAX_INLINE_LENGTH;
 use crate::binary::var_int::VarInt;
 use crate::binary::var_uint::VarUInt;
+use crate::decimal::coefficient::Coefficient;
 use crate::result::IonResult;
 use crate::types::{Mantissa, TimestampPrecision};
 use crate::{Decimal, Timestamp};
@@ -81,7 +83,19 @@ where
                                     // Consider the following case: `2000-01-01T00:00:00.123Z`.
                                     // That's 123 millis, or 123,000,000 nanos.
                                     // Our mantissa is 0.123, or 123d-3.
-                                    let scaled = utc.nanosecond() / 10u32.pow(9 - *precision); // 123,000,000 -> 123
+                                    // If the precision is finer than a nanosecond, the extra
+                                    // digits are zeros; pad the coefficient rather than
+                                    // dropping them. The padded coefficient can be arbitrarily
+                                    // large, so it is computed as a BigUint.
+                                    let nanoseconds = utc.nanosecond() as u64;
+                                    let scaled: Coefficient = if *precision <= 9 {
+                                        // 123,000,000 -> 123
+                                        (nanoseconds / 10u64.pow(9 - *precision)).into()
+                                    } else {
+                                        (BigUint::from(nanoseconds)
+                                            * BigUint::from(10u32).pow(*precision - 9))
+                                        .into()
+                                    };
                                     let exponent = (*precision as i64).neg(); // -3
                                     let fractional = Decimal::new(scaled, exponent); // 123d-3
                                     bytes_written += self.encode_decimal(&fractional)?;
@@ -131,9 +145,10 @@ where
 #[cfg(test)]
 mod binary_timestamp_tests {
     use super::*;
+    use crate::ion_data::IonEq;
     use crate::ion_reader::IonReader;
     use crate::reader::{ReaderBuilder, StreamItem};
-    use crate::IonType;
+    use crate::{Element, IonData, IonType};
     use rstest::*;
 
     // These tests show how varying levels of precision affects number of bytes
@@ -164,4 +179,113 @@ mod binary_timestamp_tests {
         }
         Ok(())
     }
+
+    // Encoding a timestamp and reading it back should preserve the exact number of
+    // fractional digits, including fractions finer than a nanosecond.
+    #[rstest]
+    #[case::millis("2021-01-08T14:12:36.123-05:00", 3)]
+    #[case::micros("2021-01-08T14:12:36.123456-05:00", 6)]
+    #[case::nanos("2021-01-08T14:12:36.123456789-05:00", 9)]
+    #[case::sub_nanos("2021-01-08T14:12:36.123456789012-05:00", 12)]
+    fn timestamp_encoding_preserves_fractional_precision(
+        #[case] input: &str,
+        #[case] expected_scale: i64,
+    ) -> IonResult<()> {
+        let expected = Element::read_one(input)?;
+        let expected_timestamp = expected.expect_timestamp()?;
+        assert_eq!(
+            expected_timestamp.fractional_seconds_scale(),
+            Some(expected_scale)
+        );
+
+        let mut buf = vec![0xE0, 0x01, 0x00, 0xEA];
+        buf.encode_timestamp_value(expected_timestamp)?;
+        let actual = Element::read_one(buf)?;
+        let actual_timestamp = actual.expect_timestamp()?;
+
+        assert_eq!(
+            actual_timestamp.fractional_seconds_scale(),
+            Some(expected_scale)
+        );
+        assert_eq!(
+            actual_timestamp.fractional_seconds_as_decimal(),
+            expected_timestamp.fractional_seconds_as_decimal()
+        );
+        assert!(actual.ion_eq(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_encoding_pads_digits_finer_than_nanoseconds() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 1, 8)
+            .with_hms(14, 12, 36)
+            .with_nanoseconds_and_precision(123_456_789, 12)
+            .build()?;
+        let mut buf = vec![0xE0, 0x01, 0x00, 0xEA];
+        buf.encode_timestamp_value(&timestamp)?;
+        let actual = Element::read_one(buf)?;
+        let actual_timestamp = actual.expect_timestamp()?;
+        assert_eq!(actual_timestamp.fractional_seconds_scale(), Some(12));
+        assert_eq!(
+            actual_timestamp.fractional_seconds_as_decimal(),
+            Some(Decimal::new(123_456_789_000u64, -12))
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::twenty_digits(20)]
+    #[case::twenty_nine_digits(29)]
+    #[case::forty_digits(40)]
+    fn timestamp_encoding_pads_very_fine_precision(#[case] precision: u32) -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 1, 8)
+            .with_hms(14, 12, 36)
+            .with_nanoseconds_and_precision(123_456_789, precision)
+            .build()?;
+        let mut buf = vec![0xE0, 0x01, 0x00, 0xEA];
+        buf.encode_timestamp_value(&timestamp)?;
+        let actual = Element::read_one(buf)?;
+        let actual_timestamp = actual.expect_timestamp()?;
+        assert_eq!(
+            actual_timestamp.fractional_seconds_scale(),
+            Some(precision as i64)
+        );
+        let expected_coefficient =
+            BigUint::from(123_456_789u64) * BigUint::from(10u32).pow(precision - 9);
+        assert_eq!(
+            actual_timestamp.fractional_seconds_as_decimal(),
+            Some(Decimal::new(expected_coefficient, -(precision as i64)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_encoding_distinguishes_unknown_offset_from_utc() -> IonResult<()> {
+        let unknown_offset = Timestamp::with_ymd(2021, 1, 8)
+            .with_hms(14, 12, 36)
+            .build()?;
+        let utc = Timestamp::with_ymd(2021, 1, 8)
+            .with_hms(14, 12, 36)
+            .with_offset(0)
+            .build()?;
+        assert!(!IonData::eq(&unknown_offset, &utc));
+
+        let mut unknown_offset_bytes = vec![];
+        unknown_offset_bytes.encode_timestamp(&unknown_offset)?;
+        let mut utc_bytes = vec![];
+        utc_bytes.encode_timestamp(&utc)?;
+        // The offset is the first field. An unknown offset is written as a negative zero VarInt,
+        // while UTC is written as a positive zero.
+        assert_eq!(unknown_offset_bytes[0], 0xC0);
+        assert_eq!(utc_bytes[0], 0x80);
+        assert_eq!(unknown_offset_bytes[1..], utc_bytes[1..]);
+
+        for expected in [unknown_offset, utc] {
+            let mut buf = vec![0xE0, 0x01, 0x00, 0xEA];
+            buf.encode_timestamp_value(&expected)?;
+            let actual = Element::read_one(buf)?;
+            assert!(IonData::eq(actual.expect_timestamp()?, &expected));
+        }
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+use crate::SymbolId;
+
 pub(crate) mod v1_0 {
     pub const SYSTEM_SYMBOLS: &[Option<&str>] = &[
         None,                             // $0
@@ -24,3 +26,51 @@ pub(crate) mod v1_0 {
         pub const ION_SHARED_SYMBOL_TABLE: usize = 9;
     }
 }
+
+/// A symbol from the Ion 1.0 system symbol table. Because every Ion 1.0 symbol table begins with
+/// these symbols, a `SystemSymbol` can be written as a symbol ID without consulting the
+/// writer's symbol table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemSymbol {
+    /// `$ion` (`$1`)
+    Ion,
+    /// `$ion_1_0` (`$2`)
+    Ion1_0,
+    /// `$ion_symbol_table` (`$3`)
+    IonSymbolTable,
+    /// `name` (`$4`)
+    Name,
+    /// `version` (`$5`)
+    Version,
+    /// `imports` (`$6`)
+    Imports,
+    /// `symbols` (`$7`)
+    Symbols,
+    /// `max_id` (`$8`)
+    MaxId,
+    /// `$ion_shared_symbol_table` (`$9`)
+    IonSharedSymbolTable,
+}
+
+impl SystemSymbol {
+    /// Returns the symbol ID of this symbol in the Ion 1.0 system symbol table.
+    pub fn sid(&self) -> SymbolId {
+        use v1_0::system_symbol_ids::*;
+        match self {
+            SystemSymbol::Ion => ION,
+            SystemSymbol::Ion1_0 => ION_1_0,
+            SystemSymbol::IonSymbolTable => ION_SYMBOL_TABLE,
+            SystemSymbol::Name => NAME,
+            SystemSymbol::Version => VERSION,
+            SystemSymbol::Imports => IMPORTS,
+            SystemSymbol::Symbols => SYMBOLS,
+            SystemSymbol::MaxId => MAX_ID,
+            SystemSymbol::IonSharedSymbolTable => ION_SHARED_SYMBOL_TABLE,
+        }
+    }
+
+    /// Returns the text of this symbol.
+    pub fn text(&self) -> &'static str {
+        v1_0::SYSTEM_SYMBOLS[self.sid()].unwrap()
+    }
+}
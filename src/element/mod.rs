@@ -43,6 +43,7 @@ mod sequence;
 use crate::data_source::IonDataSource;
 use crate::element::writer::ElementWriter;
 use crate::reader::ReaderBuilder;
+use crate::symbol_table::SymbolTable;
 use crate::{Blob, Bytes, Clob, List, SExp, Struct};
 
 use crate::result::IonFailure;
@@ -687,7 +688,20 @@ impl Element {
     }
 
     /// Reads a single Ion [`Element`] from the provided data source. If the input has invalid
-    /// data or does not contain at exactly one Ion value, returns `Err(IonError)`.
+    /// data or does not contain exactly one Ion value, returns `Err(IonError)`. Version markers and
+    /// symbol tables are not values and are not counted.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    ///
+    /// assert_eq!(Element::read_one("$ion_1_0 foo")?, Element::symbol("foo"));
+    /// let error = Element::read_one("foo bar baz").unwrap_err();
+    /// assert!(error.to_string().contains("expected exactly one value, found 3"));
+    ///# Ok(())
+    ///# }
+    /// ```
     pub fn read_one<A: AsRef<[u8]>>(data: A) -> IonResult<Element> {
         let bytes: &[u8] = data.as_ref();
         // Create an iterator over the Elements in the data
@@ -847,6 +861,33 @@ assert_eq!(element_before, element_after);
         }
     }
 
+    /// Serializes the provided [`Element`]s as a binary Ion stream, writing the resulting bytes
+    /// to the provided [`io::Write`]. This is the write-side counterpart of
+    /// [`read_all`](Self::read_all).
+    ///
+    /// Every field name, annotation, and symbol value in `elements` is added to the stream's
+    /// symbol table, which is written as a single local symbol table preceding the values.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    ///
+    /// let elements: Vec<Element> = Element::read_all("foo::{bar: baz} [quux]")?
+    ///     .into_iter()
+    ///     .collect();
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// Element::write_all(&elements, &mut buffer)?;
+    /// assert_eq!(Element::read_all(buffer)?, elements.into());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn write_all<W: io::Write>(elements: &[Element], sink: W) -> IonResult<()> {
+        // The binary writer buffers values until it is flushed, at which point it writes a
+        // symbol table defining every symbol that the values use.
+        Element::write_all_as(elements, Format::Binary, sink)
+    }
+
     #[doc = r##"
 Serializes this [`Element`] as binary Ion, returning the output as a `Vec<u8>`.
 "##]
@@ -971,6 +1012,63 @@ where
     }
 }
 
+/// Returns a symbol table containing the system symbols followed by the text of every field name,
+/// annotation, and symbol value found in `elements` (at any depth), in the order in which they
+/// are first encountered. Symbols with unknown text are skipped.
+///
+/// This allows a single symbol table covering an entire batch of values to be computed (and
+/// written) before any of the values themselves.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{symbol_table_for_elements, Element};
+///
+/// let elements: Vec<Element> = Element::read_all("foo::{bar: baz} [bar, quux]")?
+///     .into_iter()
+///     .collect();
+/// let symbol_table = symbol_table_for_elements(&elements);
+/// assert_eq!(symbol_table.len(), 14);
+/// assert_eq!(symbol_table.sid_for(&"foo"), Some(10));
+/// assert_eq!(symbol_table.sid_for(&"quux"), Some(13));
+///# Ok(())
+///# }
+/// ```
+pub fn symbol_table_for_elements(elements: &[Element]) -> SymbolTable {
+    fn intern(symbol_table: &mut SymbolTable, symbol: &Symbol) {
+        if let Some(text) = symbol.text() {
+            symbol_table.intern(text);
+        }
+    }
+
+    fn intern_symbols(symbol_table: &mut SymbolTable, element: &Element) {
+        for annotation in element.annotations().iter() {
+            intern(symbol_table, annotation);
+        }
+        match element.value() {
+            Value::Symbol(symbol) => intern(symbol_table, symbol),
+            Value::List(sequence) | Value::SExp(sequence) => {
+                for child in sequence.iter() {
+                    intern_symbols(symbol_table, child);
+                }
+            }
+            Value::Struct(strukt) => {
+                for (name, child) in strukt.fields() {
+                    intern(symbol_table, name);
+                    intern_symbols(symbol_table, child);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut symbol_table = SymbolTable::new();
+    for element in elements {
+        intern_symbols(&mut symbol_table, element);
+    }
+    symbol_table
+}
+
 #[cfg(test)]
 mod tests {
     use crate::element::annotations::IntoAnnotations;
@@ -1599,6 +1697,56 @@ mod value_tests {
         }
     }
 
+    #[test]
+    fn write_all_emits_a_single_symbol_table() -> IonResult<()> {
+        use crate::blocking_reader::BlockingRawBinaryReader;
+        use crate::ion_reader::IonReader;
+        use crate::system_reader::{SystemReader, SystemStreamItem};
+
+        let elements: Vec<Element> =
+            Element::read_all("foo::{bar: baz} quux::[{bar: corge}] grault 1")?
+                .into_iter()
+                .collect();
+        let mut buffer = Vec::new();
+        Element::write_all(&elements, &mut buffer)?;
+        assert_eq!(Element::read_all(&buffer)?, elements.clone().into());
+
+        let mut reader = SystemReader::new(BlockingRawBinaryReader::new(buffer)?);
+        let mut items = Vec::new();
+        loop {
+            match reader.next()? {
+                SystemStreamItem::Nothing => break,
+                item => items.push(item),
+            }
+        }
+        assert!(matches!(items[0], SystemStreamItem::VersionMarker(1, 0)));
+        assert!(matches!(items[1], SystemStreamItem::SymbolTableValue(_)));
+        let symbol_tables = items
+            .iter()
+            .filter(|item| matches!(item, SystemStreamItem::SymbolTableValue(_)))
+            .count();
+        assert_eq!(symbol_tables, 1);
+        assert_eq!(items.len(), 2 + elements.len());
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_for_elements() -> IonResult<()> {
+        let elements: Vec<Element> =
+            Element::read_all("foo::{bar: (baz $0 name)} quux::[{bar: corge::1}] \"str\" 'foo'")?
+                .into_iter()
+                .collect();
+        let symbol_table = super::symbol_table_for_elements(&elements);
+        // `name` is a system symbol, so it is not added again.
+        let expected = ["foo", "bar", "baz", "quux", "corge"];
+        assert_eq!(symbol_table.len(), 10 + expected.len());
+        for (sid, text) in (10..).zip(expected) {
+            assert_eq!(symbol_table.text_for(sid), Some(text));
+        }
+        assert_eq!(super::symbol_table_for_elements(&[]).len(), 10);
+        Ok(())
+    }
+
     #[test]
     fn list_display_roundtrip() {
         let list = ion_list![1, 2, 3, true, false];
@@ -1662,4 +1810,19 @@ mod value_tests {
         let element: Element = int.clone().into();
         assert_eq!(element.expect_i64(), int.expect_i64())
     }
+
+    #[rstest]
+    #[case::empty("", 0)]
+    #[case::only_system_values("$ion_1_0 $ion_symbol_table::{symbols: [\"foo\"]}", 0)]
+    #[case::two_values("1 2", 2)]
+    #[case::many_values("1 2 3 4 5", 5)]
+    fn read_one_requires_exactly_one_value(#[case] data: &str, #[case] num_values: usize) {
+        let error = Element::read_one(data).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains(&format!("expected exactly one value, found {num_values}")),
+            "unexpected error: {error}"
+        );
+    }
 }
@@ -35,31 +35,32 @@ pub trait ElementReader {
 
     /// Like [Self::read_next_element], this method reads the next Ion value in the input stream,
     /// returning it as an `Ok(Element)`. However, it also requires that the stream contain exactly
-    /// one value.
+    /// one value. Version markers and symbol tables are not values and are not counted.
     ///
     /// If the stream's data is valid and it contains one value, returns `Ok(Element)`.
     /// If the stream's data is invalid or the stream does not contain exactly one value,
-    /// returns `Err(IonError)`.
+    /// returns `Err(IonError)`. When there is more than one value, the rest of the stream is read
+    /// so that the error can report how many values were found.
     fn read_one_element(&mut self) -> IonResult<Element> {
         let mut iter = self.elements();
         let only_element = match iter.next() {
             Some(Ok(element)) => element,
             Some(Err(e)) => return Err(e),
-            None => return IonResult::decoding_error("expected 1 value, found 0"),
+            None => return IonResult::decoding_error("expected exactly one value, found 0"),
         };
-        // See if there is a second, unexpected value.
-        match iter.next() {
-            Some(Ok(element)) => {
-                return IonResult::decoding_error(format!(
-                    "found more than one value; second value: {}",
-                    element
-                ))
+        // See if there are any other, unexpected values.
+        let mut num_values = 1;
+        for element in iter {
+            if let Err(e) = element {
+                return IonResult::decoding_error(format!("error after expected value: {}", e));
             }
-            Some(Err(e)) => {
-                return IonResult::decoding_error(format!("error after expected value: {}", e))
-            }
-            None => {}
-        };
+            num_values += 1;
+        }
+        if num_values > 1 {
+            return IonResult::decoding_error(format!(
+                "expected exactly one value, found {num_values}"
+            ));
+        }
         Ok(only_element)
     }
 
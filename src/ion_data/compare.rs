@@ -0,0 +1,215 @@
+use std::fmt::{Display, Formatter};
+
+use crate::ion_data::IonEq;
+use crate::{Element, IonResult, Sequence, Struct, Symbol, Value};
+
+/// The result of comparing two Ion streams with [`compare_ion`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum IonComparison {
+    /// The streams' bytes are identical.
+    Identical,
+    /// The streams' bytes differ, but they contain Ion-equivalent data. (See [`IonData`](crate::IonData).)
+    Equivalent,
+    /// The streams contain data that is not Ion-equivalent. `path` leads to the first value found
+    /// that differs between the two streams.
+    Different { path: IonPath },
+}
+
+/// A step in an [`IonPath`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum IonPathComponent {
+    /// The value at the given position in a stream, list, or s-expression.
+    Index(usize),
+    /// The value(s) associated with the given field name in a struct.
+    Field(Symbol),
+}
+
+/// The location of a value within an Ion stream. The first component is always the
+/// [`Index`](IonPathComponent::Index) of a top-level value.
+///
+/// When displayed, a path is written in a syntax resembling Rust's indexing and field access.
+/// For example, `[2].foo[0]` refers to the first child of the `foo` field in the third top-level
+/// value.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IonPath {
+    components: Vec<IonPathComponent>,
+}
+
+impl IonPath {
+    /// Returns the steps that lead from the top level of the stream to the value at this path.
+    pub fn components(&self) -> &[IonPathComponent] {
+        &self.components
+    }
+}
+
+impl Display for IonPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for component in &self.components {
+            match component {
+                IonPathComponent::Index(index) => write!(f, "[{index}]")?,
+                IonPathComponent::Field(name) => write!(f, ".{}", name.text().unwrap_or("$0"))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compares two Ion streams, reporting whether they are byte-for-byte identical, Ion-equivalent
+/// but encoded differently, or different. In the last case, the path to the first value that
+/// differs is included.
+///
+/// Either stream may be text or binary Ion. Returns an error if either stream cannot be read.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{compare_ion, IonComparison};
+///
+/// assert_eq!(compare_ion(b"{a: [1, 2]}", b"{a: [1, 2]}")?, IonComparison::Identical);
+/// assert_eq!(compare_ion(b"{a: [1, 2]}", b"{ a : [1,2] }")?, IonComparison::Equivalent);
+///
+/// let IonComparison::Different { path } = compare_ion(b"{a: [1, 2]}", b"{a: [1, 3]}")? else {
+///     panic!("expected a difference");
+/// };
+/// assert_eq!(path.to_string(), "[0].a[1]");
+///# Ok(())
+///# }
+/// ```
+pub fn compare_ion(actual: &[u8], expected: &[u8]) -> IonResult<IonComparison> {
+    if actual == expected {
+        return Ok(IonComparison::Identical);
+    }
+    let actual = Element::read_all(actual)?;
+    let expected = Element::read_all(expected)?;
+    let mut path = IonPath::default();
+    if find_difference_in_sequences(&actual, &expected, &mut path) {
+        Ok(IonComparison::Different { path })
+    } else {
+        Ok(IonComparison::Equivalent)
+    }
+}
+
+/// If `actual` and `expected` are not Ion-equivalent, appends the location of the first difference
+/// to `path` and returns `true`. Otherwise, returns `false`.
+fn find_difference(actual: &Element, expected: &Element, path: &mut IonPath) -> bool {
+    if actual.ion_eq(expected) {
+        return false;
+    }
+    // If the values' annotations or types differ, the values themselves are the difference.
+    if actual.annotations() != expected.annotations() {
+        return true;
+    }
+    match (actual.value(), expected.value()) {
+        (Value::List(actual), Value::List(expected))
+        | (Value::SExp(actual), Value::SExp(expected)) => {
+            find_difference_in_sequences(actual, expected, path);
+        }
+        (Value::Struct(actual), Value::Struct(expected)) => {
+            find_difference_in_structs(actual, expected, path);
+        }
+        _ => {}
+    }
+    true
+}
+
+fn find_difference_in_sequences(
+    actual: &Sequence,
+    expected: &Sequence,
+    path: &mut IonPath,
+) -> bool {
+    for index in 0..actual.len().max(expected.len()) {
+        path.components.push(IonPathComponent::Index(index));
+        match (actual.get(index), expected.get(index)) {
+            (Some(actual_child), Some(expected_child)) => {
+                if find_difference(actual_child, expected_child, path) {
+                    return true;
+                }
+            }
+            // One sequence is longer than the other; the first extra value is the difference.
+            _ => return true,
+        }
+        path.components.pop();
+    }
+    false
+}
+
+fn find_difference_in_structs(actual: &Struct, expected: &Struct, path: &mut IonPath) -> bool {
+    let field_names = actual
+        .fields()
+        .chain(expected.fields())
+        .map(|(name, _)| name);
+    for name in field_names {
+        let actual_values: Vec<&Element> = actual.get_all(name).collect();
+        let expected_values: Vec<&Element> = expected.get_all(name).collect();
+        if let ([actual_value], [expected_value]) = (&actual_values[..], &expected_values[..]) {
+            path.components.push(IonPathComponent::Field(name.clone()));
+            if find_difference(actual_value, expected_value, path) {
+                return true;
+            }
+            path.components.pop();
+            continue;
+        }
+        // The field is repeated or missing. Its values are equivalent only if each one in `actual`
+        // can be paired with a distinct equivalent value in `expected`.
+        let mut unmatched = expected_values;
+        let all_matched = actual_values.iter().all(|actual_value| {
+            match unmatched.iter().position(|v| v.ion_eq(actual_value)) {
+                Some(position) => {
+                    unmatched.swap_remove(position);
+                    true
+                }
+                None => false,
+            }
+        });
+        if !all_matched || !unmatched.is_empty() {
+            path.components.push(IonPathComponent::Field(name.clone()));
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Format;
+    use rstest::*;
+
+    #[test]
+    fn identical_and_equivalent() -> IonResult<()> {
+        let text = b"foo::{a: [1, 2.0, \"three\"], b: (4 5), a: null.int} 6";
+        assert_eq!(compare_ion(text, text)?, IonComparison::Identical);
+
+        let mut binary = Vec::new();
+        Element::write_all_as(&Element::read_all(text)?, Format::Binary, &mut binary)?;
+        assert_eq!(compare_ion(&binary, text)?, IonComparison::Equivalent);
+
+        let reordered = b"foo::{b: (4 5), a: null.int, a: [1, 2.0, \"three\"]} 6";
+        assert_eq!(compare_ion(reordered, text)?, IonComparison::Equivalent);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::top_level_value("1 2 3", "1 4 3", "[1]")]
+    #[case::extra_top_level_value("1 2", "1 2 3", "[2]")]
+    #[case::nested_in_list("[1, [2, 3]]", "[1, [2, 4]]", "[0][1][1]")]
+    #[case::nested_in_struct("{a: {b: [1, 2]}}", "{a: {b: [1, 2, 3]}}", "[0].a.b[2]")]
+    #[case::missing_field("{a: 1, b: 2}", "{a: 1}", "[0].b")]
+    #[case::repeated_field("{a: 1, a: 2}", "{a: 1, a: 3}", "[0].a")]
+    #[case::annotations("[foo::1]", "[bar::1]", "[0][0]")]
+    #[case::types("[1]", "[1e0]", "[0][0]")]
+    #[case::precision("[1.0]", "[1.00]", "[0][0]")]
+    fn different(
+        #[case] actual: &str,
+        #[case] expected: &str,
+        #[case] path: &str,
+    ) -> IonResult<()> {
+        match compare_ion(actual.as_bytes(), expected.as_bytes())? {
+            IonComparison::Different { path: actual_path } => {
+                assert_eq!(actual_path.to_string(), path)
+            }
+            other => panic!("expected a difference at {path}, found {other:?}"),
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+mod compare;
 mod ion_eq;
 mod ion_ord;
 
@@ -5,6 +6,7 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
+pub use compare::{compare_ion, IonComparison, IonPath, IonPathComponent};
 pub(crate) use ion_eq::{ion_eq_bool, ion_eq_f64, IonEq};
 pub(crate) use ion_ord::{ion_cmp_bool, ion_cmp_f64, IonOrd};
 
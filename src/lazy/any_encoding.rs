@@ -1,6 +1,7 @@
 #![allow(non_camel_case_types)]
 
 use std::fmt::Debug;
+use std::ops::Range;
 
 use bumpalo::Bump as BumpAllocator;
 
@@ -13,8 +14,8 @@ use crate::lazy::binary::raw::sequence::{
 use crate::lazy::binary::raw::value::LazyRawBinaryValue;
 use crate::lazy::decoder::private::{LazyContainerPrivate, LazyRawValuePrivate};
 use crate::lazy::decoder::{
-    LazyDecoder, LazyRawFieldExpr, LazyRawReader, LazyRawSequence, LazyRawStruct, LazyRawValue,
-    LazyRawValueExpr, RawFieldExpr, RawValueExpr,
+    AnnotationEncoding, LazyDecoder, LazyRawFieldExpr, LazyRawReader, LazyRawSequence,
+    LazyRawStruct, LazyRawValue, LazyRawValueExpr, RawFieldExpr, RawValueExpr,
 };
 use crate::lazy::encoding::{BinaryEncoding_1_0, TextEncoding_1_0, TextEncoding_1_1};
 use crate::lazy::expanded::macro_evaluator::RawEExpression;
@@ -134,6 +135,54 @@ impl<'top> Iterator for LazyRawAnyMacroArgsIterator<'top> {
 /// A lazy raw reader that can decode both text and binary Ion.
 pub struct LazyRawAnyReader<'data> {
     encoding: RawReaderKind<'data>,
+    // The encoding that the reader identified from the beginning of its input.
+    detected_encoding: Option<IonEncoding>,
+}
+
+/// An encoding of Ion data, as identified by [`LazyRawAnyReader::detected_encoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IonEncoding {
+    BinaryV1_0,
+    TextV1_0,
+    BinaryV1_1,
+    TextV1_1,
+}
+
+impl<'data> LazyRawAnyReader<'data> {
+    /// Returns the encoding of the reader's input, as determined from its first bytes when the
+    /// reader was constructed. Binary data is identified by its Ion version marker (IVM). Text
+    /// is identified as Ion 1.1 if it begins with a `$ion_1_1` IVM; otherwise, text is Ion 1.0.
+    ///
+    /// Returns `None` if the input is empty or contains only whitespace.
+    ///
+    /// Ion 1.1 data can be identified, but this reader cannot yet read it.
+    pub fn detected_encoding(&self) -> Option<IonEncoding> {
+        self.detected_encoding
+    }
+
+    fn detect_encoding(data: &[u8]) -> Option<IonEncoding> {
+        const BINARY_1_0_IVM: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+        const BINARY_1_1_IVM: [u8; 4] = [0xE0, 0x01, 0x01, 0xEA];
+        const TEXT_1_1_IVM: &[u8] = b"$ion_1_1";
+        if data.starts_with(&BINARY_1_0_IVM) {
+            return Some(IonEncoding::BinaryV1_0);
+        }
+        if data.starts_with(&BINARY_1_1_IVM) {
+            return Some(IonEncoding::BinaryV1_1);
+        }
+        let text_start = data.iter().position(|b| !b.is_ascii_whitespace())?;
+        let text = &data[text_start..];
+        let is_text_1_1_ivm = text.starts_with(TEXT_1_1_IVM)
+            && !matches!(
+                text.get(TEXT_1_1_IVM.len()),
+                Some(b) if b.is_ascii_alphanumeric() || *b == b'_' || *b == b'$'
+            );
+        if is_text_1_1_ivm {
+            Some(IonEncoding::TextV1_1)
+        } else {
+            Some(IonEncoding::TextV1_0)
+        }
+    }
 }
 
 pub enum RawReaderKind<'data> {
@@ -145,6 +194,7 @@ impl<'data> From<LazyRawTextReader_1_0<'data>> for LazyRawAnyReader<'data> {
     fn from(reader: LazyRawTextReader_1_0<'data>) -> Self {
         LazyRawAnyReader {
             encoding: RawReaderKind::Text_1_0(reader),
+            detected_encoding: Some(IonEncoding::TextV1_0),
         }
     }
 }
@@ -153,17 +203,21 @@ impl<'data> From<LazyRawBinaryReader<'data>> for LazyRawAnyReader<'data> {
     fn from(reader: LazyRawBinaryReader<'data>) -> Self {
         LazyRawAnyReader {
             encoding: RawReaderKind::Binary_1_0(reader),
+            detected_encoding: Some(IonEncoding::BinaryV1_0),
         }
     }
 }
 
 impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
     fn new(data: &'data [u8]) -> Self {
-        if data.starts_with(&[0xE0u8, 0x01, 0x00, 0xEA]) {
+        let detected_encoding = Self::detect_encoding(data);
+        let mut reader: Self = if detected_encoding == Some(IonEncoding::BinaryV1_0) {
             LazyRawBinaryReader::new(data).into()
         } else {
             LazyRawTextReader_1_0::new(data).into()
-        }
+        };
+        reader.detected_encoding = detected_encoding;
+        reader
     }
 
     fn next<'top>(
@@ -178,6 +232,20 @@ impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
             RawReaderKind::Binary_1_0(r) => Ok(r.next()?.into()),
         }
     }
+
+    fn position(&self) -> usize {
+        match &self.encoding {
+            RawReaderKind::Text_1_0(r) => r.position(),
+            RawReaderKind::Binary_1_0(r) => r.position(),
+        }
+    }
+
+    fn resynchronize(&mut self, position: usize) -> usize {
+        match &mut self.encoding {
+            RawReaderKind::Text_1_0(r) => r.resynchronize(position),
+            RawReaderKind::Binary_1_0(r) => r.resynchronize(position),
+        }
+    }
 }
 
 // ===== Values ======
@@ -438,6 +506,38 @@ impl<'top> LazyRawValue<'top, AnyEncoding> for LazyRawAnyValue<'top> {
             LazyRawValueKind::Text_1_1(v) => Ok(v.read()?.into()),
         }
     }
+
+    fn value_length(&self) -> usize {
+        match &self.encoding {
+            LazyRawValueKind::Text_1_0(v) => v.value_length(),
+            LazyRawValueKind::Binary_1_0(v) => v.value_length(),
+            LazyRawValueKind::Text_1_1(v) => v.value_length(),
+        }
+    }
+
+    fn range(&self) -> Range<usize> {
+        match &self.encoding {
+            LazyRawValueKind::Text_1_0(v) => v.range(),
+            LazyRawValueKind::Binary_1_0(v) => v.range(),
+            LazyRawValueKind::Text_1_1(v) => v.range(),
+        }
+    }
+
+    fn type_descriptor(&self) -> Option<u8> {
+        match &self.encoding {
+            LazyRawValueKind::Text_1_0(v) => v.type_descriptor(),
+            LazyRawValueKind::Binary_1_0(v) => v.type_descriptor(),
+            LazyRawValueKind::Text_1_1(v) => v.type_descriptor(),
+        }
+    }
+
+    fn annotation_encoding(&self) -> AnnotationEncoding {
+        match &self.encoding {
+            LazyRawValueKind::Text_1_0(v) => v.annotation_encoding(),
+            LazyRawValueKind::Binary_1_0(v) => v.annotation_encoding(),
+            LazyRawValueKind::Text_1_1(v) => v.annotation_encoding(),
+        }
+    }
 }
 
 // ===== Annotations =====
@@ -960,4 +1060,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn detected_encoding() {
+        use IonEncoding::*;
+        let cases: &[(&[u8], Option<IonEncoding>)] = &[
+            (&[0xE0, 0x01, 0x00, 0xEA, 0x20], Some(BinaryV1_0)),
+            (&[0xE0, 0x01, 0x01, 0xEA], Some(BinaryV1_1)),
+            (b"$ion_1_0 foo", Some(TextV1_0)),
+            (b"foo bar", Some(TextV1_0)),
+            (b"\n  $ion_1_1 (:values 1)", Some(TextV1_1)),
+            (b"$ion_1_1_and_more", Some(TextV1_0)),
+            (b"", None),
+            (b" \n\t", None),
+        ];
+        for (data, expected) in cases {
+            let reader = LazyRawAnyReader::new(data);
+            assert_eq!(reader.detected_encoding(), *expected, "input: {data:?}");
+        }
+    }
 }
@@ -468,15 +468,29 @@ impl<'a> ImmutableBuffer<'a> {
             return IonResult::decoding_error("found an annotations wrapper with no annotations");
         }
 
-        // Validate that the annotated value is not missing.
+        // Validate that the annotations sequence fits within the wrapper's declared length.
         let expected_value_length = annotations_and_value_length
-            - annotations_length.size_in_bytes()
-            - annotations_length.value();
+            .checked_sub(annotations_length.size_in_bytes() + annotations_length.value())
+            .ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "annotations wrapper length mismatch at offset {}: the annotations sequence \
+                     is longer than the wrapper's declared length ({annotations_and_value_length})",
+                    self.offset()
+                ))
+            })?;
 
         if expected_value_length == 0 {
             return IonResult::decoding_error("found an annotation wrapper with no value");
         }
 
+        // Make sure the annotations sequence is actually in the buffer before consuming it.
+        if input_after_annotations_length.len() < annotations_length.value() {
+            return IonResult::incomplete(
+                "an annotations sequence",
+                input_after_annotations_length.offset(),
+            );
+        }
+
         // Skip over the annotations sequence itself; the reader will return to it if/when the
         // reader asks to iterate over those symbol IDs.
         let final_input = input_after_annotations_length.consume(annotations_length.value());
@@ -781,9 +795,13 @@ impl<'a> ImmutableBuffer<'a> {
         let mut lazy_value =
             input_after_annotations.read_value_without_annotations(type_descriptor)?;
         if wrapper.expected_value_length != lazy_value.encoded_value.total_length() {
-            return IonResult::decoding_error(
-                "value length did not match length declared by annotations wrapper",
-            );
+            return IonResult::decoding_error(format!(
+                "annotations wrapper length mismatch at offset {}: the wrapper declared a \
+                 {}-byte value but the value was {} bytes long",
+                input.offset(),
+                wrapper.expected_value_length,
+                lazy_value.encoded_value.total_length()
+            ));
         }
 
         lazy_value.encoded_value.annotations_header_length = wrapper.header_length;
@@ -1,6 +1,7 @@
 mod encoded_value;
 pub mod immutable_buffer;
 pub mod raw;
+pub mod validate;
 
 #[cfg(test)]
 pub(crate) mod test_utilities;
@@ -1,10 +1,11 @@
+use crate::binary::constants::v1_0::IVM;
 use crate::lazy::binary::immutable_buffer::ImmutableBuffer;
 use crate::lazy::binary::raw::value::LazyRawBinaryValue;
 use crate::lazy::decoder::LazyRawReader;
 use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::lazy::raw_stream_item::{LazyRawStreamItem, RawStreamItem};
 use crate::result::IonFailure;
-use crate::IonResult;
+use crate::{IonResult, IonType};
 
 use bumpalo::Bump as BumpAllocator;
 
@@ -83,6 +84,47 @@ impl<'data> LazyRawBinaryReader<'data> {
 
         self.read_value(buffer)
     }
+
+    /// Returns the Ion type of the next value in the stream without advancing the reader. If the
+    /// value is annotated, this is the type of the value inside the annotations wrapper. Any
+    /// version markers and NOP padding that precede the value are skipped over. Returns `None` if
+    /// there are no more values.
+    ///
+    /// Only the headers of the value (and its annotations wrapper, if any) are read.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::IonType;
+    /// use ion_rs::lazy::binary::raw::reader::LazyRawBinaryReader;
+    ///
+    /// // An IVM, `$4::"foo"`, and `true`
+    /// let data = [0xE0, 0x01, 0x00, 0xEA, 0xE6, 0x81, 0x84, 0x83, 0x66, 0x6F, 0x6F, 0x11];
+    /// let mut reader = LazyRawBinaryReader::new(&data);
+    /// assert_eq!(reader.peek_type()?, Some(IonType::String));
+    /// reader.next()?.expect_ivm()?;
+    /// assert_eq!(reader.peek_type()?, Some(IonType::String));
+    /// reader.next()?.expect_value()?;
+    /// assert_eq!(reader.peek_type()?, Some(IonType::Bool));
+    /// reader.next()?.expect_value()?;
+    /// assert_eq!(reader.peek_type()?, None);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn peek_type(&self) -> IonResult<Option<IonType>> {
+        let mut buffer = self.data.advance_to_next_item()?;
+        while !buffer.is_empty() {
+            let type_descriptor = buffer.peek_type_descriptor()?;
+            if type_descriptor.is_nop() {
+                (_, buffer) = buffer.consume_nop_padding(type_descriptor)?;
+            } else if type_descriptor.is_ivm_start() {
+                (_, buffer) = buffer.read_ivm()?;
+            } else {
+                break;
+            }
+        }
+        let lazy_value = ImmutableBuffer::peek_sequence_value(buffer)?;
+        Ok(lazy_value.map(|value| value.ion_type()))
+    }
 }
 
 impl<'data> LazyRawReader<'data, BinaryEncoding_1_0> for LazyRawBinaryReader<'data> {
@@ -99,6 +141,28 @@ impl<'data> LazyRawReader<'data, BinaryEncoding_1_0> for LazyRawBinaryReader<'da
     {
         self.next()
     }
+
+    fn position(&self) -> usize {
+        self.data.buffer.offset() + self.data.bytes_to_skip
+    }
+
+    fn resynchronize(&mut self, position: usize) -> usize {
+        // Binary Ion is not self-synchronizing; the only sequence that reliably marks the beginning
+        // of a top-level item is an Ion version marker. Skip to the next IVM following `position`,
+        // or to the end of the input if there isn't one.
+        let buffer = self.data.buffer;
+        let search_start = (position + 1)
+            .saturating_sub(buffer.offset())
+            .min(buffer.len());
+        let bytes_to_skip = buffer.bytes()[search_start..]
+            .windows(IVM.len())
+            .position(|window| window == IVM)
+            .map(|index| search_start + index)
+            .unwrap_or(buffer.len());
+        self.data.buffer = buffer.consume(bytes_to_skip);
+        self.data.bytes_to_skip = 0;
+        self.data.buffer.offset()
+    }
 }
 
 /// Wraps an [`ImmutableBuffer`], allowing the reader to advance each time an item is successfully
@@ -123,7 +187,7 @@ impl<'data> DataSource<'data> {
         self.buffer
     }
 
-    fn advance_to_next_item(&mut self) -> IonResult<ImmutableBuffer<'data>> {
+    fn advance_to_next_item(&self) -> IonResult<ImmutableBuffer<'data>> {
         if self.buffer.len() < self.bytes_to_skip {
             return IonResult::incomplete(
                 "cannot advance to next item, insufficient data in buffer",
@@ -170,7 +234,7 @@ mod tests {
     use crate::lazy::binary::test_utilities::to_binary_ion;
     use crate::lazy::raw_stream_item::RawStreamItem;
     use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
-    use crate::{IonResult, IonType, RawSymbolTokenRef};
+    use crate::{IonError, IonResult, IonType, RawSymbolTokenRef};
 
     #[test]
     fn test_struct() -> IonResult<()> {
@@ -310,4 +374,70 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn peek_type() -> IonResult<()> {
+        let data: Vec<u8> = vec![
+            0xe0, 0x01, 0x00, 0xea, // IVM
+            0xe0, 0x01, 0x00, 0xea, // IVM
+            0x01, 0xff, // 2-byte NOP
+            0xe5, 0x82, 0x84, 0x85, 0xb1, 0x10, // $4::$5::[false]
+            0x00, // 1-byte NOP
+            0xdf, // null.struct
+        ];
+        let mut reader = LazyRawBinaryReader::new(&data);
+        // Peeking does not advance the reader.
+        assert_eq!(reader.peek_type()?, Some(IonType::List));
+        assert_eq!(reader.peek_type()?, Some(IonType::List));
+        let _ivm = reader.next()?.expect_ivm()?;
+        assert_eq!(reader.peek_type()?, Some(IonType::List));
+        let _ivm = reader.next()?.expect_ivm()?;
+        let list = reader.next()?.expect_value()?;
+        assert_eq!(list.ion_type(), IonType::List);
+        assert_eq!(reader.peek_type()?, Some(IonType::Struct));
+        let _null_struct = reader.next()?.expect_value()?;
+        assert_eq!(reader.peek_type()?, None);
+        assert!(matches!(reader.next()?, RawStreamItem::EndOfStream));
+
+        assert_eq!(LazyRawBinaryReader::new(&[]).peek_type()?, None);
+        // The annotations wrapper is truncated.
+        assert!(LazyRawBinaryReader::new(&[0xe5, 0x82]).peek_type().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_annotations_sequence() -> IonResult<()> {
+        // An annotations wrapper whose annotations sequence length (2) runs past the end of the
+        // input. This used to panic instead of reporting that the input was incomplete.
+        let data = [0xe5, 0x82];
+        let mut reader = LazyRawBinaryReader::new(&data);
+        assert!(matches!(reader.next(), Err(IonError::Incomplete(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn annotations_wrapper_length_mismatch() -> IonResult<()> {
+        let streams: &[&[u8]] = &[
+            // The wrapper declares a 1-byte value, but the value is 2 bytes long
+            &[0xE0, 0x01, 0x00, 0xEA, 0xE3, 0x81, 0x84, 0x21, 0x01],
+            // The annotations sequence is longer than the wrapper's declared length
+            &[
+                0xE0, 0x01, 0x00, 0xEA, 0xE3, 0x85, 0x84, 0x84, 0x84, 0x84, 0x84, 0x10,
+            ],
+        ];
+        for data in streams {
+            let mut reader = LazyRawBinaryReader::new(data);
+            let _ivm = reader.next()?.expect_ivm()?;
+            let error = reader
+                .next()
+                .expect_err("annotations wrapper should be rejected");
+            assert!(
+                error
+                    .to_string()
+                    .contains("annotations wrapper length mismatch at offset 4"),
+                "{error}"
+            );
+        }
+        Ok(())
+    }
 }
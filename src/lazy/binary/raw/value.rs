@@ -8,7 +8,7 @@ use crate::lazy::binary::raw::sequence::{
     LazyRawBinaryList, LazyRawBinarySExp, LazyRawBinarySequence,
 };
 use crate::lazy::decoder::private::LazyRawValuePrivate;
-use crate::lazy::decoder::LazyRawValue;
+use crate::lazy::decoder::{AnnotationEncoding, LazyRawValue};
 use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::str_ref::StrRef;
@@ -17,6 +17,7 @@ use crate::types::SymbolId;
 use crate::{Decimal, Int, IonError, IonResult, IonType, RawSymbolTokenRef, Timestamp};
 use bytes::{BigEndian, ByteOrder};
 use std::fmt::{Debug, Formatter};
+use std::ops::Range;
 use std::{fmt, mem};
 
 /// A value that has been identified in the input stream but whose data has not yet been read.
@@ -74,6 +75,37 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_0> for LazyRawBinaryValue<'top> {
     fn read(&self) -> IonResult<RawValueRef<'top, BinaryEncoding_1_0>> {
         self.read()
     }
+
+    fn value_length(&self) -> usize {
+        self.encoded_value.value_length()
+    }
+
+    fn range(&self) -> Range<usize> {
+        let start = self
+            .encoded_value
+            .annotations_offset()
+            .unwrap_or(self.encoded_value.header_offset);
+        start..self.encoded_value.value_end_exclusive()
+    }
+
+    fn type_descriptor(&self) -> Option<u8> {
+        // The input begins with the value's field ID, if it has one.
+        self.input
+            .bytes()
+            .get(self.encoded_value.field_id_length as usize)
+            .copied()
+    }
+
+    fn annotation_encoding(&self) -> AnnotationEncoding {
+        if !self.encoded_value.has_annotations() {
+            return AnnotationEncoding::Unannotated;
+        }
+        // The value is annotated, so its first byte is the annotations wrapper's type descriptor.
+        match self.type_descriptor() {
+            Some(0xEE) => AnnotationEncoding::VarUIntLengthWrapper,
+            _ => AnnotationEncoding::InlineLengthWrapper,
+        }
+    }
 }
 
 impl<'top> LazyRawBinaryValue<'top> {
@@ -0,0 +1,114 @@
+use crate::lazy::binary::immutable_buffer::ImmutableBuffer;
+use crate::lazy::binary::raw::reader::{DataSource, LazyRawBinaryReader};
+use crate::lazy::binary::raw::value::LazyRawBinaryValue;
+use crate::lazy::raw_stream_item::RawStreamItem;
+use crate::result::IonFailure;
+use crate::{IonResult, IonType};
+
+/// Confirms that `data` is a structurally sound binary Ion 1.0 stream.
+///
+/// Each value's type descriptor and length are checked and the contents of every container are
+/// traversed, but scalar payloads are never decoded and symbol IDs are not resolved. This makes
+/// validation considerably cheaper than reading the stream, though it also means that some
+/// problems (for example, an undefined symbol ID or a malformed timestamp) will not be detected.
+///
+/// Returns an error describing the first problem encountered, if any.
+pub fn validate_binary(data: &[u8]) -> IonResult<()> {
+    let mut reader = LazyRawBinaryReader::new(data);
+    loop {
+        match reader.next()? {
+            RawStreamItem::VersionMarker(_, _) => {}
+            RawStreamItem::Value(value) => validate_value(value)?,
+            RawStreamItem::EExpression(_) => unreachable!("Ion 1.0 does not support macros"),
+            RawStreamItem::EndOfStream => return Ok(()),
+        }
+    }
+}
+
+/// Validates a single value, descending into any containers it holds.
+fn validate_value(value: LazyRawBinaryValue<'_>) -> IonResult<()> {
+    // Containers are traversed using an explicit stack rather than recursion so that deeply
+    // nested input cannot exhaust the call stack. Each entry is the remaining body of a container
+    // and a flag indicating whether that container is a struct.
+    let mut containers: Vec<(DataSource<'_>, bool)> = Vec::new();
+    check_value(value, &mut containers)?;
+    while let Some((body, is_struct)) = containers.last_mut() {
+        let child = if *is_struct {
+            body.try_parse_next(ImmutableBuffer::peek_field)?
+        } else {
+            body.try_parse_next(ImmutableBuffer::peek_sequence_value)?
+        };
+        match child {
+            Some(child) => check_value(child, &mut containers)?,
+            None => {
+                containers.pop();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Confirms that all of `value`'s bytes are available. If `value` is a non-null container, pushes
+/// its body onto the `containers` stack so its contents can be validated.
+fn check_value<'top>(
+    value: LazyRawBinaryValue<'top>,
+    containers: &mut Vec<(DataSource<'top>, bool)>,
+) -> IonResult<()> {
+    if value.input.len() < value.encoded_value.total_length() {
+        return IonResult::incomplete("a value", value.input.offset());
+    }
+    if value.is_null() {
+        return Ok(());
+    }
+    let is_struct = match value.ion_type() {
+        IonType::Struct => true,
+        IonType::List | IonType::SExp => false,
+        _ => return Ok(()),
+    };
+    containers.push((DataSource::new(value.available_body()), is_struct));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::binary::test_utilities::to_binary_ion;
+
+    #[test]
+    fn validate_well_formed_stream() -> IonResult<()> {
+        let data = to_binary_ion(
+            r#"
+            1 2.5 3e0 "foo" bar null.struct 2024-01-01T
+            foo::[1, (2 baz::{a: 3, b: [4, {}]}), {{aGVsbG8=}}]
+            {c: {d: (e f g)}, h: null.list}
+        "#,
+        )?;
+        validate_binary(&data)
+    }
+
+    #[test]
+    fn reject_malformed_streams() {
+        let ivm = [0xE0u8, 0x01, 0x00, 0xEA];
+        let bodies: &[&[u8]] = &[
+            // A string whose declared length exceeds the available data
+            &[0x83, b'a', b'b'],
+            // A list whose declared length exceeds the available data
+            &[0xB3, 0x21, 0x01],
+            // A list containing a value that extends past the end of the list
+            &[0xB2, 0x22, 0x01, 0x01],
+            // A list containing an invalid type code
+            &[0xB1, 0xF0],
+            // A struct containing a field name with no value
+            &[0xD1, 0x84],
+            // An annotations wrapper containing a NOP
+            &[0xE3, 0x81, 0x84, 0x00],
+        ];
+        for body in bodies {
+            let data: Vec<u8> = ivm.iter().chain(body.iter()).copied().collect();
+            assert!(
+                validate_binary(&data).is_err(),
+                "validation unexpectedly succeeded for {body:02x?}"
+            );
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::ops::Range;
 
 use bumpalo::Bump as BumpAllocator;
 
@@ -182,6 +183,37 @@ pub trait LazyRawReader<'data, D: LazyDecoder> {
     ) -> IonResult<LazyRawStreamItem<'top, D>>
     where
         'data: 'top;
+
+    /// Returns the stream offset of the next byte that the reader will examine.
+    fn position(&self) -> usize;
+
+    /// Repositions the reader after it has failed to read the item beginning at `position`.
+    /// The reader will skip ahead to the next location in the input that plausibly begins a
+    /// top-level item and return the stream offset at which reading will resume.
+    ///
+    /// This is a best-effort operation; there is no guarantee that the resumed reader will
+    /// be correctly aligned with the data that follows.
+    fn resynchronize(&mut self, position: usize) -> usize;
+}
+
+/// How a value's annotations were physically encoded in its input stream. See
+/// [`LazyValue::annotation_encoding`](crate::lazy::value::LazyValue::annotation_encoding).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnnotationEncoding {
+    /// The value has no annotations.
+    Unannotated,
+    /// The annotations were written in text Ion using the `annotation::` syntax.
+    Text,
+    /// The annotations were written in a binary Ion 1.0 annotations wrapper whose length is stored
+    /// in the low nibble of its type descriptor (`0xE3`-`0xED`).
+    InlineLengthWrapper,
+    /// The annotations were written in a binary Ion 1.0 annotations wrapper whose length is stored
+    /// in a VarUInt following its type descriptor (`0xEE`).
+    VarUIntLengthWrapper,
+    /// The value was produced by macro evaluation rather than read from the input stream, so its
+    /// annotations (if any) have no encoding.
+    Synthesized,
 }
 
 pub trait LazyRawValue<'top, D: LazyDecoder>:
@@ -191,6 +223,25 @@ pub trait LazyRawValue<'top, D: LazyDecoder>:
     fn is_null(&self) -> bool;
     fn annotations(&self) -> D::AnnotationsIterator<'top>;
     fn read(&self) -> IonResult<RawValueRef<'top, D>>;
+    /// Returns the number of bytes in the value's encoded body. In binary Ion, this is the length
+    /// declared by the value's header. This does not include the value's field name, annotations,
+    /// or header (if any).
+    fn value_length(&self) -> usize;
+    /// Returns the `[start, end)` offsets of the value's complete encoding in the input. This
+    /// includes the value's annotations and header (if any), but not its field name.
+    fn range(&self) -> Range<usize>;
+    /// In binary Ion, returns the first byte of the value's encoding (not including its field
+    /// name). This is the value's type descriptor or, if the value is annotated, the type
+    /// descriptor of its annotations wrapper. Text encodings do not have type descriptors and
+    /// return `None`.
+    fn type_descriptor(&self) -> Option<u8> {
+        None
+    }
+    /// Returns how the value's annotations were encoded in the input. Encodings that do not
+    /// report this return [`AnnotationEncoding::Synthesized`].
+    fn annotation_encoding(&self) -> AnnotationEncoding {
+        AnnotationEncoding::Synthesized
+    }
 }
 
 pub trait LazyRawSequence<'top, D: LazyDecoder>:
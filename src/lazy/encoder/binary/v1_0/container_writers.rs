@@ -1,16 +1,55 @@
 use crate::binary::var_uint::VarUInt;
 use crate::lazy::encoder::binary::v1_0::value_writer::{
-    BinaryAnnotatableValueWriter_1_0, MAX_INLINE_LENGTH,
+    BinaryAnnotatableValueWriter_1_0, BinaryEncodingOptions_1_0, BinaryValueWriter_1_0,
 };
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter};
-use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::encoder::write_as_ion::{DynValue, WriteAsIon};
 use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
 use crate::result::EncodingError;
-use crate::{IonError, IonResult, RawSymbolTokenRef};
+use crate::{IonError, IonResult, RawSymbolTokenRef, SymbolId};
 use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 use delegate::delegate;
+use std::cell::Cell;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Tracks how deeply nested the container currently being written is, along with the deepest
+/// nesting that has been reached so far.
+#[derive(Debug, Default)]
+pub struct DepthTracker {
+    current: Cell<usize>,
+    max: Cell<usize>,
+}
+
+impl DepthTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the deepest container nesting that has been reached. Top-level scalars have a
+    /// depth of 0, the values inside a top-level container have a depth of 1, and so on.
+    pub fn max_depth_reached(&self) -> usize {
+        self.max.get()
+    }
+
+    /// Returns the nesting depth of the container currently being written, or 0 at the top level.
+    pub(crate) fn current_depth(&self) -> usize {
+        self.current.get()
+    }
+
+    fn enter_container(&self) {
+        let depth = self.current.get() + 1;
+        self.current.set(depth);
+        self.max.set(self.max.get().max(depth));
+    }
+
+    fn exit_container(&self) {
+        self.current.set(self.current.get() - 1);
+    }
+}
 
 /// A helper type that holds fields and logic that is common to [`BinaryListWriter_1_0`],
 /// [`BinarySExpWriter_1_0`], and [`BinaryStructWriter_1_0`].
@@ -19,6 +58,10 @@ pub struct BinaryContainerWriter_1_0<'value, 'top> {
     type_code: u8,
     // An allocator reference that can be shared with nested container writers
     allocator: &'top BumpAllocator,
+    // Records the nesting depth of this container and its descendants.
+    depth: &'top DepthTracker,
+    // Encoding settings that apply to this container and its descendants.
+    options: BinaryEncodingOptions_1_0,
     // The buffer containing the parent's encoded body. When this list writer is finished encoding
     // its own data, a header will be written to the parent and then the list body will be copied
     // over.
@@ -26,14 +69,35 @@ pub struct BinaryContainerWriter_1_0<'value, 'top> {
 }
 
 impl<'value, 'top> BinaryContainerWriter_1_0<'value, 'top> {
+    /// Constructs a writer for a container with the specified type code that encodes to
+    /// `parent_buffer` using the default [`BinaryEncodingOptions_1_0`].
     pub fn new(
         type_code: u8,
         allocator: &'top BumpAllocator,
         parent_buffer: &'value mut BumpVec<'top, u8>,
+    ) -> Self {
+        let depth = allocator.alloc(DepthTracker::new());
+        Self::new_with_options(
+            type_code,
+            allocator,
+            depth,
+            Default::default(),
+            parent_buffer,
+        )
+    }
+
+    pub(crate) fn new_with_options(
+        type_code: u8,
+        allocator: &'top BumpAllocator,
+        depth: &'top DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+        parent_buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         Self {
             type_code,
             allocator,
+            depth,
+            options,
             parent_buffer,
         }
     }
@@ -43,20 +107,28 @@ impl<'value, 'top> BinaryContainerWriter_1_0<'value, 'top> {
         'top: 'a,
         F: FnOnce(BinaryContainerValuesWriter_1_0<'a>) -> IonResult<BumpVec<'a, u8>>,
     {
-        let container_values_writer = BinaryContainerValuesWriter_1_0::new(self.allocator);
-        let encoded_values = write_fn(container_values_writer)?;
+        let container_values_writer = BinaryContainerValuesWriter_1_0::new_with_options(
+            self.allocator,
+            self.depth,
+            self.options,
+        );
+        self.depth.enter_container();
+        let encoded_values = write_fn(container_values_writer);
+        self.depth.exit_container();
+        let encoded_values = encoded_values?;
         self.write_header_and_encoded_body(encoded_values.as_slice())
     }
 
+    /// Writes a null of this container's type (for example, `null.struct`) in place of the
+    /// container.
+    pub fn write_null(self) -> IonResult<()> {
+        self.parent_buffer.push(self.type_code | 0x0F);
+        Ok(())
+    }
+
     fn write_header_and_encoded_body(&mut self, body: &[u8]) -> IonResult<()> {
-        let body_length = body.len();
-        if body_length <= MAX_INLINE_LENGTH {
-            let type_descriptor = self.type_code | (body_length as u8);
-            self.parent_buffer.push(type_descriptor);
-        } else {
-            self.parent_buffer.push(self.type_code | 0xE);
-            VarUInt::write_u64(&mut self.parent_buffer, body_length as u64)?;
-        }
+        self.options
+            .write_header(self.parent_buffer, self.type_code, body.len())?;
         self.parent_buffer.extend_from_slice(body);
         Ok(())
     }
@@ -64,18 +136,40 @@ impl<'value, 'top> BinaryContainerWriter_1_0<'value, 'top> {
 
 pub struct BinaryContainerValuesWriter_1_0<'value> {
     allocator: &'value BumpAllocator,
+    depth: &'value DepthTracker,
+    options: BinaryEncodingOptions_1_0,
     buffer: BumpVec<'value, u8>,
 }
 
 impl<'value> BinaryContainerValuesWriter_1_0<'value> {
+    /// Constructs a writer that encodes container values using the default
+    /// [`BinaryEncodingOptions_1_0`].
     pub fn new(allocator: &'value BumpAllocator) -> Self {
+        let depth = allocator.alloc(DepthTracker::new());
+        Self::new_with_options(allocator, depth, Default::default())
+    }
+
+    pub(crate) fn new_with_options(
+        allocator: &'value BumpAllocator,
+        depth: &'value DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+    ) -> Self {
         let buffer = BumpVec::new_in(allocator);
-        Self { allocator, buffer }
+        Self {
+            allocator,
+            depth,
+            options,
+            buffer,
+        }
     }
 
     pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
-        let annotated_value_writer =
-            BinaryAnnotatableValueWriter_1_0::new(self.allocator, &mut self.buffer);
+        let annotated_value_writer = BinaryAnnotatableValueWriter_1_0::new_with_options(
+            self.allocator,
+            self.depth,
+            self.options,
+            &mut self.buffer,
+        );
         value.write_as_ion(annotated_value_writer)?;
         Ok(self)
     }
@@ -93,14 +187,40 @@ impl<'value> BinaryListValuesWriter_1_0<'value> {
         self.values_writer.write(value)?;
         Ok(self)
     }
+
+    /// Writes a value whose Ion type is determined at runtime. See [`DynValue`].
+    pub fn write_dyn(&mut self, value: &DynValue) -> IonResult<&mut Self> {
+        self.write(value)
+    }
+
+    /// Writes each of the provided integers to the list. The output is identical to writing each
+    /// value individually, but the values are encoded directly into the list's body in a single
+    /// pass with the body's capacity reserved up front.
+    pub fn write_i64_slice(&mut self, values: &[i64]) -> IonResult<&mut Self> {
+        let values_writer = &mut self.values_writer;
+        // Each int is encoded as a type descriptor byte followed by at most 8 magnitude bytes.
+        values_writer.buffer.reserve(values.len() * 9);
+        for value in values {
+            BinaryValueWriter_1_0::new_with_options(
+                values_writer.allocator,
+                values_writer.depth,
+                values_writer.options,
+                &mut values_writer.buffer,
+            )
+            .write_i64(*value)?;
+        }
+        Ok(self)
+    }
 }
 
 impl<'value> MakeValueWriter for BinaryListValuesWriter_1_0<'value> {
     type ValueWriter<'a> = BinaryAnnotatableValueWriter_1_0<'a, 'value> where Self: 'a;
 
     fn value_writer(&mut self) -> Self::ValueWriter<'_> {
-        BinaryAnnotatableValueWriter_1_0::new(
+        BinaryAnnotatableValueWriter_1_0::new_with_options(
             self.values_writer.allocator,
+            self.values_writer.depth,
+            self.values_writer.options,
             &mut self.values_writer.buffer,
         )
     }
@@ -110,6 +230,7 @@ impl<'value> SequenceWriter for BinaryListValuesWriter_1_0<'value> {
     delegate! {
         to self {
             fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self>;
+            fn write_i64_slice(&mut self, values: &[i64]) -> IonResult<&mut Self>;
         }
     }
 }
@@ -156,8 +277,10 @@ impl<'value> MakeValueWriter for BinarySExpValuesWriter_1_0<'value> {
     type ValueWriter<'a> = BinaryAnnotatableValueWriter_1_0<'a, 'value> where Self: 'a;
 
     fn value_writer(&mut self) -> Self::ValueWriter<'_> {
-        BinaryAnnotatableValueWriter_1_0::new(
+        BinaryAnnotatableValueWriter_1_0::new_with_options(
             self.values_writer.allocator,
+            self.values_writer.depth,
+            self.values_writer.options,
             &mut self.values_writer.buffer,
         )
     }
@@ -199,35 +322,68 @@ impl<'value, 'top> BinarySExpWriter_1_0<'value, 'top> {
 
 pub struct BinaryStructFieldsWriter_1_0<'value> {
     container_values_writer: BinaryContainerValuesWriter_1_0<'value>,
+    // Whether field names with symbol ID 0 (unknown text) are permitted.
+    allow_unknown_field_name: bool,
 }
 
 impl<'value> BinaryStructFieldsWriter_1_0<'value> {
     pub fn new(container_values_writer: BinaryContainerValuesWriter_1_0<'value>) -> Self {
         Self {
             container_values_writer,
+            allow_unknown_field_name: false,
         }
     }
 
+    /// Configures whether this writer accepts field names with symbol ID 0 (`$0`).
+    ///
+    /// A field named `$0` has no resolvable text, which usually means that the field name failed
+    /// to intern. By default, attempting to write one is reported as an encoding error.
+    pub fn allow_unknown_field_name(&mut self, allow: bool) -> &mut Self {
+        self.allow_unknown_field_name = allow;
+        self
+    }
+
     pub fn write<A: AsRawSymbolTokenRef, V: WriteAsIon>(
         &mut self,
         name: A,
         value: V,
     ) -> IonResult<&mut Self> {
-        // Write the field name
-        let sid = match name.as_raw_symbol_token_ref() {
-            RawSymbolTokenRef::SymbolId(sid) => sid,
-            RawSymbolTokenRef::Text(text) => {
-                return Err(IonError::Encoding(EncodingError::new(format!(
-                    "tried to write a text literal using the v1.0 raw binary writer: '{text}'"
-                ))));
-            }
-        };
-        VarUInt::write_u64(&mut self.container_values_writer.buffer, sid as u64)?;
-
+        self.write_field_name(name)?;
         // Write the field value
         self.container_values_writer.write(value)?;
         Ok(self)
     }
+
+    /// Writes a struct field named `name` whose value is written by `write_fn`, which is passed a
+    /// value writer for the field. This allows expensive or conditional logic for producing the
+    /// value to live inside the closure while still using the fluent field-writing API.
+    ///
+    /// `write_fn` must write exactly one value.
+    pub fn write_with<A, F>(&mut self, name: A, write_fn: F) -> IonResult<&mut Self>
+    where
+        A: AsRawSymbolTokenRef,
+        F: for<'a> FnOnce(BinaryAnnotatableValueWriter_1_0<'a, 'value>) -> IonResult<()>,
+    {
+        self.write_field_name(name)?;
+        let values_writer = &mut self.container_values_writer;
+        write_fn(BinaryAnnotatableValueWriter_1_0::new_with_options(
+            values_writer.allocator,
+            values_writer.depth,
+            values_writer.options,
+            &mut values_writer.buffer,
+        ))?;
+        Ok(self)
+    }
+
+    fn write_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) -> IonResult<()> {
+        let sid = field_name_sid(
+            name,
+            self.allow_unknown_field_name,
+            &self.container_values_writer.options,
+        )?;
+        VarUInt::write_u64(&mut self.container_values_writer.buffer, sid as u64)?;
+        Ok(())
+    }
 }
 
 impl<'value> StructWriter for BinaryStructFieldsWriter_1_0<'value> {
@@ -242,6 +398,230 @@ impl<'value> StructWriter for BinaryStructFieldsWriter_1_0<'value> {
     }
 }
 
+// Returns the symbol ID of a struct field name. Reports an error if the name is a text literal,
+// if it is `$0` and `allow_unknown_field_name` is false, or if `options` does not permit it.
+fn field_name_sid<A: AsRawSymbolTokenRef>(
+    name: A,
+    allow_unknown_field_name: bool,
+    options: &BinaryEncodingOptions_1_0,
+) -> IonResult<SymbolId> {
+    let sid = match name.as_raw_symbol_token_ref() {
+        RawSymbolTokenRef::SymbolId(sid) => sid,
+        RawSymbolTokenRef::Text(text) => {
+            return Err(IonError::Encoding(EncodingError::new(format!(
+                "tried to write a text literal using the v1.0 raw binary writer: '{text}'"
+            ))))
+        }
+    };
+    if sid == 0 && !allow_unknown_field_name {
+        return Err(IonError::Encoding(EncodingError::new(
+            "tried to write a struct field name with symbol ID 0 (unknown text)",
+        )));
+    }
+    options.check_symbol_id(sid)?;
+    Ok(sid)
+}
+
+// A scratch buffer for struct writers that need to see every field before any of them can be
+// emitted. Each field's encoding (field ID followed by value) is appended to the buffer in the
+// order that the fields were written.
+struct BufferedStructFields<'value> {
+    allocator: &'value BumpAllocator,
+    depth: &'value DepthTracker,
+    options: BinaryEncodingOptions_1_0,
+    // Whether field names with symbol ID 0 (unknown text) are permitted.
+    allow_unknown_field_name: bool,
+    buffer: BumpVec<'value, u8>,
+}
+
+impl<'value> BufferedStructFields<'value> {
+    fn new(
+        allocator: &'value BumpAllocator,
+        depth: &'value DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+    ) -> Self {
+        Self {
+            allocator,
+            depth,
+            options,
+            allow_unknown_field_name: false,
+            buffer: BumpVec::new_in(allocator),
+        }
+    }
+
+    // Returns the symbol ID of `name`, or an error if it cannot be written as a field name.
+    fn field_name_sid<A: AsRawSymbolTokenRef>(&self, name: A) -> IonResult<SymbolId> {
+        field_name_sid(name, self.allow_unknown_field_name, &self.options)
+    }
+
+    // Encodes a field with the specified name and value, returning the range of the buffer that
+    // holds its encoding.
+    fn write<V: WriteAsIon>(&mut self, sid: SymbolId, value: V) -> IonResult<Range<usize>> {
+        let start = self.buffer.len();
+        VarUInt::write_u64(&mut self.buffer, sid as u64)?;
+        let annotated_value_writer = BinaryAnnotatableValueWriter_1_0::new_with_options(
+            self.allocator,
+            self.depth,
+            self.options,
+            &mut self.buffer,
+        );
+        value.write_as_ion(annotated_value_writer)?;
+        Ok(start..self.buffer.len())
+    }
+
+    // Consumes the buffer, returning the concatenated encodings found at each of `ranges`.
+    fn into_encoded_fields<'a>(
+        self,
+        ranges: impl Iterator<Item = &'a Range<usize>>,
+    ) -> BumpVec<'value, u8> {
+        let mut encoded = BumpVec::with_capacity_in(self.buffer.len(), self.allocator);
+        for range in ranges {
+            encoded.extend_from_slice(&self.buffer[range.clone()]);
+        }
+        encoded
+    }
+}
+
+/// Buffers the fields of a struct so they can be emitted in a caller-specified order.
+/// See [`BinaryStructWriter_1_0::write_fields_ordered`].
+pub struct BinaryOrderedStructFieldsWriter_1_0<'value, 'order> {
+    // The field name symbol IDs in the order that their fields should be emitted.
+    order: &'order [SymbolId],
+    buffered_fields: BufferedStructFields<'value>,
+    // For each field that has been written, the position of its name in `order` and the range of
+    // the buffer that holds its encoding.
+    fields: BumpVec<'value, (usize, Range<usize>)>,
+}
+
+impl<'value, 'order> BinaryOrderedStructFieldsWriter_1_0<'value, 'order> {
+    pub fn new(
+        allocator: &'value BumpAllocator,
+        depth: &'value DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+        order: &'order [SymbolId],
+    ) -> Self {
+        Self {
+            order,
+            buffered_fields: BufferedStructFields::new(allocator, depth, options),
+            fields: BumpVec::new_in(allocator),
+        }
+    }
+
+    /// Configures whether this writer accepts field names with symbol ID 0 (`$0`). See
+    /// [`BinaryStructFieldsWriter_1_0::allow_unknown_field_name`].
+    pub fn allow_unknown_field_name(&mut self, allow: bool) -> &mut Self {
+        self.buffered_fields.allow_unknown_field_name = allow;
+        self
+    }
+
+    pub fn write<A: AsRawSymbolTokenRef, V: WriteAsIon>(
+        &mut self,
+        name: A,
+        value: V,
+    ) -> IonResult<&mut Self> {
+        let sid = self.buffered_fields.field_name_sid(name)?;
+        let Some(order_index) = self.order.iter().position(|s| *s == sid) else {
+            return Err(IonError::Encoding(EncodingError::new(format!(
+                "field name ${sid} does not appear in the specified field order"
+            ))));
+        };
+        let range = self.buffered_fields.write(sid, value)?;
+        self.fields.push((order_index, range));
+        Ok(self)
+    }
+
+    /// Consumes the writer, returning the encoded fields sorted by their position in `order`.
+    /// Fields that share a name are emitted in the order in which they were written.
+    fn into_encoded_fields(mut self) -> BumpVec<'value, u8> {
+        // `sort_by_key` is stable, so repeated field names retain their relative order.
+        self.fields.sort_by_key(|(order_index, _)| *order_index);
+        self.buffered_fields
+            .into_encoded_fields(self.fields.iter().map(|(_, range)| range))
+    }
+}
+
+impl<'value, 'order> StructWriter for BinaryOrderedStructFieldsWriter_1_0<'value, 'order> {
+    delegate! {
+        to self {
+            fn write<A: AsRawSymbolTokenRef, V: WriteAsIon>(
+                &mut self,
+                name: A,
+                value: V,
+            ) -> IonResult<&mut Self>;
+        }
+    }
+}
+
+/// Buffers the fields of a struct so that only the last value written for each field name is
+/// emitted. See [`BinaryStructWriter_1_0::write_fields_dedup_last_wins`].
+pub struct BinaryDedupStructFieldsWriter_1_0<'value> {
+    // Holds each field's encoding in the order that the fields were written, including values
+    // that were later replaced.
+    buffered_fields: BufferedStructFields<'value>,
+    // For each distinct field name in the order it was first written, the range of the buffer
+    // that holds the encoding of the last field written with that name.
+    fields: BumpVec<'value, Range<usize>>,
+    // Maps each field name that has been written to its entry in `fields`.
+    field_indexes: HashMap<SymbolId, usize>,
+}
+
+impl<'value> BinaryDedupStructFieldsWriter_1_0<'value> {
+    pub fn new(
+        allocator: &'value BumpAllocator,
+        depth: &'value DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+    ) -> Self {
+        Self {
+            buffered_fields: BufferedStructFields::new(allocator, depth, options),
+            fields: BumpVec::new_in(allocator),
+            field_indexes: HashMap::new(),
+        }
+    }
+
+    /// Configures whether this writer accepts field names with symbol ID 0 (`$0`). See
+    /// [`BinaryStructFieldsWriter_1_0::allow_unknown_field_name`].
+    pub fn allow_unknown_field_name(&mut self, allow: bool) -> &mut Self {
+        self.buffered_fields.allow_unknown_field_name = allow;
+        self
+    }
+
+    pub fn write<A: AsRawSymbolTokenRef, V: WriteAsIon>(
+        &mut self,
+        name: A,
+        value: V,
+    ) -> IonResult<&mut Self> {
+        let sid = self.buffered_fields.field_name_sid(name)?;
+        let range = self.buffered_fields.write(sid, value)?;
+        match self.field_indexes.entry(sid) {
+            // The field keeps its original position, but its encoding is replaced.
+            Entry::Occupied(entry) => self.fields[*entry.get()] = range,
+            Entry::Vacant(entry) => {
+                entry.insert(self.fields.len());
+                self.fields.push(range);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Consumes the writer, returning the encoding of the last field written for each name in the
+    /// order in which each name was first written.
+    fn into_encoded_fields(self) -> BumpVec<'value, u8> {
+        self.buffered_fields.into_encoded_fields(self.fields.iter())
+    }
+}
+
+impl<'value> StructWriter for BinaryDedupStructFieldsWriter_1_0<'value> {
+    delegate! {
+        to self {
+            fn write<A: AsRawSymbolTokenRef, V: WriteAsIon>(
+                &mut self,
+                name: A,
+                value: V,
+            ) -> IonResult<&mut Self>;
+        }
+    }
+}
+
 pub struct BinaryStructWriter_1_0<'value, 'top> {
     container_writer: BinaryContainerWriter_1_0<'value, 'top>,
 }
@@ -266,4 +646,54 @@ impl<'value, 'top> BinaryStructWriter_1_0<'value, 'top> {
                 Ok(struct_fields_writer.container_values_writer.buffer)
             })
     }
+
+    /// Writes `null.struct` (`0xDF`) instead of a struct. This is equivalent to calling
+    /// `write_null(IonType::Struct)` on the value writer that produced this struct writer.
+    pub fn write_null_struct(self) -> IonResult<()> {
+        self.container_writer.write_null()
+    }
+
+    /// Writes a struct whose fields are emitted in the order in which their field name symbol IDs
+    /// appear in `order`, regardless of the order in which `write_fn` writes them.
+    ///
+    /// Returns an encoding error if `write_fn` writes a field whose name is not in `order`.
+    pub fn write_fields_ordered<'a, F>(self, order: &[SymbolId], write_fn: F) -> IonResult<()>
+    where
+        'top: 'a,
+        F: for<'o> FnOnce(&mut BinaryOrderedStructFieldsWriter_1_0<'a, 'o>) -> IonResult<()>,
+    {
+        self.container_writer
+            .write_values(|container_values_writer| {
+                let mut ordered_fields_writer = BinaryOrderedStructFieldsWriter_1_0::new(
+                    container_values_writer.allocator,
+                    container_values_writer.depth,
+                    container_values_writer.options,
+                    order,
+                );
+                write_fn(&mut ordered_fields_writer)?;
+                Ok(ordered_fields_writer.into_encoded_fields())
+            })
+    }
+
+    /// Writes a struct in which each field name appears at most once. If `write_fn` writes more
+    /// than one field with the same name, only the last value written for that name is emitted,
+    /// at the position where the name was first written.
+    ///
+    /// The struct's fields are buffered until `write_fn` returns.
+    pub fn write_fields_dedup_last_wins<'a, F>(self, write_fn: F) -> IonResult<()>
+    where
+        'top: 'a,
+        F: FnOnce(&mut BinaryDedupStructFieldsWriter_1_0<'a>) -> IonResult<()>,
+    {
+        self.container_writer
+            .write_values(|container_values_writer| {
+                let mut dedup_fields_writer = BinaryDedupStructFieldsWriter_1_0::new(
+                    container_values_writer.allocator,
+                    container_values_writer.depth,
+                    container_values_writer.options,
+                );
+                write_fn(&mut dedup_fields_writer)?;
+                Ok(dedup_fields_writer.into_encoded_fields())
+            })
+    }
 }
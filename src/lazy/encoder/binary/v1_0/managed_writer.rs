@@ -0,0 +1,148 @@
+use std::io::Write;
+
+use crate::lazy::encoder::annotate::Annotate;
+use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+use crate::lazy::encoder::symbol_table_writer::SymbolTableWriter;
+use crate::lazy::encoder::write_as_ion::{WriteAsIon, WriteAsIonValue};
+use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
+use crate::{IonResult, SymbolId, SymbolTable};
+
+/// A binary Ion 1.0 writer that manages a symbol table on behalf of its caller.
+///
+/// [`LazyRawBinaryWriter_1_0`] can only encode annotations that are symbol IDs. This writer wraps
+/// one and allows annotations to be specified as text; any text that is not already in its symbol
+/// table is interned, and a local symbol table append defining the new symbols is written before
+/// the value that uses them.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::Element;
+/// use ion_rs::lazy::encoder::binary::v1_0::managed_writer::ManagedBinaryWriter_1_0;
+///
+/// let mut writer = ManagedBinaryWriter_1_0::new(Vec::new())?;
+/// writer
+///     .write_annotated(&1, &["foo", "bar"])?
+///     .write_annotated(&2, &["foo"])?;
+///
+/// assert_eq!(Element::read_all(writer.take_buffer())?, Element::read_all("foo::bar::1 foo::2")?);
+///# Ok(())
+///# }
+/// ```
+pub struct ManagedBinaryWriter_1_0<W: Write> {
+    raw_writer: LazyRawBinaryWriter_1_0<W>,
+    symbol_table: SymbolTable,
+}
+
+impl<W: Write> ManagedBinaryWriter_1_0<W> {
+    /// Constructs a new writer and writes an Ion 1.0 Version Marker to output. The writer's
+    /// symbol table initially contains only the system symbols.
+    pub fn new(output: W) -> IonResult<Self> {
+        Ok(Self {
+            raw_writer: LazyRawBinaryWriter_1_0::new(output)?,
+            symbol_table: SymbolTable::new(),
+        })
+    }
+
+    /// Returns the symbol table that is active at the end of the output written so far.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
+    }
+
+    /// Writes the given Rust value to the output stream as a top-level value. As with the raw
+    /// writer, any symbols it contains must be symbol IDs.
+    pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
+        self.raw_writer.write(value)?;
+        Ok(self)
+    }
+
+    /// Writes the given Rust value to the output stream as a top-level value with the provided
+    /// annotations. Annotations with text are written as the symbol ID of that text in the
+    /// writer's symbol table, which is first extended if necessary. Symbol ID annotations are
+    /// written as-is.
+    pub fn write_annotated<V: WriteAsIonValue, A: AsRawSymbolTokenRef>(
+        &mut self,
+        value: &V,
+        annotations: &[A],
+    ) -> IonResult<&mut Self> {
+        let sids = self.resolve_annotations(annotations)?;
+        self.raw_writer.write(value.annotated_with(&sids))?;
+        Ok(self)
+    }
+
+    /// Converts each annotation to a symbol ID, writing a local symbol table append for any text
+    /// that was not already in the symbol table.
+    fn resolve_annotations<A: AsRawSymbolTokenRef>(
+        &mut self,
+        annotations: &[A],
+    ) -> IonResult<Vec<SymbolId>> {
+        // Any text that is interned below is appended to the end of the symbol table.
+        let first_new_sid = self.symbol_table.len();
+        let sids = annotations
+            .iter()
+            .map(|annotation| match annotation.as_raw_symbol_token_ref() {
+                RawSymbolTokenRef::Text(text) => self.symbol_table.intern(text),
+                RawSymbolTokenRef::SymbolId(sid) => sid,
+            })
+            .collect();
+        SymbolTableWriter::new(&mut self.raw_writer)
+            .write_append(&self.symbol_table.symbols()[first_new_sid..])?;
+        Ok(sids)
+    }
+
+    /// Flushes any encoded bytes that have not already been written to the output sink.
+    pub fn flush(&mut self) -> IonResult<()> {
+        self.raw_writer.flush()
+    }
+}
+
+impl ManagedBinaryWriter_1_0<Vec<u8>> {
+    /// Returns all of the bytes encoded so far, leaving the writer's output empty. See
+    /// [`LazyRawBinaryWriter_1_0::take_buffer`].
+    pub fn take_buffer(&mut self) -> Vec<u8> {
+        self.raw_writer.take_buffer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lazy::encoder::binary::v1_0::managed_writer::ManagedBinaryWriter_1_0;
+    use crate::{Element, IonResult, SystemSymbol};
+
+    #[test]
+    fn intern_text_annotations() -> IonResult<()> {
+        let mut writer = ManagedBinaryWriter_1_0::new(Vec::new())?;
+        writer
+            .write_annotated(&"a", &["foo"])?
+            .write_annotated(&"b", &["foo", "bar"])?
+            // `name` is a system symbol, so it does not need to be added to the symbol table.
+            .write_annotated(&"c", &["name"])?
+            .write_annotated(&"d", &[SystemSymbol::Name.sid(), 10])?
+            .write("e")?
+            // Text that repeats within a value is only added to the symbol table once.
+            .write_annotated(&"f", &["baz", "foo", "baz"])?;
+        assert_eq!(writer.symbol_table().sid_for(&"foo"), Some(10));
+        assert_eq!(writer.symbol_table().sid_for(&"bar"), Some(11));
+        assert_eq!(writer.symbol_table().sid_for(&"baz"), Some(12));
+        assert_eq!(writer.symbol_table().len(), 13);
+
+        let expected = Element::read_all(
+            r#"foo::"a" foo::bar::"b" name::"c" name::foo::"d" "e" baz::foo::baz::"f""#,
+        )?;
+        assert_eq!(Element::read_all(writer.take_buffer())?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_tables_are_only_written_for_new_symbols() -> IonResult<()> {
+        let mut writer = ManagedBinaryWriter_1_0::new(Vec::new())?;
+        writer.write_annotated(&1, &["foo"])?;
+        let first = writer.take_buffer();
+        writer.write_annotated(&2, &["foo".to_owned()])?;
+        // The second value reuses `$10` without another symbol table append:
+        // 0xE4 (annotation wrapper, length 4), 0x81 (annotations length 1), 0x8A ($10), 0x21 0x02
+        assert_eq!(writer.take_buffer(), vec![0xE4, 0x81, 0x8A, 0x21, 0x02]);
+        assert_eq!(Element::read_all(first)?, Element::read_all("foo::1")?);
+        Ok(())
+    }
+}
@@ -3,7 +3,8 @@ use crate::lazy::encoder::LazyEncoder;
 use crate::lazy::encoding::BinaryEncoding_1_0;
 use std::io::Write;
 
-mod container_writers;
+pub mod container_writers;
+pub mod managed_writer;
 pub mod value_writer;
 pub mod writer;
 
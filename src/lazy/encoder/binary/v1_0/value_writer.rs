@@ -1,3 +1,4 @@
+use std::io::Write;
 use std::mem;
 
 use bumpalo::collections::Vec as BumpVec;
@@ -8,17 +9,20 @@ use num_bigint::Sign;
 use num_traits::Zero;
 
 use crate::binary::decimal::DecimalBinaryEncoder;
+use crate::binary::non_blocking::type_descriptor::TypeDescriptor;
 use crate::binary::timestamp::TimestampBinaryEncoder;
 use crate::binary::uint;
 use crate::binary::uint::DecodedUInt;
 use crate::binary::var_uint::VarUInt;
+use crate::constants::v1_0;
+use crate::constants::v1_0::system_symbol_ids;
 use crate::lazy::encoder::binary::v1_0::container_writers::{
-    BinaryContainerWriter_1_0, BinaryListValuesWriter_1_0, BinaryListWriter_1_0,
-    BinarySExpValuesWriter_1_0, BinarySExpWriter_1_0, BinaryStructFieldsWriter_1_0,
-    BinaryStructWriter_1_0,
+    BinaryContainerWriter_1_0, BinaryDedupStructFieldsWriter_1_0, BinaryListValuesWriter_1_0,
+    BinaryListWriter_1_0, BinaryOrderedStructFieldsWriter_1_0, BinarySExpValuesWriter_1_0,
+    BinarySExpWriter_1_0, BinaryStructFieldsWriter_1_0, BinaryStructWriter_1_0, DepthTracker,
 };
 use crate::lazy::encoder::private::Sealed;
-use crate::lazy::encoder::value_writer::{AnnotatableValueWriter, ValueWriter};
+use crate::lazy::encoder::value_writer::{AnnotatableValueWriter, FloatWriteOptions, ValueWriter};
 use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
 use crate::result::{EncodingError, IonFailure};
 use crate::types::integer::IntData;
@@ -28,18 +32,80 @@ use crate::{Decimal, Int, IonError, IonResult, IonType, RawSymbolTokenRef, Symbo
 /// Larger length values will need to be written as a VarUInt following the type descriptor.
 pub(crate) const MAX_INLINE_LENGTH: usize = 13;
 
+/// Settings that control how binary Ion 1.0 values are encoded. A [`LazyRawBinaryWriter_1_0`]
+/// shares its options with each of the value and container writers that it creates.
+///
+/// [`LazyRawBinaryWriter_1_0`]: crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BinaryEncodingOptions_1_0 {
+    // Whether each value's annotations are encoded in the reverse of the order provided.
+    pub(crate) reverse_annotation_order: bool,
+    // Whether lengths that would fit in a type descriptor's length nibble are instead written as a
+    // VarUInt following a type descriptor whose length nibble is `0xE`.
+    pub(crate) always_varuint_length: bool,
+    // Whether writing anything that would require a local symbol table is an error.
+    pub(crate) assert_no_symbol_table: bool,
+}
+
+impl BinaryEncodingOptions_1_0 {
+    /// Writes a type descriptor with the provided `type_code` (its high nibble) followed, if
+    /// needed, by a VarUInt representation of `length`.
+    pub(crate) fn write_header(
+        &self,
+        buffer: &mut BumpVec<'_, u8>,
+        type_code: u8,
+        length: usize,
+    ) -> IonResult<()> {
+        if length <= MAX_INLINE_LENGTH && !self.always_varuint_length {
+            buffer.push(type_code | length as u8);
+        } else {
+            buffer.push(type_code | 0x0E);
+            VarUInt::write_u64(buffer, length as u64)?;
+        }
+        Ok(())
+    }
+
+    /// If the writer has asserted that no symbol table is needed, returns an encoding error when
+    /// `sid` is not in the system symbol table.
+    pub(crate) fn check_symbol_id(&self, sid: SymbolId) -> IonResult<()> {
+        if self.assert_no_symbol_table && sid >= v1_0::SYSTEM_SYMBOLS.len() {
+            return IonResult::encoding_error(format!(
+                "symbol ID ${sid} is not a system symbol, but the writer asserts that no symbol \
+                 table is needed"
+            ));
+        }
+        Ok(())
+    }
+}
+
 pub struct BinaryValueWriter_1_0<'value, 'top> {
     allocator: &'top BumpAllocator,
+    depth: &'top DepthTracker,
+    options: BinaryEncodingOptions_1_0,
     encoding_buffer: &'value mut BumpVec<'top, u8>,
 }
 
 impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
+    /// Constructs a writer that encodes to `encoding_buffer` using the default
+    /// [`BinaryEncodingOptions_1_0`].
     pub fn new(
         allocator: &'top BumpAllocator,
         encoding_buffer: &'value mut BumpVec<'top, u8>,
+    ) -> BinaryValueWriter_1_0<'value, 'top> {
+        let depth = allocator.alloc(DepthTracker::new());
+        Self::new_with_options(allocator, depth, Default::default(), encoding_buffer)
+    }
+
+    pub(crate) fn new_with_options(
+        allocator: &'top BumpAllocator,
+        depth: &'top DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+        encoding_buffer: &'value mut BumpVec<'top, u8>,
     ) -> BinaryValueWriter_1_0<'value, 'top> {
         BinaryValueWriter_1_0 {
             allocator,
+            depth,
+            options,
             encoding_buffer,
         }
     }
@@ -59,36 +125,22 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
     }
 
     pub fn write_symbol_id(mut self, symbol_id: SymbolId) -> IonResult<()> {
+        self.options.check_symbol_id(symbol_id)?;
         const SYMBOL_BUFFER_SIZE: usize = mem::size_of::<u64>();
         let mut buffer = [0u8; SYMBOL_BUFFER_SIZE];
         let mut writer = std::io::Cursor::new(&mut buffer).writer();
         let encoded_length = DecodedUInt::write_u64(&mut writer, symbol_id as u64)?;
 
-        let type_descriptor: u8;
-        if encoded_length <= MAX_INLINE_LENGTH {
-            type_descriptor = 0x70 | encoded_length as u8;
-            self.push_byte(type_descriptor);
-        } else {
-            type_descriptor = 0x7E;
-            self.push_byte(type_descriptor);
-            VarUInt::write_u64(self.encoding_buffer, encoded_length as u64)?;
-        }
+        self.options
+            .write_header(self.encoding_buffer, 0x70, encoded_length)?;
         let raw_buffer = writer.into_inner().into_inner();
         self.push_bytes(&raw_buffer[..encoded_length]);
         Ok(())
     }
 
     pub fn write_lob(mut self, value: &[u8], type_code: u8) -> IonResult<()> {
-        let encoded_length = value.len();
-        let type_descriptor: u8;
-        if encoded_length <= MAX_INLINE_LENGTH {
-            type_descriptor = type_code | encoded_length as u8;
-            self.push_byte(type_descriptor);
-        } else {
-            type_descriptor = type_code | 0x0E;
-            self.push_byte(type_descriptor);
-            VarUInt::write_u64(self.encoding_buffer, encoded_length as u64)?;
-        }
+        self.options
+            .write_header(self.encoding_buffer, type_code, value.len())?;
         self.push_bytes(value);
         Ok(())
     }
@@ -109,6 +161,14 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
             IonType::SExp => 0xCF,
             IonType::Struct => 0xDF,
         };
+        // Guard against an `IonType` being mapped to the wrong descriptor byte.
+        debug_assert!(
+            {
+                let descriptor = TypeDescriptor::from_byte(byte);
+                descriptor.is_null() && descriptor.ion_type == Some(ion_type)
+            },
+            "type descriptor 0x{byte:02X} is not a null.{ion_type}"
+        );
         self.push_byte(byte);
         Ok(())
     }
@@ -128,12 +188,13 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
         // The encoded length will never be larger than 8 bytes, so it will
         // always fit in the Int's type descriptor byte.
         let encoded_length = bytes_to_write.len();
-        let type_descriptor: u8 = if value >= 0 {
-            0x20 | (encoded_length as u8)
+        let type_code: u8 = if value >= 0 { 0x20 } else { 0x30 };
+        if self.options.always_varuint_length {
+            self.options
+                .write_header(self.encoding_buffer, type_code, encoded_length)?;
         } else {
-            0x30 | (encoded_length as u8)
-        };
-        self.push_byte(type_descriptor);
+            self.push_byte(type_code | (encoded_length as u8));
+        }
         self.push_bytes(bytes_to_write);
 
         Ok(())
@@ -148,32 +209,74 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
 
         // From here on, `value` is a `BigInt`.
         if value.is_zero() {
-            self.push_byte(0x20);
-            return Ok(());
+            return self.options.write_header(self.encoding_buffer, 0x20, 0);
         }
 
         let (sign, magnitude_be_bytes) = value.to_bytes_be();
 
-        let mut type_descriptor: u8 = match sign {
+        let type_code: u8 = match sign {
             Sign::Plus | Sign::NoSign => 0x20,
             Sign::Minus => 0x30,
         };
 
         let encoded_length = magnitude_be_bytes.len();
-        if encoded_length <= 13 {
-            type_descriptor |= encoded_length as u8;
-            self.push_byte(type_descriptor);
-        } else {
-            type_descriptor |= 0xEu8;
-            self.push_byte(type_descriptor);
-            VarUInt::write_u64(self.encoding_buffer, encoded_length as u64)?;
-        }
+        self.options
+            .write_header(self.encoding_buffer, type_code, encoded_length)?;
 
         self.push_bytes(magnitude_be_bytes.as_slice());
 
         Ok(())
     }
 
+    /// Writes `value` as an Ion int, as [`Self::write_int`] does, if its magnitude can be encoded
+    /// in `byte_width` or fewer bytes. Otherwise, returns an
+    /// [`IonError::IllegalOperation`](crate::IonError::IllegalOperation) and writes nothing.
+    ///
+    /// Zero has an empty magnitude and fits in any width.
+    pub fn try_write_fixed_int(self, value: &Int, byte_width: usize) -> IonResult<()> {
+        let magnitude_length = match &value.data {
+            IntData::I64(i) => uint::encode_u64(i.unsigned_abs()).as_bytes().len(),
+            IntData::BigInt(i) => ((i.bits() + 7) / 8) as usize,
+        };
+        if magnitude_length > byte_width {
+            return IonResult::illegal_operation(format!(
+                "the int {value} requires {magnitude_length} bytes, which exceeds the maximum of {byte_width}"
+            ));
+        }
+        self.write_int(value)
+    }
+
+    /// Writes `value` as an Ion int. Unlike [`Self::write_int`], values outside the range of an
+    /// `i64` are encoded without allocating a `BigInt`; the output is otherwise identical.
+    pub fn write_i128(self, value: i128) -> IonResult<()> {
+        if let Ok(value) = i64::try_from(value) {
+            return self.write_i64(value);
+        }
+        let type_code: u8 = if value >= 0 { 0x20 } else { 0x30 };
+        self.write_int_magnitude(type_code, value.unsigned_abs())
+    }
+
+    /// Writes `value` as an Ion int. Unlike [`Self::write_int`], values outside the range of an
+    /// `i64` are encoded without allocating a `BigInt`; the output is otherwise identical.
+    pub fn write_u128(self, value: u128) -> IonResult<()> {
+        if let Ok(value) = i64::try_from(value) {
+            return self.write_i64(value);
+        }
+        self.write_int_magnitude(0x20, value)
+    }
+
+    /// Writes an int with the provided type code (which encodes its sign) and nonzero magnitude.
+    fn write_int_magnitude(mut self, type_code: u8, magnitude: u128) -> IonResult<()> {
+        let magnitude_bytes = magnitude.to_be_bytes();
+        // Skip the leading zero bytes.
+        let first_occupied_byte = (magnitude.leading_zeros() / 8) as usize;
+        let bytes_to_write = &magnitude_bytes[first_occupied_byte..];
+        self.options
+            .write_header(self.encoding_buffer, type_code, bytes_to_write.len())?;
+        self.push_bytes(bytes_to_write);
+        Ok(())
+    }
+
     pub fn write_f32(mut self, value: f32) -> IonResult<()> {
         if value == 0f32 && !value.is_sign_negative() {
             self.push_byte(0x40);
@@ -196,33 +299,74 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
         Ok(())
     }
 
+    /// Writes `value` using the 4-byte float encoding if it can be converted to an `f32` without
+    /// loss, and the 8-byte encoding otherwise. The infinities and NaNs whose payload fits in an
+    /// `f32` are written using 4 bytes. Negative zero keeps its sign; only positive zero is
+    /// written as the empty float.
+    ///
+    /// This is equivalent to [`write_f64_with`](ValueWriter::write_f64_with) with
+    /// [`FloatWriteOptions::minimal_width`] enabled.
+    pub fn write_float_compact(self, value: f64) -> IonResult<()> {
+        let options = FloatWriteOptions {
+            minimal_width: true,
+            ..FloatWriteOptions::default()
+        };
+        self.write_f64_with(value, options)
+    }
+
+    /// Writes `value` as a decimal. A coefficient of negative zero is written with its sign bit
+    /// set, so `-0d0` and `0d0` remain distinct when the value is read back.
     pub fn write_decimal(self, value: &Decimal) -> IonResult<()> {
+        if self.options.always_varuint_length {
+            let mut body = BumpVec::new_in(self.allocator);
+            body.encode_decimal(value)?;
+            return self.write_header_and_body(0x50, &body);
+        }
         let _encoded_size = self.encoding_buffer.encode_decimal_value(value)?;
         Ok(())
     }
 
     pub fn write_timestamp(self, value: &Timestamp) -> IonResult<()> {
+        if self.options.always_varuint_length {
+            let mut body = BumpVec::new_in(self.allocator);
+            body.encode_timestamp(value)?;
+            return self.write_header_and_body(0x60, &body);
+        }
         let _ = self.encoding_buffer.encode_timestamp_value(value)?;
         Ok(())
     }
 
+    fn write_header_and_body(mut self, type_code: u8, body: &[u8]) -> IonResult<()> {
+        self.options
+            .write_header(self.encoding_buffer, type_code, body.len())?;
+        self.push_bytes(body);
+        Ok(())
+    }
+
     pub fn write_string<A: AsRef<str>>(mut self, value: A) -> IonResult<()> {
         let text: &str = value.as_ref();
         let encoded_length = text.len(); // The number of utf8 bytes
 
-        let type_descriptor: u8;
-        if encoded_length <= MAX_INLINE_LENGTH {
-            type_descriptor = 0x80 | encoded_length as u8;
-            self.push_byte(type_descriptor);
-        } else {
-            type_descriptor = 0x8E;
-            self.push_byte(type_descriptor);
-            VarUInt::write_u64(self.encoding_buffer, encoded_length as u64)?;
-        }
+        self.options
+            .write_header(self.encoding_buffer, 0x80, encoded_length)?;
         self.push_bytes(text.as_bytes());
         Ok(())
     }
 
+    /// Writes `utf8` as a string without first converting it to a `&str`. This allows bytes that
+    /// have already been validated elsewhere to be written without validating them again.
+    ///
+    /// The caller must ensure that `utf8` is valid UTF-8; if it is not, the output will be
+    /// rejected by readers. This is only checked in debug builds.
+    pub fn write_string_bytes(self, utf8: &[u8]) -> IonResult<()> {
+        debug_assert!(
+            std::str::from_utf8(utf8).is_ok(),
+            "write_string_bytes was passed invalid UTF-8"
+        );
+        // The string type descriptor's high nibble is type code 8
+        self.write_lob(utf8, 0x80)
+    }
+
     pub fn write_symbol<A: AsRawSymbolTokenRef>(self, value: A) -> IonResult<()> {
         match value.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(sid) => self.write_symbol_id(sid),
@@ -232,12 +376,32 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
         }
     }
 
+    /// Writes `null.clob`. This is equivalent to `write_null(IonType::Clob)`.
+    ///
+    /// A null clob is distinct from an empty one; to write a clob with no bytes, use
+    /// [`write_clob`](Self::write_clob) with an empty slice.
+    pub fn write_null_clob(self) -> IonResult<()> {
+        self.write_null(IonType::Clob)
+    }
+
+    /// Writes `null.blob`. This is equivalent to `write_null(IonType::Blob)`.
+    ///
+    /// A null blob is distinct from an empty one; to write a blob with no bytes, use
+    /// [`write_blob`](Self::write_blob) with an empty slice.
+    pub fn write_null_blob(self) -> IonResult<()> {
+        self.write_null(IonType::Blob)
+    }
+
+    /// Writes `value` as a clob. An empty slice produces an empty clob (`{{""}}`, encoded as
+    /// `0x90`), not `null.clob`.
     pub fn write_clob<A: AsRef<[u8]>>(self, value: A) -> IonResult<()> {
         let bytes: &[u8] = value.as_ref();
         // The clob type descriptor's high nibble is type code 9
         self.write_lob(bytes, 0x90)
     }
 
+    /// Writes `value` as a blob. An empty slice produces an empty blob (`{{}}`, encoded as
+    /// `0xA0`), not `null.blob`.
     pub fn write_blob<A: AsRef<[u8]>>(self, value: A) -> IonResult<()> {
         let bytes: &[u8] = value.as_ref();
         // The blob type descriptor's high nibble is type code 10 (0xA)
@@ -246,31 +410,59 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
 
     fn list_writer(&mut self) -> BinaryListWriter_1_0<'_, 'top> {
         const LIST_TYPE_CODE: u8 = 0xB0;
-        BinaryListWriter_1_0::new(BinaryContainerWriter_1_0::new(
+        BinaryListWriter_1_0::new(BinaryContainerWriter_1_0::new_with_options(
             LIST_TYPE_CODE,
             self.allocator,
+            self.depth,
+            self.options,
             self.encoding_buffer,
         ))
     }
 
     fn sexp_writer(&mut self) -> BinarySExpWriter_1_0<'_, 'top> {
         const SEXP_TYPE_CODE: u8 = 0xC0;
-        BinarySExpWriter_1_0::new(BinaryContainerWriter_1_0::new(
+        BinarySExpWriter_1_0::new(BinaryContainerWriter_1_0::new_with_options(
             SEXP_TYPE_CODE,
             self.allocator,
+            self.depth,
+            self.options,
             self.encoding_buffer,
         ))
     }
 
-    fn struct_writer(&mut self) -> BinaryStructWriter_1_0<'_, 'top> {
+    /// Returns a [`BinaryStructWriter_1_0`] that will write this value as a struct. This is useful
+    /// when the caller needs more control than [`write_struct`](ValueWriter::write_struct) offers,
+    /// such as deciding to write `null.struct` via
+    /// [`write_null_struct`](BinaryStructWriter_1_0::write_null_struct) instead.
+    pub fn struct_writer(self) -> BinaryStructWriter_1_0<'value, 'top> {
         const STRUCT_TYPE_CODE: u8 = 0xD0;
-        BinaryStructWriter_1_0::new(BinaryContainerWriter_1_0::new(
+        BinaryStructWriter_1_0::new(BinaryContainerWriter_1_0::new_with_options(
             STRUCT_TYPE_CODE,
             self.allocator,
+            self.depth,
+            self.options,
             self.encoding_buffer,
         ))
     }
 
+    /// Writes a struct whose fields are emitted in the order in which their field name symbol IDs
+    /// appear in `order`. See [`BinaryStructWriter_1_0::write_fields_ordered`].
+    pub fn write_struct_ordered<F>(self, order: &[SymbolId], struct_fn: F) -> IonResult<()>
+    where
+        F: for<'a, 'o> FnOnce(&mut BinaryOrderedStructFieldsWriter_1_0<'a, 'o>) -> IonResult<()>,
+    {
+        self.struct_writer().write_fields_ordered(order, struct_fn)
+    }
+
+    /// Writes a struct in which only the last value written for each field name is emitted.
+    /// See [`BinaryStructWriter_1_0::write_fields_dedup_last_wins`].
+    pub fn write_struct_dedup_last_wins<F>(self, struct_fn: F) -> IonResult<()>
+    where
+        F: for<'a> FnOnce(&mut BinaryDedupStructFieldsWriter_1_0<'a>) -> IonResult<()>,
+    {
+        self.struct_writer().write_fields_dedup_last_wins(struct_fn)
+    }
+
     fn write_list<
         F: for<'a> FnOnce(&mut <Self as ValueWriter>::ListWriter<'a>) -> IonResult<()>,
     >(
@@ -290,7 +482,7 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
     fn write_struct<
         F: for<'a> FnOnce(&mut <Self as ValueWriter>::StructWriter<'a>) -> IonResult<()>,
     >(
-        mut self,
+        self,
         struct_fn: F,
     ) -> IonResult<()> {
         self.struct_writer().write_fields(struct_fn)
@@ -310,6 +502,8 @@ impl<'value, 'top> ValueWriter for BinaryValueWriter_1_0<'value, 'top> {
             fn write_bool(self, value: bool) -> IonResult<()>;
             fn write_i64(self, value: i64) -> IonResult<()>;
             fn write_int(self, value: &Int) -> IonResult<()>;
+            fn write_i128(self, value: i128) -> IonResult<()>;
+            fn write_u128(self, value: u128) -> IonResult<()>;
             fn write_f32(self, value: f32) -> IonResult<()>;
             fn write_f64(self, value: f64) -> IonResult<()>;
             fn write_decimal(self, value: &Decimal) -> IonResult<()>;
@@ -338,19 +532,70 @@ impl<'value, 'top> ValueWriter for BinaryValueWriter_1_0<'value, 'top> {
 
 pub struct BinaryAnnotatableValueWriter_1_0<'value, 'top> {
     allocator: &'top BumpAllocator,
+    depth: &'top DepthTracker,
+    options: BinaryEncodingOptions_1_0,
     encoding_buffer: &'value mut BumpVec<'top, u8>,
+    // A buffer in which an annotated value can be encoded before it is wrapped, if the writer
+    // provided one. See `BinaryAnnotationsWrapperWriter::encode_annotated_sized`.
+    scratch_buffer: Option<&'value mut BumpVec<'top, u8>>,
 }
 
 impl<'value, 'top> BinaryAnnotatableValueWriter_1_0<'value, 'top> {
+    /// Constructs a writer that encodes to `encoding_buffer` using the default
+    /// [`BinaryEncodingOptions_1_0`].
     pub fn new(
         allocator: &'top BumpAllocator,
         encoding_buffer: &'value mut BumpVec<'top, u8>,
+    ) -> BinaryAnnotatableValueWriter_1_0<'value, 'top> {
+        let depth = allocator.alloc(DepthTracker::new());
+        Self::new_with_options(allocator, depth, Default::default(), encoding_buffer)
+    }
+
+    pub(crate) fn new_with_options(
+        allocator: &'top BumpAllocator,
+        depth: &'top DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+        encoding_buffer: &'value mut BumpVec<'top, u8>,
     ) -> BinaryAnnotatableValueWriter_1_0<'value, 'top> {
         BinaryAnnotatableValueWriter_1_0 {
             allocator,
+            depth,
+            options,
             encoding_buffer,
+            scratch_buffer: None,
         }
     }
+
+    /// Configures the writer to encode an annotated value in `scratch_buffer` before copying it
+    /// into its annotations wrapper, rather than allocating a new buffer for it. Any data already
+    /// in `scratch_buffer` is discarded.
+    pub(crate) fn with_scratch_buffer(
+        mut self,
+        scratch_buffer: &'value mut BumpVec<'top, u8>,
+    ) -> Self {
+        self.scratch_buffer = Some(scratch_buffer);
+        self
+    }
+
+    /// Like [`with_annotations`](AnnotatableValueWriter::with_annotations), but takes the
+    /// annotations as an iterator of symbol IDs rather than a borrowed slice. The symbol IDs are
+    /// copied into the writer's bump allocator, so `ids` can be a transient iterator (for example,
+    /// a `map` over some other collection). The encoded output is identical to that of
+    /// `with_annotations`.
+    ///
+    /// This is not part of [`AnnotatableValueWriter`] because the annotated value writer it
+    /// returns borrows its annotations; a writer needs somewhere to store them, and only the
+    /// binary writers have a bump allocator to copy them into.
+    pub fn with_annotation_ids(
+        self,
+        ids: impl IntoIterator<Item = SymbolId>,
+    ) -> BinaryAnnotationsWrapperWriter<'value, 'top, SymbolId>
+    where
+        'top: 'value,
+    {
+        let annotations = BumpVec::from_iter_in(ids, self.allocator).into_bump_slice();
+        self.with_annotations(annotations)
+    }
 }
 
 impl<'value, 'top: 'value> AnnotatableValueWriter
@@ -366,33 +611,71 @@ impl<'value, 'top: 'value> AnnotatableValueWriter
     where
         Self: 'a,
     {
-        BinaryAnnotationsWrapperWriter::new(self.allocator, annotations, self.encoding_buffer)
+        let mut annotations_writer = BinaryAnnotationsWrapperWriter::new_with_options(
+            self.allocator,
+            self.depth,
+            self.options,
+            annotations,
+            self.encoding_buffer,
+        );
+        annotations_writer.scratch_buffer = self.scratch_buffer;
+        annotations_writer
     }
 
     #[inline(always)]
     fn without_annotations(self) -> BinaryValueWriter_1_0<'value, 'top> {
-        BinaryValueWriter_1_0::new(self.allocator, self.encoding_buffer)
+        BinaryValueWriter_1_0::new_with_options(
+            self.allocator,
+            self.depth,
+            self.options,
+            self.encoding_buffer,
+        )
     }
 }
 
 pub struct BinaryAnnotationsWrapperWriter<'value, 'top, SymbolType: AsRawSymbolTokenRef> {
     annotations: &'value [SymbolType],
     allocator: &'top BumpAllocator,
+    depth: &'top DepthTracker,
+    options: BinaryEncodingOptions_1_0,
     output_buffer: &'value mut BumpVec<'top, u8>,
+    scratch_buffer: Option<&'value mut BumpVec<'top, u8>>,
 }
 
 impl<'value, 'top, SymbolType: AsRawSymbolTokenRef>
     BinaryAnnotationsWrapperWriter<'value, 'top, SymbolType>
 {
+    /// Constructs a writer that encodes a value with the specified `annotations` to
+    /// `encoding_buffer` using the default [`BinaryEncodingOptions_1_0`].
     pub fn new(
         allocator: &'top BumpAllocator,
         annotations: &'value [SymbolType],
         encoding_buffer: &'value mut BumpVec<'top, u8>,
+    ) -> BinaryAnnotationsWrapperWriter<'value, 'top, SymbolType> {
+        let depth = allocator.alloc(DepthTracker::new());
+        Self::new_with_options(
+            allocator,
+            depth,
+            Default::default(),
+            annotations,
+            encoding_buffer,
+        )
+    }
+
+    pub(crate) fn new_with_options(
+        allocator: &'top BumpAllocator,
+        depth: &'top DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+        annotations: &'value [SymbolType],
+        encoding_buffer: &'value mut BumpVec<'top, u8>,
     ) -> BinaryAnnotationsWrapperWriter<'value, 'top, SymbolType> {
         BinaryAnnotationsWrapperWriter {
             annotations,
             allocator,
+            depth,
+            options,
             output_buffer: encoding_buffer,
+            scratch_buffer: None,
         }
     }
 }
@@ -404,59 +687,109 @@ impl<'value, 'top, SymbolType: AsRawSymbolTokenRef>
     where
         F: for<'a> FnOnce(BinaryAnnotatedValueWriter_1_0<'a, 'top>) -> IonResult<()>,
     {
+        self.encode_annotated_sized(encode_value_fn)?;
+        Ok(())
+    }
+
+    // Like `encode_annotated`, but returns the number of bytes written to the output buffer,
+    // including the annotations wrapper's header and annotations sequence.
+    fn encode_annotated_sized<F>(mut self, encode_value_fn: F) -> IonResult<usize>
+    where
+        F: for<'a> FnOnce(BinaryAnnotatedValueWriter_1_0<'a, 'top>) -> IonResult<()>,
+    {
+        if self.options.assert_no_symbol_table && self.depth.current_depth() == 0 {
+            if let Some(RawSymbolTokenRef::SymbolId(system_symbol_ids::ION_SYMBOL_TABLE)) = self
+                .annotations
+                .first()
+                .map(|annotation| annotation.as_raw_symbol_token_ref())
+            {
+                return IonResult::encoding_error(
+                    "cannot write a local symbol table; the writer asserts that none is needed",
+                );
+            }
+        }
+        // The value is encoded in a scratch buffer so its length is known before the wrapper's
+        // header is written. If the writer provided a buffer that can be reused from one value to
+        // the next, we use it; otherwise, we allocate a new one.
         let allocator = self.allocator;
-        let buffer = allocator.alloc_with(|| BumpVec::new_in(allocator));
+        let buffer = match self.scratch_buffer.take() {
+            Some(scratch_buffer) => {
+                scratch_buffer.clear();
+                scratch_buffer
+            }
+            None => allocator.alloc_with(|| BumpVec::new_in(allocator)),
+        };
         {
-            let annotated_value_writer =
-                BinaryAnnotatedValueWriter_1_0::new(self.allocator, buffer);
+            let annotated_value_writer = BinaryAnnotatedValueWriter_1_0::new_with_options(
+                self.allocator,
+                self.depth,
+                self.options,
+                buffer,
+            );
             encode_value_fn(annotated_value_writer)?;
         }
-        self.annotate_encoded_value(buffer.as_slice())
+        self.annotate_encoded_value(buffer)
     }
 
-    fn annotate_encoded_value(self, encoded_value: &[u8]) -> IonResult<()> {
-        let mut encoded_annotations_sequence = BumpVec::new_in(self.allocator);
-        self.encode_annotations_sequence(&mut encoded_annotations_sequence)?;
-
-        let mut encoded_annotations_sequence_length = BumpVec::new_in(self.allocator);
-        VarUInt::write_u64(
-            &mut encoded_annotations_sequence_length,
-            encoded_annotations_sequence.len() as u64,
-        )?;
+    // Writes an annotations wrapper around the value encoded in `buffer` to the output buffer. The
+    // annotations sequence and its length are encoded in `buffer` following the value.
+    fn annotate_encoded_value(self, buffer: &mut BumpVec<'_, u8>) -> IonResult<usize> {
+        let start_length = self.output_buffer.len();
+        let value_length = buffer.len();
+        self.encode_annotations_sequence(buffer)?;
+        let sequence_length = buffer.len() - value_length;
+        VarUInt::write_u64(buffer, sequence_length as u64)?;
 
-        let total_length = encoded_annotations_sequence.len()
-            + encoded_annotations_sequence_length.len()
-            + encoded_value.len();
+        let total_length = buffer.len();
+        let (encoded_value, annotations) = buffer.split_at(value_length);
+        let (encoded_annotations_sequence, encoded_annotations_sequence_length) =
+            annotations.split_at(sequence_length);
 
-        if total_length <= MAX_INLINE_LENGTH {
-            self.output_buffer.push(0xE0u8 | total_length as u8);
-        } else {
-            self.output_buffer.push(0xEEu8);
-            VarUInt::write_u64(self.output_buffer, total_length as u64)?;
-        }
+        self.options
+            .write_header(self.output_buffer, 0xE0, total_length)?;
 
         self.output_buffer
-            .extend_from_slice(encoded_annotations_sequence_length.as_slice());
+            .extend_from_slice(encoded_annotations_sequence_length);
         self.output_buffer
-            .extend_from_slice(encoded_annotations_sequence.as_slice());
+            .extend_from_slice(encoded_annotations_sequence);
         self.output_buffer.extend_from_slice(encoded_value);
 
-        Ok(())
+        Ok(self.output_buffer.len() - start_length)
     }
 
     fn encode_annotations_sequence(&self, buffer: &'_ mut BumpVec<'_, u8>) -> IonResult<()> {
-        for annotation in self.annotations {
-            let RawSymbolTokenRef::SymbolId(sid) = annotation.as_raw_symbol_token_ref() else {
-                return Err(IonError::Encoding(EncodingError::new(
-                    "binary Ion 1.0 cannot encode text literal annotations",
-                )));
-            };
-            VarUInt::write_u64(buffer, sid as u64)?;
+        let mut encode_annotation = |annotation: &SymbolType| {
+            if let RawSymbolTokenRef::SymbolId(sid) = annotation.as_raw_symbol_token_ref() {
+                self.options.check_symbol_id(sid)?;
+            }
+            encode_annotation_sid(&mut *buffer, annotation)
+        };
+        if self.options.reverse_annotation_order {
+            self.annotations
+                .iter()
+                .rev()
+                .try_for_each(&mut encode_annotation)
+        } else {
+            self.annotations.iter().try_for_each(&mut encode_annotation)
         }
-        Ok(())
     }
 }
 
+/// Writes `annotation`'s symbol ID to `sink` as a VarUInt, as it would appear in an annotations
+/// sequence.
+pub(crate) fn encode_annotation_sid<W: Write>(
+    sink: &mut W,
+    annotation: impl AsRawSymbolTokenRef,
+) -> IonResult<()> {
+    let RawSymbolTokenRef::SymbolId(sid) = annotation.as_raw_symbol_token_ref() else {
+        return Err(IonError::Encoding(EncodingError::new(
+            "binary Ion 1.0 cannot encode text literal annotations",
+        )));
+    };
+    VarUInt::write_u64(sink, sid as u64)?;
+    Ok(())
+}
+
 impl<'value, 'top, SymbolType: AsRawSymbolTokenRef> Sealed
     for BinaryAnnotationsWrapperWriter<'value, 'top, SymbolType>
 {
@@ -465,9 +798,18 @@ impl<'value, 'top, SymbolType: AsRawSymbolTokenRef> Sealed
 
 /// Takes a series of `TYPE => METHOD` pairs, generating a function for each that calls the
 /// corresponding value writer method and then prefixes the encoded result with an annotations wrapper.
+///
+/// A pair written as `sized TYPE => METHOD as SIZED_METHOD` instead generates a public
+/// `SIZED_METHOD` that returns the total number of bytes written, including the wrapper.
 macro_rules! delegate_and_annotate {
     // End of iteration
     () => {};
+    (sized $value_type:ty => $method:ident as $sized_method:ident, $($rest:tt)*) => {
+        pub fn $sized_method(self, value: $value_type) -> IonResult<usize> {
+            self.encode_annotated_sized(|value_writer| value_writer.$method(value))
+        }
+        delegate_and_annotate!($($rest)*);
+    };
     // Recurses one argument pair at a time
     ($value_type:ty => $method:ident, $($rest:tt)*) => {
         fn $method(self, value: $value_type) -> IonResult<()> {
@@ -477,6 +819,17 @@ macro_rules! delegate_and_annotate {
     };
 }
 
+impl<'value, 'top, SymbolType: AsRawSymbolTokenRef>
+    BinaryAnnotationsWrapperWriter<'value, 'top, SymbolType>
+{
+    // Variants of `write_decimal` and `write_timestamp` that report how many bytes the annotated
+    // value occupies in the output, which is useful when framing values.
+    delegate_and_annotate!(
+        sized &Decimal => write_decimal as write_decimal_sized,
+        sized &Timestamp => write_timestamp as write_timestamp_sized,
+    );
+}
+
 impl<'value, 'top, SymbolType: AsRawSymbolTokenRef> ValueWriter
     for BinaryAnnotationsWrapperWriter<'value, 'top, SymbolType>
 {
@@ -522,17 +875,41 @@ impl<'value, 'top, SymbolType: AsRawSymbolTokenRef> ValueWriter
 
 pub struct BinaryAnnotatedValueWriter_1_0<'value, 'top> {
     allocator: &'top BumpAllocator,
+    depth: &'top DepthTracker,
+    options: BinaryEncodingOptions_1_0,
     // Note that unlike the BinaryValueWriter_1_0, the borrow and the BumpVec here have the same
     // lifetime. This allows this type to be passed as a closure argument.
     buffer: &'value mut BumpVec<'top, u8>,
 }
 
 impl<'value, 'top> BinaryAnnotatedValueWriter_1_0<'value, 'top> {
+    /// Constructs a writer that encodes to `buffer` using the default
+    /// [`BinaryEncodingOptions_1_0`].
     pub fn new(allocator: &'top BumpAllocator, buffer: &'value mut BumpVec<'top, u8>) -> Self {
-        Self { allocator, buffer }
+        let depth = allocator.alloc(DepthTracker::new());
+        Self::new_with_options(allocator, depth, Default::default(), buffer)
+    }
+
+    pub(crate) fn new_with_options(
+        allocator: &'top BumpAllocator,
+        depth: &'top DepthTracker,
+        options: BinaryEncodingOptions_1_0,
+        buffer: &'value mut BumpVec<'top, u8>,
+    ) -> Self {
+        Self {
+            allocator,
+            depth,
+            options,
+            buffer,
+        }
     }
     pub(crate) fn value_writer(&mut self) -> BinaryValueWriter_1_0<'_, 'top> {
-        BinaryValueWriter_1_0::new(self.allocator, self.buffer)
+        BinaryValueWriter_1_0::new_with_options(
+            self.allocator,
+            self.depth,
+            self.options,
+            self.buffer,
+        )
     }
 
     pub(crate) fn buffer(&self) -> &[u8] {
@@ -552,6 +929,8 @@ impl<'value, 'top: 'value> ValueWriter for BinaryAnnotatedValueWriter_1_0<'value
             fn write_bool(mut self, value: bool) -> IonResult<()>;
             fn write_i64(mut self, value: i64) -> IonResult<()>;
             fn write_int(mut self, value: &Int) -> IonResult<()>;
+            fn write_i128(mut self, value: i128) -> IonResult<()>;
+            fn write_u128(mut self, value: u128) -> IonResult<()>;
             fn write_f32(mut self, value: f32) -> IonResult<()>;
             fn write_f64(mut self, value: f64) -> IonResult<()>;
             fn write_decimal(mut self, value: &Decimal) -> IonResult<()>;
@@ -579,12 +958,23 @@ impl<'value, 'top: 'value> ValueWriter for BinaryAnnotatedValueWriter_1_0<'value
 }
 #[cfg(test)]
 mod tests {
+    use bumpalo::collections::Vec as BumpVec;
+    use bumpalo::Bump as BumpAllocator;
+
     use crate::lazy::encoder::annotate::Annotate;
-    use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
-    use crate::lazy::encoder::value_writer::AnnotatableValueWriter;
-    use crate::lazy::encoder::write_as_ion::WriteAsSExp;
+    use crate::lazy::encoder::binary::v1_0::value_writer::BinaryValueWriter_1_0;
+    use crate::lazy::encoder::binary::v1_0::writer::{write_and_reread, LazyRawBinaryWriter_1_0};
+    use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
+    use crate::lazy::encoder::value_writer::{
+        AnnotatableValueWriter, FloatWriteOptions, SequenceWriter, StructWriter, TruncatePolicy,
+        ValueWriter,
+    };
+    use crate::lazy::encoder::write_as_ion::{DynValue, WriteAsSExp};
     use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
-    use crate::{Element, IonData, IonResult, RawSymbolTokenRef, Timestamp};
+    use crate::{
+        Decimal, Element, Int, IonData, IonResult, IonType, RawSymbolToken, RawSymbolTokenRef,
+        Sequence, SymbolTable, SystemSymbol, Timestamp,
+    };
 
     fn writer_test(
         expected: &str,
@@ -628,6 +1018,46 @@ mod tests {
         writer_test(expected, test)
     }
 
+    #[test]
+    fn write_nulls() -> IonResult<()> {
+        let expected = r#"
+            null
+            null.bool
+            null.int
+            null.float
+            null.decimal
+            null.timestamp
+            null.symbol
+            null.string
+            null.clob
+            null.blob
+            null.list
+            null.sexp
+            null.struct
+        "#;
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            for ion_type in [
+                IonType::Null,
+                IonType::Bool,
+                IonType::Int,
+                IonType::Float,
+                IonType::Decimal,
+                IonType::Timestamp,
+                IonType::Symbol,
+                IonType::String,
+                IonType::Clob,
+                IonType::Blob,
+                IonType::List,
+                IonType::SExp,
+                IonType::Struct,
+            ] {
+                writer.value_writer().write_null(ion_type)?;
+            }
+            Ok(())
+        };
+        writer_test(expected, test)
+    }
+
     #[test]
     fn write_empty_list() -> IonResult<()> {
         let expected = "[]";
@@ -686,6 +1116,159 @@ mod tests {
         writer_test(expected, test)
     }
 
+    #[test]
+    fn write_i64_slice() -> IonResult<()> {
+        let values = [0, 1, -1, 255, -256, i64::MAX, i64::MIN + 1, 1_000_000];
+        let mut per_element = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut per_element)?;
+        writer.value_writer().write_list(|list| {
+            for value in values {
+                list.write(value)?;
+            }
+            Ok(())
+        })?;
+        writer.flush()?;
+
+        let mut sliced = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut sliced)?;
+        writer.value_writer().write_list(|list| {
+            list.write_i64_slice(&values)?.write_i64_slice(&[])?;
+            Ok(())
+        })?;
+        writer.flush()?;
+
+        assert_eq!(per_element, sliced);
+        Ok(())
+    }
+
+    #[test]
+    fn standalone_value_writer() -> IonResult<()> {
+        let allocator = BumpAllocator::new();
+        let mut buffer = BumpVec::new_in(&allocator);
+        BinaryValueWriter_1_0::new(&allocator, &mut buffer).write_list(|list| {
+            list.write([1])?;
+            Ok(())
+        })?;
+        assert_eq!(buffer.as_slice(), &[0xB3, 0xB2, 0x21, 0x01]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_null_struct() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .struct_writer()
+            .write_null_struct()?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .struct_writer()
+            .write_fields(|_| Ok(()))?;
+        writer.flush()?;
+        // The IVM, then `null.struct` followed by an empty struct
+        assert_eq!(buffer.as_slice(), &[0xE0, 0x01, 0x00, 0xEA, 0xDF, 0xD0]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_sized_annotated_values() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        let decimal_size = writer
+            .value_writer()
+            .with_annotations(&[4])
+            .write_decimal_sized(&Decimal::new(15, -1))?;
+        let timestamp_size = writer
+            .value_writer()
+            .with_annotations(&[4, 5])
+            .write_timestamp_sized(&Timestamp::with_ymd(2024, 1, 2).build()?)?;
+        writer.flush()?;
+        // The sizes include the annotations wrapper's header and annotations sequence.
+        assert_eq!(decimal_size, 6);
+        assert_eq!(timestamp_size, 10);
+        // The output begins with the 4-byte IVM.
+        assert_eq!(buffer.len(), 4 + decimal_size + timestamp_size);
+        assert_eq!(
+            Element::read_all(buffer)?,
+            Element::read_all("name::1.5 name::version::2024-01-02T")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_with_annotation_ids() -> IonResult<()> {
+        let mut borrowed = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        borrowed
+            .value_writer()
+            .with_annotations(&[4, 5])
+            .write_string("foo")?;
+        borrowed
+            .value_writer()
+            .with_annotations(&[7])
+            .write_i64(1)?;
+
+        let mut owned = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        let symbols = [SystemSymbol::Name, SystemSymbol::Version];
+        owned
+            .value_writer()
+            .with_annotation_ids(symbols.iter().map(SystemSymbol::sid))
+            .write_string("foo")?;
+        owned
+            .value_writer()
+            .with_annotation_ids(Some(7))
+            .write_i64(1)?;
+
+        let output = owned.into_output()?;
+        assert_eq!(output, borrowed.into_output()?);
+        assert_eq!(
+            Element::read_all(output)?,
+            Element::read_all("name::version::\"foo\" symbols::1")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_dyn() -> IonResult<()> {
+        let expected = r#"
+            [
+                null.string, true, 7, 2.5e0, 1.50, 2024-01-02T, "foo", name, {{4AEA6g==}},
+                {{"hi"}}, [1, "two"], {name: version}
+            ]
+        "#;
+        let values = vec![
+            DynValue::Null(IonType::String),
+            DynValue::Bool(true),
+            DynValue::Int(7.into()),
+            DynValue::Float(2.5),
+            DynValue::Decimal(Decimal::new(150, -2)),
+            DynValue::Timestamp(Timestamp::with_ymd(2024, 1, 2).build()?),
+            DynValue::String("foo".to_owned()),
+            DynValue::Symbol(RawSymbolToken::SymbolId(4)),
+            DynValue::Blob(vec![0xE0, 0x01, 0x00, 0xEA]),
+            DynValue::Clob(b"hi".to_vec()),
+            DynValue::List(vec![
+                DynValue::Int(1.into()),
+                DynValue::String("two".to_owned()),
+            ]),
+            DynValue::Struct(vec![(
+                RawSymbolToken::SymbolId(4),
+                DynValue::Symbol(RawSymbolToken::SymbolId(5)),
+            )]),
+        ];
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            writer.value_writer().write_list(|list| {
+                for value in &values {
+                    list.write_dyn(value)?;
+                }
+                Ok(())
+            })
+        };
+        writer_test(expected, test)
+    }
+
     #[test]
     fn write_sexp() -> IonResult<()> {
         let expected = r#"
@@ -754,6 +1337,7 @@ mod tests {
                 .without_annotations()
                 .write_struct(|struct_| {
                     struct_
+                        .allow_unknown_field_name(true)
                         .write(0, 1)?
                         .write(1, false)?
                         .write(2, 3f32)?
@@ -768,6 +1352,628 @@ mod tests {
         writer_test(expected, test)
     }
 
+    #[test]
+    fn write_f64_as_decimal() -> IonResult<()> {
+        let expected = r#"
+            0.1
+            -1.5
+            15d299
+            -0.
+            [1d1]
+        "#;
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            writer.value_writer().write_f64_as_decimal(0.1)?;
+            writer.value_writer().write_f64_as_decimal(-1.5)?;
+            writer.value_writer().write_f64_as_decimal(1.5e300)?;
+            writer.value_writer().write_f64_as_decimal(-0.0)?;
+            writer.value_writer().write_list(|list| {
+                list.value_writer().write_f64_as_decimal(10.0)?;
+                Ok(())
+            })?;
+            assert!(writer
+                .value_writer()
+                .write_f64_as_decimal(f64::NAN)
+                .is_err());
+            assert!(writer
+                .value_writer()
+                .write_f64_as_decimal(f64::NEG_INFINITY)
+                .is_err());
+            Ok(())
+        };
+        writer_test(expected, test)
+    }
+
+    #[test]
+    fn write_clob_str() -> IonResult<()> {
+        let expected = r#"
+            {{"h\xc3\xa9llo"}}
+            name::{{""}}
+        "#;
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            writer.value_writer().write_clob_str("héllo")?;
+            writer
+                .value_writer()
+                .with_annotations(&[RawSymbolTokenRef::SymbolId(4)])
+                .write_clob_str("")?;
+            Ok(())
+        };
+        writer_test(expected, test)
+    }
+
+    #[test]
+    fn write_struct_field_with_closure() -> IonResult<()> {
+        let expected = r#"
+            {name: 1, version: [2, 3], imports: name::"bar"}
+        "#;
+        let include_imports = true;
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            writer.value_writer().write_struct(|fields| {
+                fields
+                    .write(4, 1)?
+                    .write_with(5, |value_writer| {
+                        value_writer.write_list(|list| {
+                            list.write(2)?.write(3)?;
+                            Ok(())
+                        })
+                    })?
+                    .write_with(6, |value_writer| {
+                        if include_imports {
+                            value_writer
+                                .with_annotations(&[RawSymbolTokenRef::SymbolId(4)])
+                                .write_string("bar")
+                        } else {
+                            value_writer.write_null(IonType::String)
+                        }
+                    })?;
+                Ok(())
+            })
+        };
+        writer_test(expected, test)
+    }
+
+    #[test]
+    fn write_struct_field_name_sid_zero() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        let result = writer.value_writer().write_struct(|fields| {
+            fields.write(0, 1)?;
+            Ok(())
+        });
+        assert!(result.is_err());
+
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer.value_writer().write_struct(|fields| {
+            fields.allow_unknown_field_name(true).write(0, 1)?;
+            Ok(())
+        })?;
+        writer.flush()?;
+        // IVM, then a struct containing the field `$0: 1`
+        assert_eq!(buffer, &[0xE0, 0x01, 0x00, 0xEA, 0xD3, 0x80, 0x21, 0x01]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_f64_with() -> IonResult<()> {
+        let strict = FloatWriteOptions {
+            preserve_negative_zero: false,
+            reject_non_finite: true,
+            minimal_width: true,
+        };
+        let default = FloatWriteOptions::default();
+        let cases: &[(f64, FloatWriteOptions, &[u8])] = &[
+            (-0f64, default, &[0x48, 0x80, 0, 0, 0, 0, 0, 0, 0]),
+            (-0f64, strict, &[0x40]),
+            (1.5f64, default, &[0x48, 0x3F, 0xF8, 0, 0, 0, 0, 0, 0]),
+            (1.5f64, strict, &[0x44, 0x3F, 0xC0, 0, 0]),
+            // 0.1 cannot be represented exactly as an f32
+            (
+                0.1f64,
+                strict,
+                &[0x48, 0x3F, 0xB9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A],
+            ),
+        ];
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.take_buffer();
+        for (value, options, expected) in cases {
+            writer.value_writer().write_f64_with(*value, *options)?;
+            assert_eq!(writer.take_buffer(), *expected, "{value} with {options:?}");
+        }
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(writer.value_writer().write_f64_with(value, strict).is_err());
+            writer.value_writer().write_f64_with(value, default)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_negative_zero_decimals() -> IonResult<()> {
+        let cases: &[(Decimal, &[u8])] = &[
+            // 0d0 has an empty representation
+            (Decimal::new(0, 0), &[0x50]),
+            // -0d0: exponent 0 (0x80), coefficient -0 (0x80)
+            (Decimal::negative_zero(), &[0x52, 0x80, 0x80]),
+            // -0.00: exponent -2 (0xC2), coefficient -0 (0x80)
+            (
+                Decimal::negative_zero_with_exponent(-2),
+                &[0x52, 0xC2, 0x80],
+            ),
+            // 0d3: exponent 3 (0x83) with an empty coefficient
+            (Decimal::new(0, 3), &[0x51, 0x83]),
+        ];
+        for always_varuint_length in [false, true] {
+            for (decimal, expected) in cases {
+                let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+                writer.always_varuint_length(always_varuint_length);
+                writer.write(decimal)?;
+                let encoded = writer.take_buffer();
+                if !always_varuint_length {
+                    assert!(encoded.ends_with(expected), "{decimal}: {encoded:x?}");
+                }
+                let read_back = Element::read_one(encoded)?;
+                let read_back = read_back.as_decimal().unwrap();
+                assert!(IonData::eq(read_back, decimal), "{read_back} != {decimal}");
+                assert_eq!(
+                    read_back.coefficient().is_negative_zero(),
+                    decimal.coefficient().is_negative_zero()
+                );
+            }
+        }
+        // Text-to-binary round trip
+        let elements = Element::read_all("-0d0 0d0 -0.000")?;
+        let read_back = elements
+            .iter()
+            .map(|element| write_and_reread(element.as_decimal().unwrap()))
+            .collect::<IonResult<Sequence>>()?;
+        assert!(IonData::eq(&read_back, &elements));
+        let positive_zeros = Element::read_all("0d0 0d0 0.000")?;
+        assert!(!IonData::eq(&read_back, &positive_zeros));
+        Ok(())
+    }
+
+    #[test]
+    fn empty_lobs_are_not_null() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.take_buffer();
+        writer.value_writer().without_annotations().write_blob([])?;
+        assert_eq!(writer.take_buffer(), vec![0xA0]);
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_null_blob()?;
+        assert_eq!(writer.take_buffer(), vec![0xAF]);
+        writer.value_writer().without_annotations().write_clob([])?;
+        assert_eq!(writer.take_buffer(), vec![0x90]);
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_null_clob()?;
+        assert_eq!(writer.take_buffer(), vec![0x9F]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_string_bytes() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.take_buffer();
+        // The last string is longer than 13 bytes, so its length is written as a VarUInt.
+        for text in ["", "foo", "naïve café", "a string with 31 utf-8 bytes..."] {
+            writer
+                .value_writer()
+                .without_annotations()
+                .write_string_bytes(text.as_bytes())?;
+            let actual = writer.take_buffer();
+            writer
+                .value_writer()
+                .without_annotations()
+                .write_string(text)?;
+            assert_eq!(actual, writer.take_buffer());
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid UTF-8")]
+    #[cfg(debug_assertions)]
+    fn write_string_bytes_rejects_invalid_utf8_in_debug_builds() {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new()).unwrap();
+        let _ = writer
+            .value_writer()
+            .without_annotations()
+            .write_string_bytes(&[0x66, 0xFF]);
+    }
+
+    #[test]
+    fn write_float_compact() -> IonResult<()> {
+        let cases: &[(f64, &[u8])] = &[
+            (0f64, &[0x40]),
+            (-0f64, &[0x44, 0x80, 0, 0, 0]),
+            (1.5f64, &[0x44, 0x3F, 0xC0, 0, 0]),
+            (f64::INFINITY, &[0x44, 0x7F, 0x80, 0, 0]),
+            (f64::NEG_INFINITY, &[0x44, 0xFF, 0x80, 0, 0]),
+            (
+                0.1f64,
+                &[0x48, 0x3F, 0xB9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A],
+            ),
+            // Larger than f32::MAX
+            (
+                1e39f64,
+                &[0x48, 0x48, 0x07, 0x82, 0x87, 0xF4, 0x9C, 0x4A, 0x1D],
+            ),
+        ];
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.take_buffer();
+        for (value, expected) in cases {
+            writer
+                .value_writer()
+                .without_annotations()
+                .write_float_compact(*value)?;
+            assert_eq!(writer.take_buffer(), *expected, "{value}");
+        }
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_float_compact(f64::NAN)?;
+        let encoded = writer.take_buffer();
+        assert_eq!(encoded.len(), 5);
+        assert!(f32::from_be_bytes(encoded[1..].try_into().unwrap()).is_nan());
+        // The low bits of this NaN's payload would be lost in an f32.
+        let nan_with_payload = f64::from_bits(0x7FF8_0000_0000_0001);
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_float_compact(nan_with_payload)?;
+        assert_eq!(
+            writer.take_buffer(),
+            &[0x48, 0x7F, 0xF8, 0, 0, 0, 0, 0, 0x01]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_number() -> IonResult<()> {
+        let cases: &[(f64, &str)] = &[
+            (0f64, "0"),
+            (-0f64, "-0e0"),
+            (42f64, "42"),
+            (-7f64, "-7"),
+            (1.5f64, "1.5e0"),
+            (-9_223_372_036_854_775_808f64, "-9223372036854775808"),
+            (9_223_372_036_854_775_808f64, "9223372036854775808e0"),
+            (1e300f64, "1e300"),
+            (f64::INFINITY, "+inf"),
+            (f64::NAN, "nan"),
+        ];
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        for (value, _) in cases {
+            writer.value_writer().write_number(*value)?;
+        }
+        let actual = Element::read_all(writer.take_buffer())?;
+        assert_eq!(actual.len(), cases.len());
+        for (actual, (value, text)) in actual.iter().zip(cases) {
+            let expected = Element::read_one(*text)?;
+            assert_eq!(IonData::from(actual), IonData::from(&expected), "{value}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_string_truncated() -> IonResult<()> {
+        use TruncatePolicy::*;
+        // "é" is 2 bytes, "🦀" is 4 bytes
+        let text = "ab\u{e9}\u{1F980}c";
+        let cases: &[(usize, TruncatePolicy, &str)] = &[
+            (9, TruncateBytes, text),
+            (8, TruncateBytes, "ab\u{e9}\u{1F980}"),
+            // The limit falls inside the crab emoji
+            (7, TruncateBytes, "ab\u{e9}"),
+            (5, TruncateBytes, "ab\u{e9}"),
+            // The limit falls inside the "é"
+            (3, TruncateBytes, "ab"),
+            (0, TruncateBytes, ""),
+            (5, TruncateChars, text),
+            (4, TruncateChars, "ab\u{e9}\u{1F980}"),
+            (3, TruncateChars, "ab\u{e9}"),
+            (9, Error, text),
+        ];
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        for (max_bytes, policy, _) in cases {
+            writer
+                .value_writer()
+                .write_string_truncated(text, *max_bytes, *policy)?;
+        }
+        let actual = Element::read_all(writer.take_buffer())?;
+        let expected: Vec<Element> = cases.iter().map(|(.., s)| Element::string(*s)).collect();
+        assert_eq!(actual, expected.into());
+
+        assert!(writer
+            .value_writer()
+            .write_string_truncated(text, 8, Error)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn try_write_fixed_int() -> IonResult<()> {
+        let cases: &[(i128, usize, Option<&[u8]>)] = &[
+            (0, 0, Some(&[0x20])),
+            (255, 1, Some(&[0x21, 0xFF])),
+            (-255, 1, Some(&[0x31, 0xFF])),
+            (256, 1, None),
+            (0x7FFF_FFFF, 4, Some(&[0x24, 0x7F, 0xFF, 0xFF, 0xFF])),
+            (0x1_0000_0000, 4, None),
+            (-(1 << 63), 8, Some(&[0x38, 0x80, 0, 0, 0, 0, 0, 0, 0])),
+            (1 << 64, 8, None),
+            (1 << 64, 9, Some(&[0x29, 0x01, 0, 0, 0, 0, 0, 0, 0, 0])),
+        ];
+        for (value, byte_width, expected) in cases {
+            let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+            writer.take_buffer();
+            let result = writer
+                .value_writer()
+                .without_annotations()
+                .try_write_fixed_int(&Int::from(*value), *byte_width);
+            match expected {
+                Some(expected) => {
+                    result?;
+                    assert_eq!(writer.take_buffer(), *expected, "{value}");
+                }
+                None => {
+                    assert!(result.is_err(), "{value}");
+                    assert!(writer.take_buffer().is_empty());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_i128_and_u128() -> IonResult<()> {
+        let i128_values = [
+            0,
+            -1,
+            i64::MIN as i128,
+            i64::MAX as i128 + 1,
+            i64::MIN as i128 - 1,
+            u64::MAX as i128,
+            i128::MAX,
+            i128::MIN,
+        ];
+        let u128_values = [0, i64::MAX as u128 + 1, u64::MAX as u128 + 1, u128::MAX];
+        for always_varuint_length in [false, true] {
+            let mut actual = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+            let mut expected = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+            actual.always_varuint_length(always_varuint_length);
+            expected.always_varuint_length(always_varuint_length);
+            for value in i128_values {
+                actual.value_writer().write_i128(value)?;
+                expected.value_writer().write_int(&value.into())?;
+            }
+            for value in u128_values {
+                actual.value_writer().write_u128(value)?;
+                expected.value_writer().write_int(&value.into())?;
+            }
+            assert_eq!(actual.take_buffer(), expected.take_buffer());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_optional_and_fallible_values() -> IonResult<()> {
+        use crate::result::IonFailure;
+        let expected = r#"
+            1
+            null
+            [1, 2]
+            [3, 4]
+            {name: 5, version: "foo"}
+            {name: [6, 7], imports: {symbols: 8}}
+        "#;
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            writer
+                .write_opt(Some(1))?
+                .write_opt(None::<i64>)?
+                .write_list_try(Ok(vec![1, 2]))?
+                .write_iter([Ok(3), Ok(4)])?
+                .write_struct_try([
+                    Ok((4, DynValue::Int(5.into()))),
+                    Ok((5, DynValue::String("foo".to_owned()))),
+                ])?;
+            // An optional field whose value is a fallibly-produced list
+            let tags: Option<IonResult<Vec<i64>>> = Some(Ok(vec![6, 7]));
+            let missing: Option<IonResult<Vec<i64>>> = None;
+            writer
+                .value_writer()
+                .without_annotations()
+                .write_struct(|fields| {
+                    fields
+                        .write_opt(4, tags.transpose()?)?
+                        .write_opt(5, missing.transpose()?)?
+                        .write_struct_try(6, [Ok((7, 8))])?;
+                    Ok(())
+                })?;
+            // Inputs that produce an error are not written at all.
+            let list: IonResult<Vec<i64>> = IonResult::decoding_error("oops");
+            assert!(writer.write_list_try(list).is_err());
+            assert!(writer
+                .write_iter([Ok(9), IonResult::decoding_error("oops")])
+                .is_err());
+            assert!(writer
+                .write_struct_try([Ok((4, 9)), IonResult::decoding_error("oops")])
+                .is_err());
+            Ok(())
+        };
+        writer_test(expected, test)
+    }
+
+    #[test]
+    fn write_all() -> IonResult<()> {
+        let expected = r#"
+            1 2 3
+            [4, 5, 6]
+            (7 8 9)
+            {name: 10, version: 11}
+            name
+        "#;
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            writer.write_all([1, 2, 3])?;
+            writer.value_writer().write_list(|list| {
+                list.write_all(vec![4, 5, 6])?;
+                Ok(())
+            })?;
+            writer.value_writer().write_sexp(|sexp| {
+                sexp.write_all(7..=9)?;
+                Ok(())
+            })?;
+            writer.value_writer().write_struct(|fields| {
+                fields.write_all([(4, 10), (5, 11)])?;
+                Ok(())
+            })?;
+            // Values that precede an error are written.
+            let symbols = [
+                RawSymbolTokenRef::SymbolId(4),
+                RawSymbolTokenRef::Text("foo".into()),
+            ];
+            assert!(writer.write_all(symbols).is_err());
+            Ok(())
+        };
+        writer_test(expected, test)
+    }
+
+    #[test]
+    fn write_struct_ordered() -> IonResult<()> {
+        let mut ordered = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut ordered)?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_ordered(&[7, 4, 5], |struct_| {
+                struct_
+                    .write(4, 1)?
+                    .write(5, "foo")?
+                    .write(7, false)?
+                    .write(4, 2)?;
+                Ok(())
+            })?;
+        writer.flush()?;
+
+        let mut expected = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut expected)?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_struct(|struct_| {
+                struct_
+                    .write(7, false)?
+                    .write(4, 1)?
+                    .write(4, 2)?
+                    .write(5, "foo")?;
+                Ok(())
+            })?;
+        writer.flush()?;
+
+        assert_eq!(ordered, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn write_struct_dedup_last_wins() -> IonResult<()> {
+        let mut deduped = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut deduped)?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_dedup_last_wins(|struct_| {
+                struct_
+                    .write(4, 1)?
+                    .write(5, "foo")?
+                    .write(4, [1, 2, 3])?
+                    .write(7, false)?
+                    .write(5, "bar")?
+                    .write(4, 3)?;
+                Ok(())
+            })?;
+        writer.flush()?;
+
+        let mut expected = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut expected)?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_struct(|struct_| {
+                struct_.write(4, 3)?.write(5, "bar")?.write(7, false)?;
+                Ok(())
+            })?;
+        writer.flush()?;
+
+        assert_eq!(deduped, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn write_struct_ordered_rejects_unlisted_field() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        let result = writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_ordered(&[4, 5], |struct_| {
+                struct_.write(4, 1)?.write(6, 2)?;
+                Ok(())
+            });
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn write_buffered_struct_field_name_sid_zero() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        let result = writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_dedup_last_wins(|struct_| {
+                struct_.write(0, 1)?;
+                Ok(())
+            });
+        assert!(result.is_err());
+        let result = writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_ordered(&[0], |struct_| {
+                struct_.write(0, 1)?;
+                Ok(())
+            });
+        assert!(result.is_err());
+
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_dedup_last_wins(|struct_| {
+                struct_
+                    .allow_unknown_field_name(true)
+                    .write(0, 1)?
+                    .write(0, 2)?;
+                Ok(())
+            })?;
+        writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_ordered(&[0], |struct_| {
+                struct_.allow_unknown_field_name(true).write(0, 1)?;
+                Ok(())
+            })?;
+        writer.flush()?;
+        // IVM, then two structs containing the fields `$0: 2` and `$0: 1`
+        assert_eq!(
+            buffer,
+            &[0xE0, 0x01, 0x00, 0xEA, 0xD3, 0x80, 0x21, 0x02, 0xD3, 0x80, 0x21, 0x01]
+        );
+        Ok(())
+    }
+
     #[test]
     fn write_annotated_scalars() -> IonResult<()> {
         let expected = r#"
@@ -799,6 +2005,88 @@ mod tests {
         writer_test(expected, test)
     }
 
+    #[test]
+    fn write_system_symbols() -> IonResult<()> {
+        let expected = r#"
+            name
+            version::imports::symbols
+            $ion_symbol_table::{name: max_id}
+        "#;
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            writer
+                .value_writer()
+                .write_system_symbol(SystemSymbol::Name)?;
+            writer
+                .value_writer()
+                .with_system_annotations(&[SystemSymbol::Version, SystemSymbol::Imports])
+                .write_system_symbol(SystemSymbol::Symbols)?;
+            writer
+                .value_writer()
+                .with_system_annotations(&[SystemSymbol::IonSymbolTable])
+                .write_struct(|fields| {
+                    fields.write(
+                        SystemSymbol::Name,
+                        SystemSymbol::MaxId.as_raw_symbol_token_ref(),
+                    )?;
+                    Ok(())
+                })?;
+            Ok(())
+        };
+        writer_test(expected, test)
+    }
+
+    #[test]
+    fn write_type_annotated() -> IonResult<()> {
+        let expected = r#"
+            int::5
+            string::"hi"
+            int::null.int
+            list::[1, "two"]
+            struct::{name: 1}
+        "#;
+        let values = [
+            DynValue::Int(5.into()),
+            DynValue::String("hi".to_owned()),
+            DynValue::Null(IonType::Int),
+            DynValue::List(vec![
+                DynValue::Int(1.into()),
+                DynValue::String("two".into()),
+            ]),
+            DynValue::Struct(vec![(RawSymbolToken::SymbolId(4), DynValue::Int(1.into()))]),
+        ];
+        let mut symtab = SymbolTable::new();
+        let test = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| {
+            // Interning the type names populates `symtab`, so write the values once to discover
+            // which symbols the stream's symbol table needs to define.
+            let mut scratch = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+            for value in &values {
+                scratch
+                    .value_writer()
+                    .write_type_annotated(&mut symtab, value)?;
+            }
+            let new_symbols: Vec<&str> = symtab.symbols()[10..]
+                .iter()
+                .map(|symbol| symbol.text().unwrap())
+                .collect();
+            writer
+                .value_writer()
+                .with_system_annotations(&[SystemSymbol::IonSymbolTable])
+                .write_struct(|fields| {
+                    fields.write(SystemSymbol::Symbols, new_symbols.as_slice())?;
+                    Ok(())
+                })?;
+            for value in &values {
+                writer
+                    .value_writer()
+                    .write_type_annotated(&mut symtab, value)?;
+            }
+            Ok(())
+        };
+        writer_test(expected, test)?;
+        assert_eq!(symtab.len(), 14);
+        Ok(())
+    }
+
     #[test]
     fn write_annotated_containers() -> IonResult<()> {
         let expected = r#"
@@ -1,15 +1,83 @@
-use crate::lazy::encoder::binary::v1_0::value_writer::BinaryAnnotatableValueWriter_1_0;
+use crate::lazy::encoder::binary::v1_0::container_writers::DepthTracker;
+use crate::lazy::encoder::binary::v1_0::value_writer::{
+    encode_annotation_sid, BinaryAnnotatableValueWriter_1_0, BinaryEncodingOptions_1_0,
+};
 use crate::lazy::encoder::private::Sealed;
+use crate::lazy::encoder::text::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
-use crate::lazy::encoder::value_writer::SequenceWriter;
+use crate::lazy::encoder::value_writer::{AnnotatableValueWriter, SequenceWriter, ValueWriter};
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
-use crate::IonResult;
+use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
+use crate::{IonResult, SystemSymbol};
 use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 use delegate::delegate;
 use std::io::Write;
 
+const ION_1_0_VERSION_MARKER: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+
+/// Encodes `value` as a single binary Ion 1.0 value and returns the resulting bytes.
+///
+/// The output contains only the value's encoding; no Ion version marker or symbol table is
+/// written. Like the [`LazyRawBinaryWriter_1_0`], this function does not manage a symbol table,
+/// so any symbol values, field names, or annotations in `value` must be provided as symbol IDs.
+///
+/// This is useful for embedding individual values in other data or for hashing them.
+pub fn to_binary_value<V: WriteAsIon>(value: V) -> IonResult<Vec<u8>> {
+    let allocator = BumpAllocator::new();
+    let depth = DepthTracker::new();
+    let mut buffer = BumpVec::new_in(&allocator);
+    value.write_as_ion(BinaryAnnotatableValueWriter_1_0::new_with_options(
+        &allocator,
+        &depth,
+        BinaryEncodingOptions_1_0::default(),
+        &mut buffer,
+    ))?;
+    Ok(buffer.to_vec())
+}
+
+/// Encodes `annotations` as a binary Ion 1.0 annotations sequence: the VarUInt symbol ID of each
+/// annotation, in order. Each annotation must be provided as a symbol ID; text annotations
+/// produce an encoding error.
+///
+/// This allows the encoding of a frequently used set of annotations to be computed once and
+/// reused. Note that the returned bytes are *only* the sequence. To splice them into an
+/// annotations wrapper, the caller must write (in order):
+/// 1. The wrapper's type descriptor and length. The length counts every byte that follows it,
+///    including the `annot_length` field, the annotations sequence, and the wrapped value.
+/// 2. The `annot_length` field: a VarUInt containing the length of the returned sequence.
+/// 3. The returned sequence.
+/// 4. The encoding of the wrapped value.
+///
+/// Because the sequence contains symbol IDs, it is only meaningful in a stream whose symbol table
+/// maps those IDs to the intended text.
+pub fn encode_annotation_sids<I>(annotations: I) -> IonResult<Vec<u8>>
+where
+    I: IntoIterator,
+    I::Item: AsRawSymbolTokenRef,
+{
+    let mut encoded = Vec::new();
+    for annotation in annotations {
+        encode_annotation_sid(&mut encoded, annotation)?;
+    }
+    Ok(encoded)
+}
+
+/// Encodes `value` as a binary Ion 1.0 stream and reads it back, returning the resulting
+/// [`Element`](crate::Element).
+///
+/// This is a test helper: comparing the returned `Element` to the value that was written verifies
+/// both its encoding and the reader's interpretation of it. As with [`to_binary_value`], no
+/// symbol table is written, so any symbol IDs in `value` must refer to system symbols; a value
+/// that uses a local symbol ID cannot be read back.
+#[cfg(test)]
+pub(crate) fn write_and_reread<V: WriteAsIon>(value: V) -> IonResult<crate::Element> {
+    let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+    writer.write(value)?;
+    crate::Element::read_one(writer.take_buffer())
+}
+
 /// A "raw"-level streaming binary Ion writer. This writer does not provide symbol table
 /// management; symbol-related operations (e.g. setting field IDs and annotations or writing symbol
 /// values) require a valid symbol ID to be provided by the caller.
@@ -19,6 +87,25 @@ pub struct LazyRawBinaryWriter_1_0<W: Write> {
     // A bump allocator that can be used to cheaply create scratch buffers for nested container
     // encoding.
     allocator: BumpAllocator,
+    // Tracks the deepest container nesting reached by any value written so far.
+    depth: DepthTracker,
+    // Encoding settings that apply to every value the writer writes.
+    options: BinaryEncodingOptions_1_0,
+    // Whether an Ion version marker must be written to `output` before any more encoded data.
+    // This is set by `reset()`, which begins a new logical document.
+    ivm_pending: bool,
+    // Whether `write_ion_version_marker()` and `reset()` skip a version marker that would
+    // immediately follow another one.
+    deduplicate_version_markers: bool,
+    // If the most recent bytes in the stream are an Ion version marker, the length that the
+    // encoding buffer had when it was written. Once more bytes are encoded, the buffer's length
+    // no longer matches and the marker is no longer the most recent thing in the stream.
+    ivm_end: Option<usize>,
+    // Whether `flush()` writes a stream terminator after any values that are pending.
+    terminate_on_flush: bool,
+    // If set, a text rendering of each top-level value passed to `write()` is appended to this
+    // buffer.
+    debug_text_mirror: Option<Vec<u8>>,
     // A pointer to the bump-allocated top-level encoding buffer, if set.
     //
     // This buffer is constructed in `allocator` above, a region of memory over which we have
@@ -29,18 +116,32 @@ pub struct LazyRawBinaryWriter_1_0<W: Write> {
     // is set to a meaningful address. This allows us to refer to the contents of the buffer across
     // multiple mutable calls of `write` and `value_writer()`.
     encoding_buffer_ptr: Option<*mut ()>,
+    // A pointer to a bump-allocated scratch buffer in which top-level annotated values are encoded
+    // before being wrapped, if set. Reusing it from one value to the next keeps streams with many
+    // annotated values from filling the allocator with discarded buffers. Like the encoding
+    // buffer, it is valid until the allocator is reset.
+    annotation_buffer_ptr: Option<*mut ()>,
 }
 
 impl<W: Write> LazyRawBinaryWriter_1_0<W> {
     /// Constructs a new binary writer and writes an Ion 1.0 Version Marker to output.
     pub fn new(mut output: W) -> IonResult<Self> {
         // Write the Ion 1.0 IVM
-        output.write_all(&[0xE0, 0x01, 0x00, 0xEA])?;
+        output.write_all(&ION_1_0_VERSION_MARKER)?;
         // Construct the writer
         Ok(Self {
             output,
             allocator: BumpAllocator::new(),
+            depth: DepthTracker::new(),
+            options: BinaryEncodingOptions_1_0::default(),
+            ivm_pending: false,
+            deduplicate_version_markers: false,
+            // The stream begins with the version marker written above.
+            ivm_end: Some(0),
+            terminate_on_flush: false,
+            debug_text_mirror: None,
             encoding_buffer_ptr: None,
+            annotation_buffer_ptr: None,
         })
     }
 
@@ -60,21 +161,61 @@ impl<W: Write> LazyRawBinaryWriter_1_0<W> {
     /// Writes the given Rust value to the output stream as a top-level value.
     pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
         value.write_as_ion(self.value_writer())?;
+        if let Some(mirror) = &mut self.debug_text_mirror {
+            value.write_as_ion(LazyRawTextWriter_1_0::new(&mut *mirror).value_writer())?;
+            mirror.push(b'\n');
+        }
         Ok(self)
     }
 
+    /// Configures the writer to append a text Ion rendering of each top-level value passed to
+    /// [`Self::write`] to `buffer`, in addition to encoding it as binary. This is intended for
+    /// logging what a writer produces while debugging.
+    ///
+    /// As this writer does not manage a symbol table, symbols appear in the text as symbol IDs
+    /// (for example, `$10`). Values written using other methods are not mirrored.
+    ///
+    /// The mirror is disabled by default. Use [`Self::take_debug_text_mirror`] to retrieve
+    /// the buffer.
+    pub fn with_debug_text_mirror(&mut self, buffer: Vec<u8>) -> &mut Self {
+        self.debug_text_mirror = Some(buffer);
+        self
+    }
+
+    /// Returns the text mirror buffer configured by [`Self::with_debug_text_mirror`], if any,
+    /// disabling the mirror.
+    pub fn take_debug_text_mirror(&mut self) -> Option<Vec<u8>> {
+        self.debug_text_mirror.take()
+    }
+
     /// Flushes any encoded bytes that have not already been written to the output sink.
     ///
     /// Calling `flush` also releases memory used for bookkeeping and storage, but calling it
     /// frequently can reduce overall throughput.
     pub fn flush(&mut self) -> IonResult<()> {
+        // Only terminate the stream if values were encoded since the last flush. The encoding
+        // buffer can be allocated without any being written (for example, by `value_writer()`),
+        // and a version marker at the end of the buffer already resets the symbol table.
+        let buffer_len = self.encoding_buffer_len();
+        if self.terminate_on_flush && buffer_len > 0 && self.ivm_end != Some(buffer_len) {
+            self.write_stream_terminator()?;
+        }
+        self.ivm_end = self.ivm_end_after_flush();
         // Temporarily break apart `self` to get simultaneous references to its innards.
         let Self {
             output,
             allocator,
+            ivm_pending,
             encoding_buffer_ptr,
+            annotation_buffer_ptr,
+            ..
         } = self;
 
+        if *ivm_pending {
+            output.write_all(&ION_1_0_VERSION_MARKER)?;
+            *ivm_pending = false;
+        }
+
         let encoding_buffer = match encoding_buffer_ptr {
             // If `encoding_buffer_ptr` is set, get the slice of bytes to which it refers.
             Some(ptr) => unsafe { Self::ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(*ptr).as_slice() },
@@ -87,9 +228,199 @@ impl<W: Write> LazyRawBinaryWriter_1_0<W> {
         output.flush()?;
         // Clear the allocator. A new encoding buffer will be allocated on the next write.
         allocator.reset();
+        *encoding_buffer_ptr = None;
+        *annotation_buffer_ptr = None;
         Ok(())
     }
 
+    /// Flushes any encoded bytes that have not already been written to the output sink and
+    /// returns the sink, consuming the writer.
+    ///
+    /// This allows a writer that owns its output to hand it back when encoding is complete:
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    /// use ion_rs::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+    ///
+    /// let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+    /// writer.write(1)?.write("foo")?;
+    /// let bytes: Vec<u8> = writer.into_output()?;
+    /// assert_eq!(Element::read_all(bytes)?, Element::read_all("1 \"foo\"")?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn into_output(mut self) -> IonResult<W> {
+        self.flush()?;
+        Ok(self.output)
+    }
+
+    /// Writes a symbol table that defines no symbols, `$ion_symbol_table::{symbols: []}`. Some
+    /// consumers treat this as a marker for the end of a logical stream.
+    ///
+    /// A reader that encounters the terminator resets its symbol table to the system symbols, so it
+    /// is harmless to readers that do not look for it. Any values written after the terminator
+    /// must not refer to symbols defined before it.
+    pub fn write_stream_terminator(&mut self) -> IonResult<&mut Self> {
+        self.value_writer()
+            .with_system_annotations(&[SystemSymbol::IonSymbolTable])
+            .write_struct(|fields| {
+                fields.write(SystemSymbol::Symbols, Vec::<i64>::new())?;
+                Ok(())
+            })?;
+        Ok(self)
+    }
+
+    /// Configures whether [`flush`](Self::flush) writes a stream terminator (see
+    /// [`write_stream_terminator`](Self::write_stream_terminator)) after the values it flushes.
+    /// The terminator is only written if values have been written since the last flush, and it is
+    /// not written by [`take_buffer`](LazyRawBinaryWriter_1_0::take_buffer).
+    ///
+    /// This is disabled by default.
+    pub fn terminate_stream_on_flush(&mut self, terminate: bool) -> &mut Self {
+        self.terminate_on_flush = terminate;
+        self
+    }
+
+    /// Discards any values that have been encoded but not yet flushed and begins a new logical
+    /// document. The next call to [`flush`](Self::flush) writes a fresh Ion version marker before
+    /// any values, so a reader will treat everything written after the reset as a new stream
+    /// with no symbols defined beyond the system symbols.
+    ///
+    /// To discard unflushed values while continuing the current document, use
+    /// [`reset_keep_version`](Self::reset_keep_version) instead.
+    pub fn reset(&mut self) {
+        self.discard_unflushed();
+        // If deduplication is enabled and the stream already ends with a version marker, the new
+        // document can begin without writing another.
+        if !(self.deduplicate_version_markers && self.ivm_end == Some(0)) {
+            self.ivm_pending = true;
+        }
+        self.ivm_end = Some(0);
+    }
+
+    /// Discards any values that have been encoded but not yet flushed without beginning a new
+    /// logical document. No Ion version marker is written on the next [`flush`](Self::flush);
+    /// data written after the reset continues the stream the consumer has already seen, including
+    /// any symbol context it has established.
+    ///
+    /// If [`reset`](Self::reset) was called and its version marker has not been flushed yet,
+    /// that marker is still written.
+    pub fn reset_keep_version(&mut self) {
+        self.discard_unflushed();
+    }
+
+    fn discard_unflushed(&mut self) {
+        self.allocator.reset();
+        self.encoding_buffer_ptr = None;
+        self.annotation_buffer_ptr = None;
+        // A version marker at the very start of the discarded data was written before it.
+        self.ivm_end = self.ivm_end.filter(|end| *end == 0);
+    }
+
+    /// Writes an Ion 1.0 version marker to the stream after any values that have already been
+    /// written. A reader treats everything after the marker as a new stream with no symbols
+    /// defined beyond the system symbols.
+    ///
+    /// If [`deduplicate_version_markers`](Self::deduplicate_version_markers) is enabled and no
+    /// values have been written since the last version marker (including the one written by
+    /// [`new`](Self::new)), nothing is written.
+    pub fn write_ion_version_marker(&mut self) -> IonResult<&mut Self> {
+        if self.deduplicate_version_markers && self.ivm_end == Some(self.encoding_buffer_len()) {
+            return Ok(self);
+        }
+        let buffer = self.encoding_buffer();
+        buffer.extend_from_slice(&ION_1_0_VERSION_MARKER);
+        self.ivm_end = Some(buffer.len());
+        Ok(self)
+    }
+
+    /// Configures whether [`write_ion_version_marker`](Self::write_ion_version_marker) and
+    /// [`reset`](Self::reset) skip writing a version marker that would immediately follow
+    /// another one. This is useful when interleaving output from several sources, each of which
+    /// begins with its own version marker; readers accept consecutive markers, but they add
+    /// four bytes each.
+    ///
+    /// This is disabled by default.
+    pub fn deduplicate_version_markers(&mut self, deduplicate: bool) -> &mut Self {
+        self.deduplicate_version_markers = deduplicate;
+        self
+    }
+
+    /// Returns the top-level encoding buffer, allocating it if necessary.
+    fn encoding_buffer(&mut self) -> &mut BumpVec<'_, u8> {
+        match self.encoding_buffer_ptr {
+            Some(ptr) => unsafe { Self::ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(ptr) },
+            None => {
+                let buffer = self
+                    .allocator
+                    .alloc_with(|| BumpVec::new_in(&self.allocator));
+                self.encoding_buffer_ptr = Some(Self::mut_ref_to_ptr(buffer));
+                buffer
+            }
+        }
+    }
+
+    /// Returns the number of bytes in the top-level encoding buffer.
+    fn encoding_buffer_len(&self) -> usize {
+        match self.encoding_buffer_ptr {
+            Some(ptr) => unsafe { Self::ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(ptr).len() },
+            None => 0,
+        }
+    }
+
+    /// Returns the value `ivm_end` should have once the encoding buffer has been written to the
+    /// output and cleared.
+    fn ivm_end_after_flush(&self) -> Option<usize> {
+        let buffer_len = self.encoding_buffer_len();
+        self.ivm_end.filter(|end| *end == buffer_len).map(|_| 0)
+    }
+
+    /// Returns the deepest container nesting reached by any value this writer has written.
+    /// Top-level scalars have a depth of 0, the values inside a top-level container have a depth
+    /// of 1, and so on. This is intended for diagnostics and profiling.
+    pub fn max_depth_reached(&self) -> usize {
+        self.depth.max_depth_reached()
+    }
+
+    /// Configures whether annotations are encoded in the reverse of the order in which they are
+    /// provided. With this enabled, `a::b::value` is encoded with the symbol ID for `b` before
+    /// the symbol ID for `a`.
+    ///
+    /// This exists for interoperability with legacy consumers that expect annotations in reverse
+    /// order; the resulting data will appear to have its annotations reversed to any other
+    /// reader. This is disabled by default.
+    pub fn reverse_annotation_order(&mut self, reverse: bool) -> &mut Self {
+        self.options.reverse_annotation_order = reverse;
+        self
+    }
+
+    /// Configures whether every length is written as a VarUInt following a type descriptor whose
+    /// length nibble is `0xE`, even when the length is small enough to be written in the type
+    /// descriptor itself. This applies to ints, decimals, timestamps, symbols, strings, lobs,
+    /// containers, and annotation wrappers; other types do not have a length in their type
+    /// descriptor.
+    ///
+    /// The resulting data is valid Ion and can be read by any conforming reader, but it is larger
+    /// than necessary. This can simplify external tools that parse the encoding with a fixed
+    /// layout. This is disabled by default.
+    pub fn always_varuint_length(&mut self, always_varuint_length: bool) -> &mut Self {
+        self.options.always_varuint_length = always_varuint_length;
+        self
+    }
+
+    /// Configures whether the writer guarantees that its output does not need a local symbol
+    /// table. With this enabled, attempting to write a symbol value, field name, or annotation
+    /// whose symbol ID is beyond the end of the system symbol table (`$9`), or a top-level value
+    /// annotated with `$ion_symbol_table`, produces an encoding error.
+    ///
+    /// This is useful when the consumer's symbol table is shared out of band and the output must
+    /// only use system symbols. This is disabled by default.
+    pub fn assert_no_symbol_table(&mut self, assert: bool) -> &mut Self {
+        self.options.assert_no_symbol_table = assert;
+        self
+    }
+
     pub(crate) fn value_writer(&mut self) -> BinaryAnnotatableValueWriter_1_0<'_, '_> {
         let top_level = match self.encoding_buffer_ptr {
             // If the `encoding_buffer_ptr` is set, we already allocated an encoding buffer on
@@ -105,12 +436,53 @@ impl<W: Write> LazyRawBinaryWriter_1_0<W> {
                 buffer
             }
         };
-        let annotated_value_writer =
-            BinaryAnnotatableValueWriter_1_0::new(&self.allocator, top_level);
+        // The scratch buffer for annotated values is allocated the same way.
+        let annotation_buffer = match self.annotation_buffer_ptr {
+            Some(ptr) => unsafe { Self::ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(ptr) },
+            None => {
+                let buffer = self
+                    .allocator
+                    .alloc_with(|| BumpVec::new_in(&self.allocator));
+                self.annotation_buffer_ptr = Some(Self::mut_ref_to_ptr(buffer));
+                buffer
+            }
+        };
+        let annotated_value_writer = BinaryAnnotatableValueWriter_1_0::new_with_options(
+            &self.allocator,
+            &self.depth,
+            self.options,
+            top_level,
+        )
+        .with_scratch_buffer(annotation_buffer);
         annotated_value_writer
     }
 }
 
+impl LazyRawBinaryWriter_1_0<Vec<u8>> {
+    /// Returns all of the bytes encoded so far, leaving the writer's output empty and ready for
+    /// reuse. This moves the output `Vec` out of the writer rather than copying it to another sink.
+    ///
+    /// The Ion version marker written when the writer was constructed is included in the first
+    /// buffer taken; later buffers contain only the values written since the previous call.
+    ///
+    /// If [`reset`](Self::reset) was called since the previous buffer was taken, the returned
+    /// buffer begins with a fresh Ion version marker.
+    pub fn take_buffer(&mut self) -> Vec<u8> {
+        self.ivm_end = self.ivm_end_after_flush();
+        if self.ivm_pending {
+            self.output.extend_from_slice(&ION_1_0_VERSION_MARKER);
+            self.ivm_pending = false;
+        }
+        if let Some(ptr) = self.encoding_buffer_ptr.take() {
+            let encoding_buffer = unsafe { Self::ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(ptr) };
+            self.output.extend_from_slice(encoding_buffer.as_slice());
+        }
+        self.allocator.reset();
+        self.annotation_buffer_ptr = None;
+        std::mem::take(&mut self.output)
+    }
+}
+
 impl<W: Write> Sealed for LazyRawBinaryWriter_1_0<W> {}
 
 impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_0<W> {
@@ -138,3 +510,456 @@ impl<W: Write> MakeValueWriter for LazyRawBinaryWriter_1_0<W> {
 impl<W: Write> SequenceWriter for LazyRawBinaryWriter_1_0<W> {
     // Uses the default method implementations from SequenceWriter
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoder::annotate::Annotate;
+    use crate::lazy::encoder::write_as_ion::WriteAsSExp;
+    use crate::lazy::reader::LazyBinaryReader;
+    use crate::lazy::system_reader::LazySystemAnyReader;
+    use crate::{Decimal, Element, Int, RawSymbolTokenRef, Timestamp};
+
+    #[test]
+    fn to_binary_value_encodes_a_single_value() -> IonResult<()> {
+        assert_eq!(to_binary_value(5)?, vec![0x21, 0x05]);
+        assert_eq!(to_binary_value("hi")?, vec![0x82, b'h', b'i']);
+        assert_eq!(to_binary_value([1, 2])?, vec![0xB4, 0x21, 0x01, 0x21, 0x02]);
+        // $4::true
+        assert_eq!(
+            to_binary_value(true.annotated_with(&[4]))?,
+            vec![0xE3, 0x81, 0x84, 0x11]
+        );
+        // Text symbols cannot be encoded without a symbol table.
+        assert!(to_binary_value(RawSymbolTokenRef::Text("foo".into())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn assert_no_symbol_table() -> IonResult<()> {
+        use crate::lazy::encoder::symbol_table_writer::SymbolTableWriter;
+        use crate::SymbolTable;
+
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.assert_no_symbol_table(true);
+        let system_symbol = || RawSymbolTokenRef::SymbolId(9);
+        let user_symbol = || RawSymbolTokenRef::SymbolId(10);
+        // Values that only use system symbols are accepted, including a nested value
+        // annotated with `$ion_symbol_table`.
+        writer.write(system_symbol().annotated_with(&[4]))?;
+        writer.write([3.annotated_with(&[3])])?;
+        writer
+            .value_writer()
+            .write_struct(|fields| fields.write(4, system_symbol()).map(|_| ()))?;
+
+        assert!(writer.write(user_symbol()).is_err());
+        assert!(writer.write(1.annotated_with(&[10])).is_err());
+        assert!(writer
+            .value_writer()
+            .write_struct(|fields| fields.write(10, 1).map(|_| ()))
+            .is_err());
+        assert!(writer
+            .value_writer()
+            .without_annotations()
+            .write_struct_dedup_last_wins(|fields| fields.write(10, 1).map(|_| ()))
+            .is_err());
+        let mut symbol_table = SymbolTable::new();
+        symbol_table.intern("foo");
+        assert!(SymbolTableWriter::new(&mut writer)
+            .write_delta(&SymbolTable::new(), &symbol_table)
+            .is_err());
+
+        writer.reset_keep_version();
+        writer.assert_no_symbol_table(false);
+        writer.write(user_symbol())?;
+        Ok(())
+    }
+
+    #[test]
+    fn encode_annotation_sids_can_be_spliced() -> IonResult<()> {
+        let annotations = encode_annotation_sids([4, 200])?;
+        assert_eq!(annotations, vec![0x84, 0x01, 0xC8]);
+        // Splice the sequence into a wrapper around the int `7` and compare it to the writer's
+        // own encoding of `$4::$200::7`.
+        let value = [0x21, 0x07];
+        let mut spliced = vec![0xE0 | (1 + annotations.len() + value.len()) as u8];
+        spliced.push(0x80 | annotations.len() as u8);
+        spliced.extend_from_slice(&annotations);
+        spliced.extend_from_slice(&value);
+        assert_eq!(spliced, to_binary_value(7.annotated_with(&[4, 200]))?);
+
+        assert!(encode_annotation_sids([RawSymbolTokenRef::Text("foo".into())]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_reread_round_trips_a_value() -> IonResult<()> {
+        assert_eq!(write_and_reread(5)?, Element::read_one("5")?);
+        assert_eq!(
+            write_and_reread([1.5f64, 2.5f64])?,
+            Element::read_one("[1.5e0, 2.5e0]")?
+        );
+        // $4::$5
+        assert_eq!(
+            write_and_reread(RawSymbolTokenRef::SymbolId(5).annotated_with(&[4]))?,
+            Element::read_one("name::version")?
+        );
+        // A symbol ID that is not defined cannot be read back.
+        assert!(write_and_reread(RawSymbolTokenRef::SymbolId(100)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn write_after_flush() -> IonResult<()> {
+        // `flush` resets the bump allocator that holds the top-level encoding buffer. Values
+        // written afterward must be encoded into a newly allocated buffer rather than into the
+        // memory that the allocator has since handed out again.
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        for _ in 0..3 {
+            writer.write(1)?;
+            writer.flush()?;
+            assert!(writer.encoding_buffer_ptr.is_none());
+            assert!(writer.annotation_buffer_ptr.is_none());
+            writer.write([[1, 2], [3, 4]].annotated_with(&[4]))?;
+            writer.write("foo")?;
+        }
+        let bytes = writer.into_output()?;
+        let expected = Element::read_all("1 $4::[[1, 2], [3, 4]] \"foo\"".repeat(3))?;
+        assert_eq!(Element::read_all(bytes)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn into_output_flushes_pending_values() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.write(1)?;
+        writer.flush()?;
+        // This value is still in the encoding buffer; `into_output` must flush it.
+        writer.write([2, 3])?;
+        let bytes = writer.into_output()?;
+        assert_eq!(
+            bytes,
+            vec![0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01, 0xB4, 0x21, 0x02, 0x21, 0x03]
+        );
+
+        // A writer over a borrowed buffer hands the same borrow back.
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer.write(true)?;
+        writer.into_output()?.push(0x0F);
+        assert_eq!(buffer, vec![0xE0, 0x01, 0x00, 0xEA, 0x11, 0x0F]);
+        Ok(())
+    }
+
+    #[test]
+    fn max_depth_reached() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer.write(1)?;
+        assert_eq!(writer.max_depth_reached(), 0);
+        writer.write([1, 2])?;
+        assert_eq!(writer.max_depth_reached(), 1);
+        writer.value_writer().write_struct(|fields| {
+            fields.write(4, [[1]].annotated_with(&[5]))?;
+            Ok(())
+        })?;
+        assert_eq!(writer.max_depth_reached(), 3);
+        // The high-water mark is retained across flushes and shallower values.
+        writer.flush()?;
+        writer.write([1])?;
+        assert_eq!(writer.max_depth_reached(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn reverse_annotation_order() -> IonResult<()> {
+        // $10::$11::1, both at the top level and nested inside a list
+        let write_values = |writer: &mut LazyRawBinaryWriter_1_0<&mut Vec<u8>>| -> IonResult<()> {
+            writer
+                .write(1.annotated_with(&[10, 11]))?
+                .write([1.annotated_with(&[10, 11])])?;
+            writer.flush()
+        };
+
+        let mut buffer = Vec::new();
+        write_values(&mut LazyRawBinaryWriter_1_0::new(&mut buffer)?)?;
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA,
+            0xE5, 0x82, 0x8A, 0x8B, 0x21, 0x01,
+            0xB6, 0xE5, 0x82, 0x8A, 0x8B, 0x21, 0x01,
+        ];
+        assert_eq!(buffer, expected);
+
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer.reverse_annotation_order(true);
+        write_values(&mut writer)?;
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA,
+            0xE5, 0x82, 0x8B, 0x8A, 0x21, 0x01,
+            0xB6, 0xE5, 0x82, 0x8B, 0x8A, 0x21, 0x01,
+        ];
+        assert_eq!(buffer, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn always_varuint_length() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer.always_varuint_length(true);
+        writer
+            .write(5)?
+            .write(-5)?
+            .write("hi")?
+            .write([1u8, 2u8])?
+            .write(RawSymbolTokenRef::SymbolId(4))?
+            .write(true.annotated_with(&[4]))?
+            .write([0])?;
+        writer.flush()?;
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA,
+            0x2E, 0x81, 0x05,                   // 5
+            0x3E, 0x81, 0x05,                   // -5
+            0x8E, 0x82, b'h', b'i',             // "hi"
+            0xAE, 0x82, 0x01, 0x02,             // {{AQI=}}
+            0x7E, 0x81, 0x04,                   // $4
+            0xEE, 0x83, 0x81, 0x84, 0x11,       // $4::true
+            0xBE, 0x82, 0x2E, 0x80,             // [0]
+        ];
+        assert_eq!(buffer.as_slice(), expected);
+
+        // Data written this way is read identically to data that uses inline lengths.
+        let expected = Element::read_all(
+            r#"
+            0 name::[true, "hi", ()] {} 0d0 1.5 2024-01-02T 12345678901234567890123456789
+            "#,
+        )?;
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer.always_varuint_length(true);
+        writer.write(0)?;
+        writer
+            .value_writer()
+            .with_annotations(&[4])
+            .write_list(|list| {
+                list.write(true)?
+                    .write("hi")?
+                    .write(Vec::<i32>::new().as_sexp())?;
+                Ok(())
+            })?;
+        writer.value_writer().write_struct(|_| Ok(()))?;
+        writer
+            .write(Decimal::new(0, 0))?
+            .write(Decimal::new(15, -1))?
+            .write(Timestamp::with_ymd(2024, 1, 2).build()?)?;
+        writer
+            .value_writer()
+            .write_int(&Int::from(12345678901234567890123456789i128))?;
+        writer.flush()?;
+        assert_eq!(Element::read_all(&buffer)?, expected);
+        let mut reader = LazyBinaryReader::new(&buffer)?;
+        for element in &expected {
+            assert_eq!(&Element::try_from(reader.expect_next()?)?, element);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn take_buffer() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.write(1)?.write([2])?;
+        assert_eq!(
+            writer.take_buffer(),
+            vec![0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01, 0xB2, 0x21, 0x02]
+        );
+        // Nothing has been written since the buffer was taken.
+        assert_eq!(writer.take_buffer(), Vec::<u8>::new());
+        writer.write(true)?;
+        assert_eq!(writer.take_buffer(), vec![0x11]);
+        Ok(())
+    }
+
+    #[test]
+    fn debug_text_mirror() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.write(0)?;
+        writer.with_debug_text_mirror(b"// mirror\n".to_vec());
+        writer
+            .write(1)?
+            .write([true, false])?
+            .write("foo".annotated_with(&[4]))?;
+        writer.value_writer().write_i64(2)?;
+        let mirror = writer.take_debug_text_mirror().unwrap();
+        writer.write(3)?;
+        assert_eq!(writer.take_debug_text_mirror(), None);
+
+        let binary = writer.take_buffer();
+        let expected = Element::read_all("0 1 [true, false] name::\"foo\" 2 3")?;
+        assert_eq!(Element::read_all(binary)?, expected);
+        let mirror = String::from_utf8(mirror).unwrap();
+        assert!(mirror.starts_with("// mirror\n"));
+        let expected = Element::read_all("1 [true, false] $4::\"foo\"")?;
+        assert_eq!(Element::read_all(mirror)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_terminator() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.take_buffer();
+        writer.write_stream_terminator()?;
+        // $ion_symbol_table::{symbols: []}
+        assert_eq!(
+            writer.take_buffer(),
+            vec![0xE5, 0x81, 0x83, 0xD2, 0x87, 0xB0]
+        );
+
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        writer.terminate_stream_on_flush(true);
+        // $ion_symbol_table::{symbols: ["a"]} $10
+        writer
+            .value_writer()
+            .with_system_annotations(&[SystemSymbol::IonSymbolTable])
+            .write_struct(|fields| {
+                fields.write(SystemSymbol::Symbols, ["a"])?;
+                Ok(())
+            })?;
+        writer.write(RawSymbolTokenRef::SymbolId(10))?;
+        writer.flush()?;
+        // Nothing has been written since the last flush, so no terminator is added.
+        writer.flush()?;
+        writer.write(RawSymbolTokenRef::SymbolId(10))?;
+        writer.flush()?;
+        drop(writer);
+
+        let mut reader = LazySystemAnyReader::new(&buffer);
+        assert_eq!(reader.next_value()?.unwrap().read()?.expect_symbol()?, "a");
+        // The terminator reset the symbol table, so `$10` is no longer defined.
+        assert!(reader.next_value()?.unwrap().read().is_err());
+        // The stream ends with a second terminator.
+        let tables = LazySystemAnyReader::new(&buffer)
+            .symbol_tables_only()
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(tables.len(), 3);
+        assert!(tables[1..].iter().all(|table| table.len() == 10));
+
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.terminate_stream_on_flush(true);
+        writer.take_buffer();
+        // Allocating the encoding buffer without writing a value does not add a terminator.
+        let _ = writer.value_writer();
+        writer.flush()?;
+        assert_eq!(writer.take_buffer(), Vec::<u8>::new());
+        // Neither does a version marker, which already resets the symbol table.
+        writer.write_ion_version_marker()?;
+        writer.flush()?;
+        assert_eq!(writer.take_buffer(), vec![0xE0, 0x01, 0x00, 0xEA]);
+        Ok(())
+    }
+
+    #[test]
+    fn reset_and_reset_keep_version() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.write(1)?;
+        writer.take_buffer();
+
+        // Discard the unflushed `2` and continue the current document.
+        writer.write(2)?;
+        writer.reset_keep_version();
+        writer.write(3)?;
+        assert_eq!(writer.take_buffer(), vec![0x21, 0x03]);
+
+        // Discard the unflushed `4` and begin a new document.
+        writer.write(4)?;
+        writer.reset();
+        writer.write(5)?;
+        assert_eq!(
+            writer.take_buffer(),
+            vec![0xE0, 0x01, 0x00, 0xEA, 0x21, 0x05]
+        );
+
+        // The version marker is only written once per reset, including via `flush`.
+        writer.reset();
+        writer.write(6)?;
+        writer.flush()?;
+        writer.write(7)?;
+        writer.flush()?;
+        assert_eq!(
+            writer.take_buffer(),
+            vec![0xE0, 0x01, 0x00, 0xEA, 0x21, 0x06, 0x21, 0x07]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn deduplicate_version_markers() -> IonResult<()> {
+        const IVM: [u8; 4] = ION_1_0_VERSION_MARKER;
+
+        // Without deduplication, every version marker is written.
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer
+            .write_ion_version_marker()?
+            .write_ion_version_marker()?;
+        assert_eq!(writer.take_buffer(), [IVM, IVM, IVM].concat());
+
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.deduplicate_version_markers(true);
+        // The marker written by `new` makes these redundant.
+        writer
+            .write_ion_version_marker()?
+            .write_ion_version_marker()?;
+        writer.write(1)?;
+        // Two markers in a row yield a single marker.
+        writer
+            .write_ion_version_marker()?
+            .write_ion_version_marker()?;
+        assert_eq!(
+            writer.take_buffer(),
+            [&IVM[..], &[0x21, 0x01], &IVM].concat()
+        );
+
+        // The previous buffer ended with a marker, so neither of these writes another.
+        writer.write_ion_version_marker()?;
+        writer.reset();
+        // Values that are discarded do not separate two markers.
+        writer.write(2)?;
+        writer.reset();
+        writer.write(3)?;
+        assert_eq!(writer.take_buffer(), vec![0x21, 0x03]);
+        Ok(())
+    }
+
+    #[test]
+    fn reuse_annotation_buffer() -> IonResult<()> {
+        // Top-level annotated values are encoded in a scratch buffer that is reused from one
+        // value to the next. It must not disturb annotated values nested inside of them, and it
+        // must be replaced when the writer's allocator is reset.
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(&mut buffer)?;
+        for _ in 0..3 {
+            writer.write(1.annotated_with(&[4]))?;
+            writer
+                .value_writer()
+                .with_annotations(&[5])
+                .write_list(|list| {
+                    list.write(2.annotated_with(&[6]))?;
+                    Ok(())
+                })?;
+            writer.write(3.annotated_with(&[7, 8]))?;
+            writer.flush()?;
+            writer.write(4.annotated_with(&[4]))?;
+            writer.reset_keep_version();
+        }
+        writer.flush()?;
+        drop(writer);
+        let expected =
+            Element::read_all("name::1 version::[imports::2] symbols::max_id::3 ".repeat(3))?;
+        assert_eq!(Element::read_all(buffer)?, expected);
+        Ok(())
+    }
+}
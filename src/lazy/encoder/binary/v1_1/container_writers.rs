@@ -2,6 +2,8 @@ use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 use delegate::delegate;
 
+use crate::lazy::encoder::binary::v1_1::flex_int::FlexInt;
+use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
 use crate::lazy::encoder::binary::v1_1::value_writer::BinaryAnnotatableValueWriter_1_1;
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter};
@@ -9,6 +11,11 @@ use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
 use crate::IonResult;
 
+// The encoding of a FlexSym with a value of zero, which indicates that an opcode follows.
+const FLEX_SYM_ESCAPE: u8 = 0x01;
+// The opcode for a symbol with inline text of length zero.
+const EMPTY_TEXT_OPCODE: u8 = 0x90;
+
 /// A helper type that holds fields and logic that is common to [`BinaryListWriter_1_1`],
 /// [`BinarySExpWriter_1_1`], and [`BinaryStructWriter_1_1`].
 pub struct BinaryContainerWriter_1_1<'value, 'top> {
@@ -185,15 +192,47 @@ impl<'value, 'top> BinarySExpWriter_1_1<'value, 'top> {
 
 pub struct BinaryStructFieldsWriter_1_1<'value> {
     container_values_writer: BinaryContainerValuesWriter_1_1<'value>,
+    // Structs begin by encoding field names as symbol IDs (FlexUInts). Once a field name with
+    // inline text is written, the struct switches to encoding all field names as FlexSyms.
+    flex_sym_mode: bool,
 }
 
 impl<'value> BinaryStructFieldsWriter_1_1<'value> {
     pub fn new(container_values_writer: BinaryContainerValuesWriter_1_1<'value>) -> Self {
         Self {
             container_values_writer,
+            flex_sym_mode: false,
         }
     }
 
+    /// Writes a struct field whose name is encoded as inline UTF-8 text rather than as a symbol
+    /// ID, so the name does not need to be added to a symbol table.
+    ///
+    /// The first time this is called on a struct, it writes a FlexUInt `0`, which switches the
+    /// struct from symbol ID mode to FlexSym mode. Every field name that follows, including
+    /// this one, is encoded as a FlexSym.
+    pub fn write_field_text<V: WriteAsIon>(
+        &mut self,
+        name: &str,
+        value: V,
+    ) -> IonResult<&mut Self> {
+        let buffer = &mut self.container_values_writer.buffer;
+        if !self.flex_sym_mode {
+            FlexUInt::write_u64(buffer, 0)?;
+            self.flex_sym_mode = true;
+        }
+        if name.is_empty() {
+            // A FlexSym of zero is an escape; the opcode that follows indicates empty text.
+            buffer.extend_from_slice(&[FLEX_SYM_ESCAPE, EMPTY_TEXT_OPCODE]);
+        } else {
+            // A negative FlexSym is the length of the inline UTF-8 text that follows.
+            FlexInt::write_i64(buffer, -(name.len() as i64))?;
+            buffer.extend_from_slice(name.as_bytes());
+        }
+        self.container_values_writer.write(value)?;
+        Ok(self)
+    }
+
     pub fn write<A: AsRawSymbolTokenRef, V: WriteAsIon>(
         &mut self,
         _name: A,
@@ -240,3 +279,35 @@ impl<'value, 'top> BinaryStructWriter_1_1<'value, 'top> {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump as BumpAllocator;
+
+    use crate::lazy::encoder::binary::v1_1::container_writers::{
+        BinaryContainerValuesWriter_1_1, BinaryStructFieldsWriter_1_1,
+    };
+    use crate::IonResult;
+
+    #[test]
+    fn write_field_text() -> IonResult<()> {
+        let allocator = BumpAllocator::new();
+        let mut fields =
+            BinaryStructFieldsWriter_1_1::new(BinaryContainerValuesWriter_1_1::new(&allocator));
+        fields
+            .write_field_text("foo", 1)?
+            .write_field_text("", true)?;
+        assert_eq!(
+            fields.container_values_writer.buffer.as_slice(),
+            &[
+                0x01, // FlexUInt 0: switch to FlexSym mode
+                0xFB, // FlexSym -3: 3 bytes of inline text follow
+                b'f', b'o', b'o', // Field name text
+                0x51, 0x01, // Int 1
+                0x01, 0x90, // FlexSym escape, empty text
+                0x5E, // true
+            ]
+        );
+        Ok(())
+    }
+}
@@ -20,6 +20,29 @@ use crate::{
     Decimal, FlexInt, FlexUInt, Int, IonResult, IonType, RawSymbolTokenRef, SymbolId, Timestamp,
 };
 
+/// The encoding used to write a symbol ID in Ion 1.1. Each width is represented by its own opcode
+/// and can only encode symbol IDs within a particular (biased) range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolWidth {
+    /// Opcode `0xE1` followed by a 1-byte FixedUInt. Encodes symbol IDs `0..=255`.
+    OneByte,
+    /// Opcode `0xE2` followed by a 2-byte FixedUInt biased by 256. Encodes symbol IDs `256..=65_791`.
+    TwoBytes,
+    /// Opcode `0xE3` followed by a FlexUInt biased by 65,792. Encodes symbol IDs `65_792` and higher.
+    FlexUInt,
+}
+
+impl SymbolWidth {
+    /// Returns the width that the writer would select for `symbol_id` on its own.
+    pub fn for_symbol_id(symbol_id: SymbolId) -> SymbolWidth {
+        match symbol_id {
+            0..=255 => SymbolWidth::OneByte,
+            256..=65_791 => SymbolWidth::TwoBytes,
+            _ => SymbolWidth::FlexUInt,
+        }
+    }
+}
+
 pub struct BinaryValueWriter_1_1<'value, 'top> {
     allocator: &'top BumpAllocator,
     encoding_buffer: &'value mut BumpVec<'top, u8>,
@@ -254,18 +277,42 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
 
     #[inline]
     fn write_symbol_id(&mut self, symbol_id: SymbolId) -> IonResult<()> {
-        match symbol_id {
-            0..=255 => {
+        let width = SymbolWidth::for_symbol_id(symbol_id);
+        self.write_symbol_id_with_width(symbol_id, width)
+    }
+
+    /// Writes the symbol ID `sid` using the opcode that corresponds to the requested `width`.
+    ///
+    /// Each width can only represent a fixed range of symbol IDs: [`SymbolWidth::OneByte`] covers
+    /// `0..=255`, [`SymbolWidth::TwoBytes`] covers `256..=65_791`, and [`SymbolWidth::FlexUInt`]
+    /// covers `65_792` and higher. If `sid` falls outside the range of the requested width, an
+    /// encoding error is returned and nothing is written.
+    pub fn write_symbol_id_fixed(mut self, sid: SymbolId, width: SymbolWidth) -> IonResult<()> {
+        if SymbolWidth::for_symbol_id(sid) != width {
+            return IonResult::encoding_error(format!(
+                "symbol ID {sid} cannot be encoded with width {width:?}"
+            ));
+        }
+        self.write_symbol_id_with_width(sid, width)
+    }
+
+    fn write_symbol_id_with_width(
+        &mut self,
+        symbol_id: SymbolId,
+        width: SymbolWidth,
+    ) -> IonResult<()> {
+        match width {
+            SymbolWidth::OneByte => {
                 self.push_bytes(&[0xE1, symbol_id as u8]);
             }
             // The u16::MAX range, but biased by 256.
-            256..=65_791 => {
+            SymbolWidth::TwoBytes => {
                 self.push_byte(0xE2); // Two-byte biased FixedUInt follows
                 let encoded_length = ((symbol_id - 256) as u16).to_le_bytes();
                 self.push_bytes(encoded_length.as_slice());
             }
             // 65,792 and higher
-            _ => {
+            SymbolWidth::FlexUInt => {
                 self.push_byte(0xE3); // Biased FlexUInt follows
                 FlexUInt::write_u64(self.encoding_buffer, symbol_id as u64 - 65_792)?;
             }
@@ -605,7 +652,9 @@ impl<'value, 'top: 'value> ValueWriter for BinaryAnnotatedValueWriter_1_1<'value
 
 #[cfg(test)]
 mod tests {
+    use crate::lazy::encoder::binary::v1_1::value_writer::SymbolWidth;
     use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
+    use crate::lazy::encoder::value_writer::AnnotatableValueWriter;
     use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
     use crate::{Decimal, Int, IonResult, IonType, Null, SymbolId};
     use num_bigint::BigInt;
@@ -842,6 +891,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_symbol_ids_with_fixed_width() -> IonResult<()> {
+        let test_cases: &[(SymbolId, SymbolWidth, &[u8])] = &[
+            (0, SymbolWidth::OneByte, &[0xE1, 0x00]),
+            (255, SymbolWidth::OneByte, &[0xE1, 0xFF]),
+            (256, SymbolWidth::TwoBytes, &[0xE2, 0x00, 0x00]),
+            (65_791, SymbolWidth::TwoBytes, &[0xE2, 0xFF, 0xFF]),
+            (65_792, SymbolWidth::FlexUInt, &[0xE3, 0x01]),
+        ];
+        for (sid, width, expected_encoding) in test_cases {
+            encoding_test(
+                |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                    writer
+                        .value_writer()
+                        .without_annotations()
+                        .write_symbol_id_fixed(*sid, *width)
+                },
+                expected_encoding,
+            )?;
+        }
+
+        let mismatches: &[(SymbolId, SymbolWidth)] = &[
+            (256, SymbolWidth::OneByte),
+            (255, SymbolWidth::TwoBytes),
+            (65_792, SymbolWidth::TwoBytes),
+            (65_791, SymbolWidth::FlexUInt),
+        ];
+        for (sid, width) in mismatches {
+            let mut buffer = Vec::new();
+            let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+            let result = writer
+                .value_writer()
+                .without_annotations()
+                .write_symbol_id_fixed(*sid, *width);
+            assert!(result.is_err(), "{sid} should not fit in {width:?}");
+        }
+        Ok(())
+    }
+
     #[test]
     fn write_decimals() -> IonResult<()> {
         let test_cases: &[(Decimal, &[u8])] = &[
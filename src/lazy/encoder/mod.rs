@@ -9,6 +9,8 @@ use crate::IonResult;
 
 pub mod annotate;
 pub mod binary;
+pub mod symbol_table_writer;
+pub mod tee_sink;
 pub mod text;
 pub mod value_writer;
 pub mod write_as_ion;
@@ -50,7 +52,7 @@ mod tests {
     use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
     use crate::lazy::encoder::value_writer::{AnnotatableValueWriter, StructWriter};
     use crate::symbol_ref::AsSymbolRef;
-    use crate::{Element, IonData, IonResult, Timestamp};
+    use crate::{Element, IonData, IonResult, RawSymbolTokenRef, Timestamp};
 
     fn writer_test(
         expected: &str,
@@ -212,4 +214,101 @@ mod tests {
         };
         writer_test(expected, test)
     }
+
+    #[test]
+    fn write_symbols_with_quoting() -> IonResult<()> {
+        let expected = r#"
+            foo
+            '$10'
+            'null'
+            'true'
+            'hello world'
+            '1st'
+            '+'
+            'it\'s'
+            'tab\tand\x01control'
+            ('+' '<=' foo 'null' 'a b' '//')
+            ['+']
+            {'+': '-'}
+            'two words'::'null'::1
+        "#;
+        let test = |writer: &mut LazyRawTextWriter_1_0<&mut Vec<u8>>| {
+            writer
+                .write("foo".as_symbol_ref())?
+                .write("$10".as_symbol_ref())?
+                .write("null".as_symbol_ref())?
+                .write("true".as_symbol_ref())?
+                .write("hello world".as_symbol_ref())?
+                .write("1st".as_symbol_ref())?
+                .write("+".as_symbol_ref())?
+                .write("it's".as_symbol_ref())?
+                .write("tab\tand\x01control".as_symbol_ref())?;
+            writer.value_writer().write_sexp(|sexp| {
+                sexp.write("+".as_symbol_ref())?
+                    .write("<=".as_symbol_ref())?
+                    .write("foo".as_symbol_ref())?
+                    .write("null".as_symbol_ref())?
+                    .write("a b".as_symbol_ref())?
+                    .write("//".as_symbol_ref())?;
+                Ok(())
+            })?;
+            writer.value_writer().write_list(|list| {
+                list.write("+".as_symbol_ref())?;
+                Ok(())
+            })?;
+            writer.value_writer().write_struct(|struct_| {
+                struct_.write("+", "-".as_symbol_ref())?;
+                Ok(())
+            })?;
+            writer.write(1.annotated_with(&["two words", "null"]))?;
+            Ok(())
+        };
+        writer_test(expected, test)
+    }
+
+    #[test]
+    fn write_symbol_edge_cases() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawTextWriter_1_0::new(&mut buffer);
+        writer
+            .write("".as_symbol_ref())?
+            .write("'".as_symbol_ref())?
+            .write("it's".as_symbol_ref())?
+            .write("nan".as_symbol_ref())?
+            .write("false".as_symbol_ref())?
+            .write("$0".as_symbol_ref())?
+            .write(RawSymbolTokenRef::SymbolId(0))?;
+        writer.flush()?;
+        let text = String::from_utf8(buffer).unwrap();
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        assert_eq!(
+            tokens,
+            vec!["''", r"'\''", r"'it\'s'", "'nan'", "'false'", "'$0'", "$0"]
+        );
+        // Each symbol's text survives a round trip, and `$0` is read as a symbol with unknown text.
+        let expected = Element::read_all(r"'' '\'' 'it\'s' 'nan' 'false' '$0' $0")?;
+        assert_eq!(Element::read_all(text)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn write_operators_unquoted_in_sexp() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawTextWriter_1_0::new(&mut buffer);
+        writer.value_writer().write_sexp(|sexp| {
+            sexp.write("+".as_symbol_ref())?
+                .write("a b".as_symbol_ref())?
+                .write("/*".as_symbol_ref())?;
+            Ok(())
+        })?;
+        writer.write("+".as_symbol_ref())?;
+        writer.flush()?;
+        let text = String::from_utf8(buffer).unwrap();
+        // Only the top-level `+` requires quotes
+        assert_eq!(text.matches("'+'").count(), 1, "{text}");
+        assert!(text.trim_end().ends_with("'+'"), "{text}");
+        assert!(text.contains("'a b'"), "{text}");
+        assert!(text.contains(r"'\/*'"), "{text}");
+        Ok(())
+    }
 }
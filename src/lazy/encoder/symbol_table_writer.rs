@@ -0,0 +1,145 @@
+use crate::lazy::encoder::value_writer::{
+    AnnotatableValueWriter, SequenceWriter, StructWriter, ValueWriter,
+};
+use crate::lazy::encoder::write_as_ion::DynValue;
+use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
+use crate::result::IonFailure;
+use crate::{IonResult, IonType, Symbol, SymbolTable, SystemSymbol};
+
+/// Writes local symbol tables to a raw writer, which does not manage a symbol table of its own.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Element, SymbolTable};
+/// use ion_rs::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+/// use ion_rs::lazy::encoder::symbol_table_writer::SymbolTableWriter;
+/// use ion_rs::RawSymbolTokenRef;
+///
+/// let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+/// let mut symbol_table = SymbolTable::new();
+///
+/// let previous = symbol_table.clone();
+/// let foo = symbol_table.intern("foo");
+/// SymbolTableWriter::new(&mut writer).write_delta(&previous, &symbol_table)?;
+/// writer.write(RawSymbolTokenRef::SymbolId(foo))?;
+///
+/// assert_eq!(Element::read_one(writer.take_buffer())?, Element::symbol("foo"));
+///# Ok(())
+///# }
+/// ```
+pub struct SymbolTableWriter<'a, S: SequenceWriter> {
+    writer: &'a mut S,
+}
+
+impl<'a, S: SequenceWriter> SymbolTableWriter<'a, S> {
+    pub fn new(writer: &'a mut S) -> Self {
+        Self { writer }
+    }
+
+    /// Writes the local symbol table append that takes a reader whose active symbol table is
+    /// `from` to one whose active symbol table is `to`. That is:
+    /// ```ion
+    /// $ion_symbol_table::{imports: $ion_symbol_table, symbols: [/* symbols added in `to` */]}
+    /// ```
+    /// Symbols with unknown text are written as `null.string`. If `to` does not define any symbols
+    /// beyond those in `from`, nothing is written.
+    ///
+    /// Returns an [`IonError::IllegalOperation`](crate::IonError::IllegalOperation) if `to` is
+    /// not `from` with zero or more symbols appended, as no append could produce it.
+    pub fn write_delta(&mut self, from: &SymbolTable, to: &SymbolTable) -> IonResult<()> {
+        if to.len() < from.len() {
+            return IonResult::illegal_operation(format!(
+                "cannot append to a symbol table with {} symbols to produce one with {} symbols",
+                from.len(),
+                to.len()
+            ));
+        }
+        if let Some(sid) = (0..from.len()).find(|sid| from.text_for(*sid) != to.text_for(*sid)) {
+            return IonResult::illegal_operation(format!(
+                "cannot append to a symbol table to produce another; symbol ID {sid} differs"
+            ));
+        }
+        self.write_append(&to.symbols()[from.len()..])
+    }
+
+    /// Writes a local symbol table append that adds `new_symbols` to the reader's active symbol
+    /// table. Symbols with unknown text are written as `null.string`. If `new_symbols` is empty,
+    /// nothing is written.
+    ///
+    /// Unlike [`write_delta`](Self::write_delta), this does not require a copy of the symbol
+    /// table as it was before the symbols were added.
+    pub fn write_append(&mut self, new_symbols: &[Symbol]) -> IonResult<()> {
+        if new_symbols.is_empty() {
+            return Ok(());
+        }
+        let symbols = DynValue::List(
+            new_symbols
+                .iter()
+                .map(|symbol| match symbol.text() {
+                    Some(text) => DynValue::String(text.to_owned()),
+                    None => DynValue::Null(IonType::String),
+                })
+                .collect(),
+        );
+        self.writer
+            .value_writer()
+            .with_system_annotations(&[SystemSymbol::IonSymbolTable])
+            .write_struct(|fields| {
+                fields
+                    .write(
+                        SystemSymbol::Imports,
+                        SystemSymbol::IonSymbolTable.as_raw_symbol_token_ref(),
+                    )?
+                    .write(SystemSymbol::Symbols, &symbols)?;
+                Ok(())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+    use crate::lazy::encoder::symbol_table_writer::SymbolTableWriter;
+    use crate::lazy::encoder::text::LazyRawTextWriter_1_0;
+    use crate::{Element, IonResult, RawSymbolTokenRef, SymbolTable};
+
+    #[test]
+    fn write_delta() -> IonResult<()> {
+        let mut binary_writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        let mut text_buffer = Vec::new();
+        let mut text_writer = LazyRawTextWriter_1_0::new(&mut text_buffer);
+
+        let mut symbol_table = SymbolTable::new();
+        for texts in [&["a", "b"][..], &[], &["c"]] {
+            let previous = symbol_table.clone();
+            let sids = symbol_table.intern_all(texts.iter().copied());
+            SymbolTableWriter::new(&mut binary_writer).write_delta(&previous, &symbol_table)?;
+            SymbolTableWriter::new(&mut text_writer).write_delta(&previous, &symbol_table)?;
+            for sid in sids {
+                binary_writer.write(RawSymbolTokenRef::SymbolId(sid))?;
+                text_writer.write(RawSymbolTokenRef::SymbolId(sid))?;
+            }
+        }
+
+        let expected = Element::read_all("a b c")?;
+        assert_eq!(Element::read_all(binary_writer.take_buffer())?, expected);
+        assert_eq!(Element::read_all(text_buffer)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn write_delta_requires_append() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.take_buffer();
+        let ab = SymbolTable::from_symbols(&["a", "b"]);
+        let a = SymbolTable::from_symbols(&["a"]);
+        let c = SymbolTable::from_symbols(&["c"]);
+        let mut symbol_table_writer = SymbolTableWriter::new(&mut writer);
+        assert!(symbol_table_writer.write_delta(&ab, &a).is_err());
+        assert!(symbol_table_writer.write_delta(&a, &c).is_err());
+        assert!(symbol_table_writer.write_delta(&ab, &ab).is_ok());
+        assert!(writer.take_buffer().is_empty());
+        Ok(())
+    }
+}
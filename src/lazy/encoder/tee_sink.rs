@@ -0,0 +1,129 @@
+use std::io;
+use std::io::Write;
+
+/// An implementation of [`Write`] that forwards every byte it receives to two inner sinks. This
+/// allows a writer to (for example) persist its output to a file while also feeding it to a hash
+/// computation.
+///
+/// Each write is forwarded to the first sink and then to the second in its entirety, so both
+/// sinks receive identical data. If either sink fails, the returned [`io::Error`] has the same
+/// [`ErrorKind`](io::ErrorKind) as the original and a message identifying which sink failed.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# fn main() -> IonResult<()> {
+/// use ion_rs::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+/// use ion_rs::lazy::encoder::tee_sink::TeeSink;
+///
+/// let mut first = Vec::new();
+/// let mut second = Vec::new();
+/// let mut writer = LazyRawBinaryWriter_1_0::new(TeeSink::new(&mut first, &mut second))?;
+/// writer.write(1)?.write("foo")?;
+/// writer.flush()?;
+/// drop(writer);
+/// assert_eq!(first, second);
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug)]
+pub struct TeeSink<A: Write, B: Write> {
+    first: A,
+    second: B,
+}
+
+impl<A: Write, B: Write> TeeSink<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns references to the first and second sinks.
+    pub fn get_ref(&self) -> (&A, &B) {
+        (&self.first, &self.second)
+    }
+
+    /// Consumes the `TeeSink`, returning the first and second sinks.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    /// Adds the name of the sink that produced `error` to its message.
+    fn sink_error(sink_name: &str, error: io::Error) -> io::Error {
+        io::Error::new(error.kind(), format!("{sink_name} sink failed: {error}"))
+    }
+}
+
+impl<A: Write, B: Write> Write for TeeSink<A, B> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // A partial write to either sink would leave the two out of step, so each one is given
+        // the complete buffer.
+        self.first
+            .write_all(buf)
+            .map_err(|e| Self::sink_error("first", e))?;
+        self.second
+            .write_all(buf)
+            .map_err(|e| Self::sink_error("second", e))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.first
+            .flush()
+            .map_err(|e| Self::sink_error("first", e))?;
+        self.second
+            .flush()
+            .map_err(|e| Self::sink_error("second", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::io::Write;
+
+    use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+    use crate::lazy::encoder::tee_sink::TeeSink;
+    use crate::{Element, IonResult};
+
+    /// A sink that rejects every write and flush.
+    struct FailingSink;
+
+    impl Write for FailingSink {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+    }
+
+    #[test]
+    fn both_sinks_receive_output() -> IonResult<()> {
+        let mut first = Vec::new();
+        let mut second = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_0::new(TeeSink::new(&mut first, &mut second))?;
+        writer.write(1)?.write("foo")?.write([true, false])?;
+        writer.flush()?;
+        drop(writer);
+        assert_eq!(first, second);
+        assert_eq!(
+            Element::read_all(first)?,
+            Element::read_all("1 \"foo\" [true, false]")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors_identify_the_failing_sink() {
+        let mut sink = TeeSink::new(Vec::new(), FailingSink);
+        let error = sink.write_all(b"ion").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(error.to_string(), "second sink failed: closed");
+        // The first sink received the data before the second one failed.
+        assert_eq!(sink.get_ref().0.as_slice(), b"ion");
+
+        let mut sink = TeeSink::new(FailingSink, Vec::new());
+        let error = sink.flush().unwrap_err();
+        assert_eq!(error.to_string(), "first sink failed: closed");
+    }
+}
@@ -5,7 +5,7 @@ use crate::lazy::encoder::value_writer::{
     AnnotatableValueWriter, SequenceWriter, StructWriter, ValueWriter,
 };
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
-use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
+use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
 use crate::result::IonResult;
 use crate::text::raw_text_writer::{RawTextWriter, WhitespaceConfig};
 use crate::text::text_formatter::IonValueFormatter;
@@ -18,11 +18,18 @@ use std::io::Write;
 pub struct TextValueWriter_1_0<'value, W: Write + 'value> {
     writer: &'value mut LazyRawTextWriter_1_0<W>,
     depth: usize,
+    // The type of the container in which this value will be written, or `None` at the top level.
+    // Used to determine whether operator symbols can be written without quotes.
+    parent_type: Option<IonType>,
 }
 
 impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
     pub fn new(writer: &'value mut LazyRawTextWriter_1_0<W>, depth: usize) -> Self {
-        Self { writer, depth }
+        Self {
+            writer,
+            depth,
+            parent_type: None,
+        }
     }
 }
 
@@ -80,10 +87,8 @@ impl<'value, W: Write, SymbolType: AsRawSymbolTokenRef>
     fn encode_annotations(self) -> IonResult<TextValueWriter_1_0<'value, W>> {
         let output = &mut self.value_writer.writer.output;
         for annotation in self.annotations {
-            match annotation.as_raw_symbol_token_ref() {
-                RawSymbolTokenRef::Text(token) => write!(output, "{}::", token.as_ref()),
-                RawSymbolTokenRef::SymbolId(sid) => write!(output, "${sid}::"),
-            }?;
+            RawTextWriter::<W>::write_symbol_token(output, annotation)?;
+            write!(output, "::")?;
         }
 
         Ok(self.value_writer)
@@ -108,8 +113,8 @@ struct TextContainerWriter_1_0<'a, W: Write> {
     // Tracks whether the `end()` method was called (thereby emitting a closing delimiter) before
     // this value was dropped. This scenario is a contract violation and results in a panic.
     has_been_closed: bool,
-    // The Ion type of the container using this TextContainerWriter_1_0. This value is only
-    // used for more informative error messages.
+    // The Ion type of the container using this TextContainerWriter_1_0. This value is used for
+    // more informative error messages and to decide how nested symbols are written.
     ion_type: IonType,
 }
 
@@ -199,6 +204,7 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
         TextValueWriter_1_0 {
             writer: self.writer,
             depth: self.depth,
+            parent_type: Some(self.ion_type),
         }
     }
 
@@ -458,7 +464,11 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
     }
 
     fn write_symbol(mut self, value: impl AsRawSymbolTokenRef) -> IonResult<()> {
-        RawTextWriter::<W>::write_symbol_token(self.output(), value)?;
+        if self.parent_type == Some(IonType::SExp) {
+            RawTextWriter::<W>::write_sexp_symbol_token(self.output(), value)?;
+        } else {
+            RawTextWriter::<W>::write_symbol_token(self.output(), value)?;
+        }
         Ok(())
     }
 
@@ -1,7 +1,8 @@
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
-use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::encoder::write_as_ion::{WriteAsIon, WriteAsIonValue};
 use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
-use crate::{Decimal, Int, IonResult, IonType, Timestamp};
+use crate::result::IonFailure;
+use crate::{Decimal, Int, IonError, IonResult, IonType, SymbolTable, SystemSymbol, Timestamp};
 use delegate::delegate;
 
 pub(crate) mod internal {
@@ -35,9 +36,38 @@ pub trait AnnotatableValueWriter: Sized {
     where
         Self: 'a;
 
+    /// Writes the provided annotations, each of which is a symbol from the Ion 1.0 system symbol
+    /// table, and returns a [`ValueWriter`] that can be used to serialize the value itself.
+    fn with_system_annotations<'a>(
+        self,
+        annotations: &'a [SystemSymbol],
+    ) -> Self::AnnotatedValueWriter<'a, SystemSymbol>
+    where
+        Self: 'a,
+    {
+        self.with_annotations(annotations)
+    }
+
     /// Performs no operations and returns a [`ValueWriter`].
     fn without_annotations(self) -> Self::ValueWriter;
 
+    /// Writes `value` annotated with the name of its Ion type; for example, `int::5` or
+    /// `list::[1, 2]`. A typed null is annotated with its type, so `null.int` is written as
+    /// `int::null.int`.
+    ///
+    /// The type name is interned in `symtab` and the annotation is written as the resulting
+    /// symbol ID, so `symtab` must be the symbol table that will be in effect when the output
+    /// is read.
+    fn write_type_annotated<V: WriteAsIonValue>(
+        self,
+        symtab: &mut SymbolTable,
+        value: V,
+    ) -> IonResult<()> {
+        let ion_type = IonTypeProbe::ion_type_of(&value)?;
+        let annotation = [symtab.intern(ion_type.to_string())];
+        value.write_as_ion_value(self.with_annotations(&annotation))
+    }
+
     // Users can call `ValueWriter` methods on the `AnnotatedValueWriter` directly. Doing so
     // will implicitly call `without_annotations`.
     delegate! {
@@ -46,13 +76,21 @@ pub trait AnnotatableValueWriter: Sized {
             fn write_bool(self, value: bool) -> IonResult<()>;
             fn write_i64(self, value: i64) -> IonResult<()>;
             fn write_int(self, value: &Int) -> IonResult<()>;
+            fn write_i128(self, value: i128) -> IonResult<()>;
+            fn write_u128(self, value: u128) -> IonResult<()>;
             fn write_f32(self, value: f32) -> IonResult<()>;
             fn write_f64(self, value: f64) -> IonResult<()>;
             fn write_decimal(self, value: &Decimal) -> IonResult<()>;
+            fn write_f64_as_decimal(self, value: f64) -> IonResult<()>;
+            fn write_f64_with(self, value: f64, options: FloatWriteOptions) -> IonResult<()>;
+            fn write_number(self, value: f64) -> IonResult<()>;
             fn write_timestamp(self, value: &Timestamp) -> IonResult<()>;
             fn write_string<A: AsRef<str>>(self, value: A) -> IonResult<()>;
+            fn write_string_truncated(self, text: &str, max_bytes: usize, policy: TruncatePolicy) -> IonResult<()>;
             fn write_symbol<A: AsRawSymbolTokenRef>(self, value: A) -> IonResult<()>;
+            fn write_system_symbol(self, symbol: SystemSymbol) -> IonResult<()>;
             fn write_clob<A: AsRef<[u8]>>(self, value: A) -> IonResult<()>;
+            fn write_clob_str(self, text: &str) -> IonResult<()>;
             fn write_blob<A: AsRef<[u8]>>(self, value: A) -> IonResult<()>;
             fn write_list<F: for<'a> FnOnce(&mut <Self::ValueWriter as ValueWriter>::ListWriter<'a>) -> IonResult<()>>(
                 self,
@@ -72,6 +110,42 @@ pub trait AnnotatableValueWriter: Sized {
     }
 }
 
+/// Rules for encoding an individual float. See [`ValueWriter::write_f64_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatWriteOptions {
+    /// If `false`, negative zero is written as positive zero. Defaults to `true`.
+    pub preserve_negative_zero: bool,
+    /// If `true`, attempting to write NaN or an infinity is an error. Defaults to `false`.
+    pub reject_non_finite: bool,
+    /// If `true`, values that can be represented as an `f32` without any loss of precision are
+    /// written as 32-bit floats. A NaN is only narrowed if its payload survives the conversion.
+    /// Defaults to `false`.
+    pub minimal_width: bool,
+}
+
+impl Default for FloatWriteOptions {
+    fn default() -> Self {
+        Self {
+            preserve_negative_zero: true,
+            reject_non_finite: false,
+            minimal_width: false,
+        }
+    }
+}
+
+/// How [`ValueWriter::write_string_truncated`] handles text that exceeds its limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncatePolicy {
+    /// Text longer than the limit is not written; an encoding error is returned instead.
+    Error,
+    /// The limit is a number of UTF-8 bytes. Text is truncated to the longest prefix that fits
+    /// within the limit and ends on a character boundary, so a multi-byte character is never split.
+    TruncateBytes,
+    /// The limit is a number of characters (Unicode scalar values) rather than bytes. Text is
+    /// truncated to that many characters.
+    TruncateChars,
+}
+
 pub trait ValueWriter {
     type ListWriter<'a>: SequenceWriter;
     type SExpWriter<'a>: SequenceWriter;
@@ -81,13 +155,148 @@ pub trait ValueWriter {
     fn write_bool(self, value: bool) -> IonResult<()>;
     fn write_i64(self, value: i64) -> IonResult<()>;
     fn write_int(self, value: &Int) -> IonResult<()>;
+
+    /// Writes `value` as an Ion int. Writers may override this to avoid converting values outside
+    /// the range of an `i64` to an [`Int`].
+    fn write_i128(self, value: i128) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        self.write_int(&Int::from(value))
+    }
+
+    /// Writes `value` as an Ion int. Writers may override this to avoid converting values outside
+    /// the range of an `i64` to an [`Int`].
+    fn write_u128(self, value: u128) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        self.write_int(&Int::from(value))
+    }
+
     fn write_f32(self, value: f32) -> IonResult<()>;
     fn write_f64(self, value: f64) -> IonResult<()>;
     fn write_decimal(self, value: &Decimal) -> IonResult<()>;
+
+    /// Writes `value` as an Ion decimal rather than a float, using the decimal with the fewest
+    /// significant digits that converts back to exactly `value`.
+    ///
+    /// Returns an error if `value` is NaN or infinite, as decimals cannot represent them.
+    fn write_f64_as_decimal(self, value: f64) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        self.write_decimal(&Decimal::shortest_from_f64(value)?)
+    }
+
+    /// Writes `value` as an Ion float, applying the rules in `options`. This allows stricter
+    /// rules to be applied to specific values than to the rest of the stream. With the default
+    /// options, this is equivalent to [`Self::write_f64`].
+    ///
+    /// Returns an encoding error if `options` rejects `value`.
+    fn write_f64_with(self, value: f64, options: FloatWriteOptions) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        if options.reject_non_finite && !value.is_finite() {
+            return IonResult::encoding_error(format!(
+                "float value {value} is not finite and cannot be written"
+            ));
+        }
+        let value = if value == 0f64 && !options.preserve_negative_zero {
+            0f64
+        } else {
+            value
+        };
+        let narrowed = value as f32;
+        // Comparing the bits rather than the values keeps the sign of zero and any NaN payload
+        // that an `f32` cannot hold.
+        if options.minimal_width && (narrowed as f64).to_bits() == value.to_bits() {
+            return self.write_f32(narrowed);
+        }
+        self.write_f64(value)
+    }
+
+    /// Writes `value` as an Ion int if it is an exact integer within the range of an `i64`, and as
+    /// an Ion float otherwise. This is useful when mapping numbers from formats that do not
+    /// distinguish between integers and floats (like JSON) into Ion.
+    ///
+    /// Negative zero is written as a float so that its sign is not lost.
+    fn write_number(self, value: f64) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        // 2^63; every f64 in [-2^63, 2^63) with no fractional part is exactly representable as an
+        // i64.
+        const I64_LIMIT: f64 = 9_223_372_036_854_775_808f64;
+        let is_negative_zero = value == 0f64 && value.is_sign_negative();
+        if value.fract() == 0f64 && (-I64_LIMIT..I64_LIMIT).contains(&value) && !is_negative_zero {
+            return self.write_i64(value as i64);
+        }
+        self.write_f64(value)
+    }
+
     fn write_timestamp(self, value: &Timestamp) -> IonResult<()>;
     fn write_string(self, value: impl AsRef<str>) -> IonResult<()>;
+
+    /// Writes `text` as an Ion string, ensuring that it does not exceed `max_bytes`. Text that is
+    /// too long is handled according to `policy`; see [`TruncatePolicy`] for details. (With
+    /// [`TruncatePolicy::TruncateChars`], `max_bytes` is interpreted as a number of characters.)
+    fn write_string_truncated(
+        self,
+        text: &str,
+        max_bytes: usize,
+        policy: TruncatePolicy,
+    ) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        let text = match policy {
+            TruncatePolicy::Error if text.len() > max_bytes => {
+                return IonResult::encoding_error(format!(
+                    "string is {} bytes long, which exceeds the maximum of {max_bytes}",
+                    text.len()
+                ));
+            }
+            TruncatePolicy::Error => text,
+            TruncatePolicy::TruncateBytes => {
+                let mut end = text.len().min(max_bytes);
+                // Back up to the start of any character that the limit would split.
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                &text[..end]
+            }
+            TruncatePolicy::TruncateChars => match text.char_indices().nth(max_bytes) {
+                Some((end, _)) => &text[..end],
+                None => text,
+            },
+        };
+        self.write_string(text)
+    }
     fn write_symbol(self, value: impl AsRawSymbolTokenRef) -> IonResult<()>;
+
+    /// Writes a symbol from the Ion 1.0 system symbol table. Unlike [`Self::write_symbol`], this
+    /// cannot be passed a symbol ID that is outside the system table.
+    fn write_system_symbol(self, symbol: SystemSymbol) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        self.write_symbol(symbol)
+    }
+
     fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
+
+    /// Writes the UTF-8 bytes of `text` as an Ion clob.
+    ///
+    /// Clobs are opaque; no encoding declaration is written alongside the bytes.
+    fn write_clob_str(self, text: &str) -> IonResult<()>
+    where
+        Self: Sized,
+    {
+        self.write_clob(text.as_bytes())
+    }
+
     fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
 
     fn write_list<F: for<'a> FnOnce(&mut Self::ListWriter<'a>) -> IonResult<()>>(
@@ -112,6 +321,115 @@ pub trait StructWriter {
         name: A,
         value: V,
     ) -> IonResult<&mut Self>;
+
+    /// Writes a struct field using the provided name and `value` if `value` is `Some`. If it is
+    /// `None`, the field is omitted.
+    ///
+    /// An optional field whose value is produced fallibly can be written by first transposing it:
+    /// `fields.write_opt(name, value.transpose()?)`.
+    fn write_opt<A: AsRawSymbolTokenRef, V: WriteAsIon>(
+        &mut self,
+        name: A,
+        value: Option<V>,
+    ) -> IonResult<&mut Self> {
+        match value {
+            Some(value) => self.write(name, value),
+            None => Ok(self),
+        }
+    }
+
+    /// Writes a struct field whose value is a list of the provided `values`. If `values` is an
+    /// error, it is returned and nothing is written.
+    fn write_list_try<A, I, V>(&mut self, name: A, values: IonResult<I>) -> IonResult<&mut Self>
+    where
+        A: AsRawSymbolTokenRef,
+        I: IntoIterator<Item = V>,
+        V: WriteAsIon,
+    {
+        let values: Vec<V> = values?.into_iter().collect();
+        self.write(name, CollectedList(values))
+    }
+
+    /// Writes a struct field whose value is a list of the values produced by `values`. All of the
+    /// values are collected before the field is written; if any of them is an error, it is
+    /// returned and nothing is written.
+    fn write_iter<A, I, V>(&mut self, name: A, values: I) -> IonResult<&mut Self>
+    where
+        A: AsRawSymbolTokenRef,
+        I: IntoIterator<Item = IonResult<V>>,
+        V: WriteAsIon,
+    {
+        let values = values.into_iter().collect::<IonResult<Vec<V>>>()?;
+        self.write(name, CollectedList(values))
+    }
+
+    /// Writes a struct field whose value is a struct containing the name/value pairs produced by
+    /// `fields`. All of the pairs are collected before the field is written; if any of them is an
+    /// error, it is returned and nothing is written.
+    fn write_struct_try<A, I, B, V>(&mut self, name: A, fields: I) -> IonResult<&mut Self>
+    where
+        A: AsRawSymbolTokenRef,
+        I: IntoIterator<Item = IonResult<(B, V)>>,
+        B: AsRawSymbolTokenRef,
+        V: WriteAsIon,
+    {
+        let fields = fields.into_iter().collect::<IonResult<Vec<(B, V)>>>()?;
+        self.write(name, CollectedFields(fields))
+    }
+
+    /// Writes a struct field for each of the name/value pairs in `fields`, in order, and upon
+    /// success returns another reference to `self`. If writing a field fails, the error is
+    /// returned immediately; the fields that preceded it have already been written.
+    fn write_all<I, A, V>(&mut self, fields: I) -> IonResult<&mut Self>
+    where
+        I: IntoIterator<Item = (A, V)>,
+        A: AsRawSymbolTokenRef,
+        V: WriteAsIon,
+    {
+        for (name, value) in fields {
+            self.write(name, value)?;
+        }
+        Ok(self)
+    }
+}
+
+/// The values gathered by the `write_list_try` and `write_iter` methods, which are written as a
+/// list.
+struct CollectedList<V>(Vec<V>);
+
+impl<V: WriteAsIon> WriteAsIonValue for CollectedList<V> {
+    fn write_as_ion_value<W: ValueWriter>(&self, writer: W) -> IonResult<()> {
+        writer.write_list(|list| {
+            for value in &self.0 {
+                value.write_as_ion(list.value_writer())?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// The name/value pairs gathered by the `write_struct_try` methods, which are written as a struct.
+struct CollectedFields<A, V>(Vec<(A, V)>);
+
+impl<A: AsRawSymbolTokenRef, V: WriteAsIon> WriteAsIonValue for CollectedFields<A, V> {
+    fn write_as_ion_value<W: ValueWriter>(&self, writer: W) -> IonResult<()> {
+        writer.write_struct(|fields| {
+            for (name, value) in &self.0 {
+                fields.write(name, Borrowed(value))?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Writes the value it refers to, allowing a borrowed field value to be passed to
+/// [`StructWriter::write`].
+struct Borrowed<'a, V>(&'a V);
+
+impl<'a, V: WriteAsIon> WriteAsIon for Borrowed<'a, V> {
+    fn write_as_ion<W: AnnotatableValueWriter>(&self, writer: W) -> IonResult<()> {
+        self.0.write_as_ion(writer)
+    }
 }
 
 /// Takes a series of `TYPE => METHOD` pairs, generating a function for each that calls the
@@ -162,6 +480,77 @@ pub trait SequenceWriter: MakeValueWriter {
         impl AsRef<[u8]> => write_blob,
     );
 
+    /// Writes `value` if it is `Some`, or an untyped `null` if it is `None`, and upon success
+    /// returns another reference to `self`.
+    fn write_opt<V: WriteAsIon>(&mut self, value: Option<V>) -> IonResult<&mut Self> {
+        match value {
+            Some(value) => self.write(value),
+            None => self.write_null(IonType::Null),
+        }
+    }
+
+    /// Writes a list of the provided `values` and upon success returns another reference to
+    /// `self`. If `values` is an error, it is returned and nothing is written.
+    fn write_list_try<I, V>(&mut self, values: IonResult<I>) -> IonResult<&mut Self>
+    where
+        I: IntoIterator<Item = V>,
+        V: WriteAsIon,
+    {
+        let values: Vec<V> = values?.into_iter().collect();
+        self.write(CollectedList(values))
+    }
+
+    /// Writes a list of the values produced by `values` and upon success returns another reference
+    /// to `self`. All of the values are collected before the list is written; if any of them is an
+    /// error, it is returned and nothing is written.
+    fn write_iter<I, V>(&mut self, values: I) -> IonResult<&mut Self>
+    where
+        I: IntoIterator<Item = IonResult<V>>,
+        V: WriteAsIon,
+    {
+        let values = values.into_iter().collect::<IonResult<Vec<V>>>()?;
+        self.write(CollectedList(values))
+    }
+
+    /// Writes a struct containing the name/value pairs produced by `fields` and upon success
+    /// returns another reference to `self`. All of the pairs are collected before the struct is
+    /// written; if any of them is an error, it is returned and nothing is written.
+    fn write_struct_try<I, A, V>(&mut self, fields: I) -> IonResult<&mut Self>
+    where
+        I: IntoIterator<Item = IonResult<(A, V)>>,
+        A: AsRawSymbolTokenRef,
+        V: WriteAsIon,
+    {
+        let fields = fields.into_iter().collect::<IonResult<Vec<(A, V)>>>()?;
+        self.write(CollectedFields(fields))
+    }
+
+    /// Writes each of the provided values in the current context, in order, and upon success
+    /// returns another reference to `self`. If writing a value fails, the error is returned
+    /// immediately; the values that preceded it have already been written.
+    ///
+    /// Unlike [`write_iter`](Self::write_iter), this does not wrap the values in a list.
+    fn write_all<I, V>(&mut self, values: I) -> IonResult<&mut Self>
+    where
+        I: IntoIterator<Item = V>,
+        V: WriteAsIon,
+    {
+        for value in values {
+            self.write(value)?;
+        }
+        Ok(self)
+    }
+
+    /// Writes each of the provided integers in the current context and upon success returns
+    /// another reference to `self`. Writers may override this to encode the values more
+    /// efficiently than writing them one at a time.
+    fn write_i64_slice(&mut self, values: &[i64]) -> IonResult<&mut Self> {
+        for value in values {
+            self.write_i64(*value)?;
+        }
+        Ok(self)
+    }
+
     // XXX: For now, it's not possible to offer versions of `write_list`, `write_sexp`, or
     //      `write_struct`. This is due to a point-in-time limitation in the borrow checker[1].
     //      It is still possible to call (e.g.)
@@ -169,3 +558,142 @@ pub trait SequenceWriter: MakeValueWriter {
     //      as a workaround.
     // [1]: https://blog.rust-lang.org/2022/10/28/gats-stabilization.html#implied-static-requirement-from-higher-ranked-trait-bounds
 }
+
+/// A [`ValueWriter`] that does not encode anything. Instead, it records the Ion type of the value
+/// that it is asked to write. The closures passed to its container methods are not called.
+struct IonTypeProbe<'a> {
+    ion_type: &'a mut Option<IonType>,
+}
+
+impl<'a> IonTypeProbe<'a> {
+    /// Returns the Ion type of the value that `value` writes.
+    fn ion_type_of<V: WriteAsIonValue>(value: &V) -> IonResult<IonType> {
+        let mut ion_type = None;
+        value.write_as_ion_value(IonTypeProbe {
+            ion_type: &mut ion_type,
+        })?;
+        ion_type.ok_or_else(|| IonError::illegal_operation("no value was written"))
+    }
+
+    fn record(self, ion_type: IonType) -> IonResult<()> {
+        *self.ion_type = Some(ion_type);
+        Ok(())
+    }
+}
+
+impl<'a> ValueWriter for IonTypeProbe<'a> {
+    type ListWriter<'b> = IonTypeProbe<'a>;
+    type SExpWriter<'b> = IonTypeProbe<'a>;
+    type StructWriter<'b> = IonTypeProbe<'a>;
+
+    fn write_null(self, ion_type: IonType) -> IonResult<()> {
+        self.record(ion_type)
+    }
+
+    fn write_bool(self, _value: bool) -> IonResult<()> {
+        self.record(IonType::Bool)
+    }
+
+    fn write_i64(self, _value: i64) -> IonResult<()> {
+        self.record(IonType::Int)
+    }
+
+    fn write_int(self, _value: &Int) -> IonResult<()> {
+        self.record(IonType::Int)
+    }
+
+    fn write_f32(self, _value: f32) -> IonResult<()> {
+        self.record(IonType::Float)
+    }
+
+    fn write_f64(self, _value: f64) -> IonResult<()> {
+        self.record(IonType::Float)
+    }
+
+    fn write_decimal(self, _value: &Decimal) -> IonResult<()> {
+        self.record(IonType::Decimal)
+    }
+
+    fn write_timestamp(self, _value: &Timestamp) -> IonResult<()> {
+        self.record(IonType::Timestamp)
+    }
+
+    fn write_string(self, _value: impl AsRef<str>) -> IonResult<()> {
+        self.record(IonType::String)
+    }
+
+    fn write_symbol(self, _value: impl AsRawSymbolTokenRef) -> IonResult<()> {
+        self.record(IonType::Symbol)
+    }
+
+    fn write_clob(self, _value: impl AsRef<[u8]>) -> IonResult<()> {
+        self.record(IonType::Clob)
+    }
+
+    fn write_blob(self, _value: impl AsRef<[u8]>) -> IonResult<()> {
+        self.record(IonType::Blob)
+    }
+
+    fn write_list<F: for<'b> FnOnce(&mut Self::ListWriter<'b>) -> IonResult<()>>(
+        self,
+        _list_fn: F,
+    ) -> IonResult<()> {
+        self.record(IonType::List)
+    }
+
+    fn write_sexp<F: for<'b> FnOnce(&mut Self::SExpWriter<'b>) -> IonResult<()>>(
+        self,
+        _sexp_fn: F,
+    ) -> IonResult<()> {
+        self.record(IonType::SExp)
+    }
+
+    fn write_struct<F: for<'b> FnOnce(&mut Self::StructWriter<'b>) -> IonResult<()>>(
+        self,
+        _struct_fn: F,
+    ) -> IonResult<()> {
+        self.record(IonType::Struct)
+    }
+}
+
+impl<'a> AnnotatableValueWriter for IonTypeProbe<'a> {
+    type ValueWriter = Self;
+    type AnnotatedValueWriter<'b, SymbolType: AsRawSymbolTokenRef + 'b> = Self where Self: 'b;
+
+    fn with_annotations<'b, SymbolType: AsRawSymbolTokenRef>(
+        self,
+        _annotations: &'b [SymbolType],
+    ) -> Self::AnnotatedValueWriter<'b, SymbolType>
+    where
+        Self: 'b,
+    {
+        self
+    }
+
+    fn without_annotations(self) -> Self::ValueWriter {
+        self
+    }
+}
+
+impl<'a> MakeValueWriter for IonTypeProbe<'a> {
+    type ValueWriter<'b> = IonTypeProbe<'b> where Self: 'b;
+
+    fn value_writer(&mut self) -> Self::ValueWriter<'_> {
+        IonTypeProbe {
+            ion_type: self.ion_type,
+        }
+    }
+}
+
+impl<'a> SequenceWriter for IonTypeProbe<'a> {}
+
+impl<'a> StructWriter for IonTypeProbe<'a> {
+    fn write<A: AsRawSymbolTokenRef, V: WriteAsIon>(
+        &mut self,
+        _name: A,
+        value: V,
+    ) -> IonResult<&mut Self> {
+        value.write_as_ion(self.value_writer())?;
+        Ok(self)
+    }
+}
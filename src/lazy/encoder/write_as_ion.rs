@@ -16,9 +16,11 @@
 //! provided by the [`Annotate`](crate::lazy::encoder::annotate::Annotate) trait.
 use std::marker::PhantomData;
 
-use crate::lazy::encoder::value_writer::{AnnotatableValueWriter, SequenceWriter, ValueWriter};
+use crate::lazy::encoder::value_writer::{
+    AnnotatableValueWriter, SequenceWriter, StructWriter, ValueWriter,
+};
 use crate::{
-    Blob, Clob, Decimal, Int, IonResult, Null, RawSymbolToken, RawSymbolTokenRef, Symbol,
+    Blob, Clob, Decimal, Int, IonResult, IonType, Null, RawSymbolToken, RawSymbolTokenRef, Symbol,
     SymbolRef, Timestamp,
 };
 
@@ -205,3 +207,55 @@ macro_rules! impl_write_as_ion_value_for_sexp_type_hint {
 impl_write_as_ion_value_for_sexp_type_hint!(Vec<T>, T);
 impl_write_as_ion_value_for_sexp_type_hint!(&[T], T);
 impl_write_as_ion_value_for_sexp_type_hint!([T; N], T, const N: usize);
+
+/// An Ion value whose type is determined at runtime.
+///
+/// Most Rust types map to a single Ion type, which allows the writer to be selected at compile
+/// time. `DynValue` is an escape hatch for code that builds values from runtime-typed data (for
+/// example, a dynamic serializer); each variant is written using the corresponding
+/// [`ValueWriter`] method. Containers may hold values of any mix of types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynValue {
+    /// A null of the given type; `IonType::Null` writes an untyped `null`.
+    Null(IonType),
+    Bool(bool),
+    Int(Int),
+    Float(f64),
+    Decimal(Decimal),
+    Timestamp(Timestamp),
+    String(String),
+    Symbol(RawSymbolToken),
+    Blob(Vec<u8>),
+    Clob(Vec<u8>),
+    List(Vec<DynValue>),
+    Struct(Vec<(RawSymbolToken, DynValue)>),
+}
+
+impl WriteAsIonValue for DynValue {
+    fn write_as_ion_value<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        match self {
+            DynValue::Null(ion_type) => writer.write_null(*ion_type),
+            DynValue::Bool(value) => writer.write_bool(*value),
+            DynValue::Int(value) => writer.write_int(value),
+            DynValue::Float(value) => writer.write_f64(*value),
+            DynValue::Decimal(value) => writer.write_decimal(value),
+            DynValue::Timestamp(value) => writer.write_timestamp(value),
+            DynValue::String(value) => writer.write_string(value),
+            DynValue::Symbol(value) => writer.write_symbol(value),
+            DynValue::Blob(value) => writer.write_blob(value),
+            DynValue::Clob(value) => writer.write_clob(value),
+            DynValue::List(values) => writer.write_list(|list| {
+                for value in values {
+                    list.write(value)?;
+                }
+                Ok(())
+            }),
+            DynValue::Struct(fields) => writer.write_struct(|struct_| {
+                for (name, value) in fields {
+                    struct_.write(name, value)?;
+                }
+                Ok(())
+            }),
+        }
+    }
+}
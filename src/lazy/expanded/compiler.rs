@@ -619,6 +619,9 @@ mod tests {
                 macro_table: &self.macro_table,
                 symbol_table: &self.symbol_table,
                 allocator: &self.allocator,
+                normalize_timestamps_to_utc: false,
+                max_annotations_per_value: None,
+                reject_duplicate_fields: false,
             }
         }
     }
@@ -35,14 +35,16 @@
 use std::cell::{Cell, UnsafeCell};
 use std::fmt::{Debug, Formatter};
 use std::iter::empty;
+use std::ops::Range;
 
 use bumpalo::Bump as BumpAllocator;
 
 use sequence::{LazyExpandedList, LazyExpandedSExp};
 
 use crate::element::iterators::SymbolsIterator;
+use crate::lazy::any_encoding::{AnyEncoding, IonEncoding};
 use crate::lazy::bytes_ref::BytesRef;
-use crate::lazy::decoder::{LazyDecoder, LazyRawReader, LazyRawValue};
+use crate::lazy::decoder::{AnnotationEncoding, LazyDecoder, LazyRawReader, LazyRawValue};
 use crate::lazy::encoding::RawValueLiteral;
 use crate::lazy::expanded::compiler::TemplateCompiler;
 use crate::lazy::expanded::macro_evaluator::{MacroEvaluator, RawEExpression};
@@ -54,7 +56,9 @@ use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::str_ref::StrRef;
-use crate::lazy::system_reader::{LazySystemReader, PendingLst};
+use crate::lazy::system_reader::{
+    LazySystemReader, PendingLst, RecoverableError, ResumeToken, SymbolTableStats,
+};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
@@ -88,6 +92,12 @@ pub struct EncodingContext<'top> {
     pub(crate) macro_table: &'top MacroTable,
     pub(crate) symbol_table: &'top SymbolTable,
     pub(crate) allocator: &'top BumpAllocator,
+    // Whether timestamps with a known offset should be converted to UTC when they are read.
+    pub(crate) normalize_timestamps_to_utc: bool,
+    // If set, iterating over a value's annotations raises an error after this many annotations.
+    pub(crate) max_annotations_per_value: Option<usize>,
+    // Whether iterating over a struct's fields raises an error upon finding a repeated field name.
+    pub(crate) reject_duplicate_fields: bool,
 }
 
 impl<'top> EncodingContext<'top> {
@@ -100,6 +110,9 @@ impl<'top> EncodingContext<'top> {
             macro_table,
             symbol_table,
             allocator,
+            normalize_timestamps_to_utc: false,
+            max_annotations_per_value: None,
+            reject_duplicate_fields: false,
         }
     }
 }
@@ -165,6 +178,9 @@ pub struct LazyExpandingReader<'data, D: LazyDecoder> {
     // in the field below, we cast away the pointer's type for the purposes of storage and then cast
     // it back at dereference time when a 'top lifetime is available.
     evaluator_ptr: Cell<Option<*mut ()>>,
+    // The first byte of the encoding of the top-level value most recently read from the input
+    // stream, if it was binary. See `LazyRawValue::type_descriptor`.
+    current_type_descriptor: Cell<Option<u8>>,
 
     // XXX: The `UnsafeCell` wrappers around the fields below are a workaround for
     //      a limitation in rustc's borrow checker that prevents mutable references from being
@@ -190,21 +206,175 @@ pub struct LazyExpandingReader<'data, D: LazyDecoder> {
     // TODO: Make the symbol and macro tables traits on `D` such that they can be configured
     //       statically. Then 1.0 types can use `Never` for the macro table.
     symbol_table: UnsafeCell<SymbolTable>,
+    // If fallback symbols have been configured, this holds `symbol_table` with any symbol IDs it
+    // does not define resolved using `fallback_symbols`. It is used in place of `symbol_table`
+    // when resolving symbols, while `symbol_table` continues to track the stream's own symbols.
+    resolved_symbol_table: UnsafeCell<Option<SymbolTable>>,
     macro_table: UnsafeCell<MacroTable>,
+    // If set, top-level values whose encoded body is longer than this many bytes will be rejected.
+    max_value_len: Option<usize>,
+    // If set, timestamps with a known offset are converted to UTC when they are read.
+    normalize_timestamps_to_utc: bool,
+    // If set, values with more than this many annotations will be rejected when their annotations
+    // are read.
+    max_annotations_per_value: Option<usize>,
+    // If set, iterating over a struct whose fields include a repeated name raises an error.
+    reject_duplicate_fields: bool,
+    // Symbol text that, in addition to `$ion_symbol_table`, signals a symbol table append when
+    // found in a symbol table's `imports` field.
+    append_sentinels: Vec<String>,
+    // Symbols to consult when the stream's own symbol table does not define a symbol ID.
+    fallback_symbols: Option<SymbolTable>,
+    // Statistics about the symbol tables that have been applied so far.
+    symbol_table_stats: Cell<SymbolTableStats>,
+    // If false, e-expressions are surfaced as `SystemStreamItem::EExpression` instead of being
+    // evaluated.
+    expand_macros: bool,
+    // If set, the reader will raise an error instead of yielding more than this many application
+    // values.
+    max_total_values: Option<usize>,
+    // The number of application values that the reader has yielded so far.
+    values_yielded: Cell<usize>,
+}
+
+/// Constructs the error raised when a method that only returns values or encoding context changes
+/// encounters an e-expression that the reader has been configured not to expand.
+pub(crate) fn unexpanded_e_expression_error<T>() -> IonResult<T> {
+    IonResult::illegal_operation(
+        "found an e-expression, but macro expansion is disabled; use `next_item` to read it",
+    )
+}
+
+impl<'data> LazyExpandingReader<'data, AnyEncoding> {
+    /// Returns the encoding that the raw reader identified. See
+    /// [`LazySystemReader::detected_encoding`].
+    pub(crate) fn detected_encoding(&self) -> Option<IonEncoding> {
+        // SAFETY: The raw reader is mutated by `next_item`, which only requires `&self`, so the
+        //         borrow checker cannot rule out an overlap. Here the shared reference is dropped
+        //         before this method returns, and `detected_encoding` never calls back into
+        //         `next_item`. Because `UnsafeCell` is not `Sync`, no other thread can be
+        //         advancing the reader in the meantime.
+        unsafe { &*self.raw_reader.get() }.detected_encoding()
+    }
 }
 
 impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
     pub(crate) fn new(raw_reader: D::Reader<'data>) -> Self {
+        let symbol_table = SymbolTable::new();
+        let symbol_table_stats = SymbolTableStats {
+            peak_len: symbol_table.len(),
+            ..Default::default()
+        };
         Self {
             raw_reader: raw_reader.into(),
             evaluator_ptr: None.into(),
+            current_type_descriptor: None.into(),
             allocator: BumpAllocator::new().into(),
             pending_lst: PendingLst::new().into(),
-            symbol_table: SymbolTable::new().into(),
+            symbol_table: symbol_table.into(),
+            resolved_symbol_table: None.into(),
             macro_table: MacroTable::new().into(),
+            max_value_len: None,
+            normalize_timestamps_to_utc: false,
+            max_annotations_per_value: None,
+            reject_duplicate_fields: false,
+            append_sentinels: Vec::new(),
+            fallback_symbols: None,
+            symbol_table_stats: Cell::new(symbol_table_stats),
+            expand_macros: true,
+            max_total_values: None,
+            values_yielded: Cell::new(0),
         }
     }
 
+    /// Returns the first byte of the current top-level value's encoding. See
+    /// [`LazySystemReader::current_type_descriptor`].
+    pub(crate) fn current_type_descriptor(&self) -> Option<u8> {
+        self.current_type_descriptor.get()
+    }
+
+    /// Configures the reader to raise an error upon encountering a top-level value whose encoded
+    /// body is longer than `max_value_len` bytes. See [`LazySystemReader::max_value_len`].
+    pub(crate) fn set_max_value_len(&mut self, max_value_len: usize) {
+        self.max_value_len = Some(max_value_len);
+    }
+
+    /// Configures whether timestamps are converted to UTC when they are read. See
+    /// [`LazySystemReader::normalize_timestamps_to_utc`].
+    pub(crate) fn set_normalize_timestamps_to_utc(&mut self, normalize: bool) {
+        self.normalize_timestamps_to_utc = normalize;
+    }
+
+    /// Configures the reader to raise an error when iterating over the annotations of a value that
+    /// has more than `max_annotations` of them. See [`LazySystemReader::max_annotations_per_value`].
+    pub(crate) fn set_max_annotations_per_value(&mut self, max_annotations: usize) {
+        self.max_annotations_per_value = Some(max_annotations);
+    }
+
+    /// Configures whether iterating over a struct's fields raises an error upon finding a repeated
+    /// field name. See [`LazySystemReader::reject_duplicate_fields`].
+    pub(crate) fn set_reject_duplicate_fields(&mut self, reject: bool) {
+        self.reject_duplicate_fields = reject;
+    }
+
+    /// Begins reading `ion_data` as a new stream, reusing the reader's allocations. See
+    /// [`LazySystemReader::reset`].
+    pub(crate) fn reset(&mut self, ion_data: &'data [u8]) {
+        *self.raw_reader.get_mut() = D::Reader::new(ion_data);
+        // The evaluator lives in the allocator, which is about to be cleared.
+        self.evaluator_ptr.set(None);
+        self.allocator.get_mut().reset();
+        self.current_type_descriptor.set(None);
+        let pending_lst = self.pending_lst.get_mut();
+        pending_lst.has_changes = false;
+        pending_lst.is_lst_append = false;
+        pending_lst.symbols.clear();
+        let symbol_table = self.symbol_table.get_mut();
+        symbol_table.reset();
+        if let Some(fallback_symbols) = &self.fallback_symbols {
+            *self.resolved_symbol_table.get_mut() =
+                Some(symbol_table.with_fallback(fallback_symbols));
+        }
+        self.symbol_table_stats.set(SymbolTableStats {
+            peak_len: symbol_table.len(),
+            ..Default::default()
+        });
+        self.values_yielded.set(0);
+    }
+
+    /// Returns statistics about the symbol tables that have been applied so far. See
+    /// [`LazySystemReader::symbol_table_stats`].
+    pub(crate) fn symbol_table_stats(&self) -> SymbolTableStats {
+        self.symbol_table_stats.get()
+    }
+
+    /// Configures whether e-expressions are evaluated or surfaced as they were found in the input.
+    /// See [`LazySystemReader::expand_macros`].
+    pub(crate) fn set_expand_macros(&mut self, expand_macros: bool) {
+        self.expand_macros = expand_macros;
+    }
+
+    /// Configures the reader to raise an error instead of yielding more than `max_total_values`
+    /// application values. See [`LazySystemReader::max_total_values`].
+    pub(crate) fn set_max_total_values(&mut self, max_total_values: usize) {
+        self.max_total_values = Some(max_total_values);
+    }
+
+    /// Configures the reader to treat `text` as a signal to append to the current symbol table when
+    /// it is found in a symbol table's `imports` field. See
+    /// [`LazySystemReader::add_append_sentinel`].
+    pub(crate) fn add_append_sentinel(&mut self, text: &str) {
+        self.append_sentinels.push(text.to_owned());
+    }
+
+    /// Configures the reader to resolve symbol IDs that the stream's symbol table does not define
+    /// using `fallback_symbols`. See [`LazySystemReader::with_fallback_symbols`].
+    pub(crate) fn set_fallback_symbols(&mut self, fallback_symbols: SymbolTable) {
+        let resolved = self.symbol_table.get_mut().with_fallback(&fallback_symbols);
+        *self.resolved_symbol_table.get_mut() = Some(resolved);
+        self.fallback_symbols = Some(fallback_symbols);
+    }
+
     // TODO: This method is temporary. It will be removed when the ability to read 1.1 encoding
     //       directives from the input stream is available. Until then, template creation is manual.
     pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
@@ -215,18 +385,53 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
         macro_table.add_macro(template_macro)
     }
 
+    /// Returns the symbol table changes that will be applied the next time the reader advances.
+    ///
+    /// Callers must not call [`Self::next_item`] while the returned reference is alive.
+    pub(crate) fn pending_lst(&self) -> &PendingLst {
+        // SAFETY: `next_item` takes `&self` but modifies the pending LST, so the borrow checker
+        //         does not prevent it from running while this reference is alive. The only
+        //         callers are in `LazySystemReader` (`next_event` and `SymbolTablesIterator`),
+        //         which read the pending LST right after `next_item` returns a symbol table and
+        //         drop the reference before advancing again.
+        unsafe { &*self.pending_lst.get() }
+    }
+
+    /// Returns the symbol table that is currently in effect. Changes from a symbol table that the
+    /// reader has just visited are not applied until the reader advances.
+    ///
+    /// Callers must not call [`Self::next_item`] while the returned reference is alive.
+    pub(crate) fn symbol_table(&self) -> &SymbolTable {
+        // SAFETY: The symbol table is only modified in `between_top_level_expressions`, which
+        //         `next_item` calls through a `&self` receiver, so the borrow checker cannot
+        //         enforce the requirement above. `LazySystemReader::symbol_table` hands this
+        //         reference to users through a shared borrow of the system reader, and every
+        //         `LazySystemReader` method that advances takes `&mut self`. The crate's other
+        //         callers (`interpret_value` and `SymbolTablesIterator`) use the reference after
+        //         the symbol table has been updated and drop it before advancing again.
+        unsafe { &*self.symbol_table.get() }
+    }
+
     fn context(&self) -> EncodingContext<'_> {
         // SAFETY: The only time that the macro table, symbol table, and allocator can be modified
         // is in the body of the method `between_top_level_expressions`. As long as nothing holds
         // a reference to the `EncodingContext` we create here when that method is running,
         // this is safe.
-        unsafe {
+        let mut context = unsafe {
+            let symbol_table = match &*self.resolved_symbol_table.get() {
+                Some(resolved_symbol_table) => resolved_symbol_table,
+                None => &*self.symbol_table.get(),
+            };
             EncodingContext::new(
                 &*self.macro_table.get(),
-                &*self.symbol_table.get(),
+                symbol_table,
                 &*self.allocator.get(),
             )
-        }
+        };
+        context.normalize_timestamps_to_utc = self.normalize_timestamps_to_utc;
+        context.max_annotations_per_value = self.max_annotations_per_value;
+        context.reject_duplicate_fields = self.reject_duplicate_fields;
+        context
     }
 
     fn ptr_to_mut_ref<'a, T>(ptr: *mut ()) -> &'a mut T {
@@ -252,22 +457,47 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
 
     /// Updates the encoding context with the information stored in the `PendingLst`.
     // TODO: This only works on Ion 1.0 symbol tables for now, hence the name `PendingLst`
-    fn apply_pending_lst(pending_lst: &mut PendingLst, symbol_table: &mut SymbolTable) {
+    fn apply_pending_lst(
+        pending_lst: &mut PendingLst,
+        symbol_table: &mut SymbolTable,
+        stats: &mut SymbolTableStats,
+    ) {
         // If the symbol table's `imports` field had a value of `$ion_symbol_table`, then we're
         // appending the symbols it defined to the end of our existing local symbol table.
         // Otherwise, we need to clear the existing table before appending the new symbols.
-        if !pending_lst.is_lst_append {
+        if pending_lst.is_lst_append {
+            stats.appends += 1;
+        } else {
             // We're setting the symbols list, not appending to it.
             symbol_table.reset();
+            stats.resets += 1;
         }
+        stats.symbols_interned += pending_lst.symbols.len();
         // `drain()` empties the pending symbols list
         for symbol in pending_lst.symbols.drain(..) {
             symbol_table.intern_or_add_placeholder(symbol);
         }
+        stats.peak_len = stats.peak_len.max(symbol_table.len());
         pending_lst.is_lst_append = false;
         pending_lst.has_changes = false;
     }
 
+    /// Returns a decoding error if the provided value's encoded body is longer than the
+    /// configured maximum value length.
+    fn check_value_len(&self, raw_value: &D::Value<'_>) -> IonResult<()> {
+        match self.max_value_len {
+            Some(max_value_len) if raw_value.value_length() > max_value_len => {
+                IonResult::decoding_error(format!(
+                    "found a {} value with an encoded length of {} bytes, which exceeds the configured maximum ({} bytes)",
+                    raw_value.ion_type(),
+                    raw_value.value_length(),
+                    max_value_len
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Inspects a `LazyExpandedValue` to determine whether it is a symbol table or an
     /// application-level value. Returns it as the appropriate variant of `SystemStreamItem`.
     fn interpret_value<'top>(
@@ -278,7 +508,12 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
         if LazySystemReader::is_symbol_table_struct(&value)? {
             // ...traverse it and record any new symbols in our `pending_lst`.
             let pending_lst = unsafe { &mut *self.pending_lst.get() };
-            LazySystemReader::process_symbol_table(pending_lst, &value)?;
+            LazySystemReader::process_symbol_table(
+                pending_lst,
+                &value,
+                &self.append_sentinels,
+                self.symbol_table(),
+            )?;
             pending_lst.has_changes = true;
             let lazy_struct = LazyStruct {
                 expanded_struct: value.read()?.expect_struct().unwrap(),
@@ -311,14 +546,22 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
         let pending_lst: &mut PendingLst = unsafe { &mut *self.pending_lst.get() };
         if pending_lst.has_changes {
             let symbol_table: &mut SymbolTable = unsafe { &mut *self.symbol_table.get() };
-            Self::apply_pending_lst(pending_lst, symbol_table);
+            let mut stats = self.symbol_table_stats.get();
+            Self::apply_pending_lst(pending_lst, symbol_table, &mut stats);
+            self.symbol_table_stats.set(stats);
+            if let Some(fallback_symbols) = &self.fallback_symbols {
+                let resolved_symbol_table = unsafe { &mut *self.resolved_symbol_table.get() };
+                *resolved_symbol_table = Some(symbol_table.with_fallback(fallback_symbols));
+            }
         }
     }
 
     /// Returns the next application-level value.
     ///
     /// This method will consume and process as many system-level values as possible until it
-    /// encounters an application-level value or the end of the stream.
+    /// encounters an application-level value or the end of the stream. If macro expansion has
+    /// been disabled and the reader encounters an e-expression, returns an illegal operation
+    /// error; use [`Self::next_item`] to read unexpanded e-expressions.
     pub fn next_value(&mut self) -> IonResult<Option<LazyValue<D>>> {
         loop {
             match self.next_item()? {
@@ -330,6 +573,7 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
                     // nothing to be done here.
                 }
                 SystemStreamItem::Value(value) => return Ok(Some(value)),
+                SystemStreamItem::EExpression(_) => return unexpanded_e_expression_error(),
                 SystemStreamItem::EndOfStream => return Ok(None),
             }
         }
@@ -341,6 +585,37 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
     where
         'data: 'top,
     {
+        // Once the limit has been exceeded, the reader refuses to go any further.
+        self.check_values_yielded()?;
+        let item = self.next_item_unchecked()?;
+        if let SystemStreamItem::Value(_) = item {
+            self.values_yielded.set(self.values_yielded.get() + 1);
+            self.check_values_yielded()?;
+        }
+        Ok(item)
+    }
+
+    /// Raises an error if the reader has yielded more application values than the configured
+    /// maximum.
+    fn check_values_yielded(&self) -> IonResult<()> {
+        match self.max_total_values {
+            Some(max_total_values) if self.values_yielded.get() > max_total_values => {
+                IonResult::decoding_error(format!(
+                    "stream contains more than the maximum of {max_total_values} values"
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Like [`Self::next_item`], but does not enforce the reader's `max_total_values` limit.
+    fn next_item_unchecked<'top>(&'top self) -> IonResult<SystemStreamItem<'top, D>>
+    where
+        'data: 'top,
+    {
+        // Values produced by macro evaluation are not read directly from the input, so they have no
+        // type descriptor. If the next item is a value literal, this will be set below.
+        self.current_type_descriptor.set(None);
         // If there's already an active macro evaluator, that means the reader is still in the process
         // of expanding a macro invocation it previously encountered. See if it has a value to give us.
         if let Some(stream_item) = self.next_from_evaluator()? {
@@ -366,12 +641,19 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
                 }
                 // We got our value; return it.
                 Value(raw_value) => {
+                    self.current_type_descriptor
+                        .set(raw_value.type_descriptor());
+                    self.check_value_len(&raw_value)?;
                     let value = LazyExpandedValue {
                         source: ExpandedValueSource::ValueLiteral(raw_value),
                         context: self.context(),
                     };
                     return self.interpret_value(value);
                 }
+                // It's a macro invocation, but the reader has been configured not to expand it.
+                EExpression(e_exp) if !self.expand_macros => {
+                    return Ok(SystemStreamItem::EExpression(e_exp))
+                }
                 // It's another macro invocation, we'll start evaluating it.
                 EExpression(e_exp) => {
                     let context = self.context();
@@ -406,6 +688,33 @@ impl<'data, D: LazyDecoder> LazyExpandingReader<'data, D> {
         }
     }
 
+    /// Like [`Self::next_item`], but if reading the next item fails the reader will skip ahead to
+    /// the next plausible top-level boundary so that reading can continue. See
+    /// [`LazySystemReader::next_item_recoverable`] for details.
+    pub fn next_item_recoverable<'top>(
+        &'top self,
+    ) -> Result<SystemStreamItem<'top, D>, RecoverableError>
+    where
+        'data: 'top,
+    {
+        let error_offset = unsafe { &*self.raw_reader.get() }.position();
+        let error = match self.next_item() {
+            Ok(item) => return Ok(item),
+            Err(error) => error,
+        };
+        // SAFETY: Reading failed, so no values from the current top-level expression have been
+        //         returned to the caller. Nothing holds a reference to the raw reader, the
+        //         evaluator, or the pending LST.
+        self.evaluator_ptr.set(None);
+        unsafe { *self.pending_lst.get() = PendingLst::new() };
+        let raw_reader = unsafe { &mut *self.raw_reader.get() };
+        let resume_offset = raw_reader.resynchronize(error_offset);
+        Err(RecoverableError::new(
+            error,
+            ResumeToken::new(error_offset, resume_offset),
+        ))
+    }
+
     /// If there is not an evaluation in process, returns `Ok(None)`.
     /// If there is an evaluation in process but it does not yield another value, returns `Ok(None)`.
     /// If there is an evaluation in process and it yields another value, returns `Ok(Some(value))`.
@@ -545,6 +854,33 @@ impl<'top, D: LazyDecoder> LazyExpandedValue<'top, D> {
         }
     }
 
+    /// Returns `Ok(true)` if this value's annotations have exactly the provided text, or
+    /// `Ok(false)` if not. Annotations encoded as symbol IDs are resolved using the active symbol
+    /// table. Comparison stops at the first annotation that does not match.
+    pub fn annotations_are<A: AsRef<str>, I: IntoIterator<Item = A>>(
+        &self,
+        expected: I,
+    ) -> IonResult<bool> {
+        let symbol_table = self.context.symbol_table;
+        let mut actual = self.annotations();
+        for expected_text in expected {
+            let expected_text = expected_text.as_ref();
+            let matches = match actual.next() {
+                None => false,
+                Some(Err(e)) => return Err(e),
+                Some(Ok(RawSymbolTokenRef::Text(text))) => text.as_ref() == expected_text,
+                Some(Ok(RawSymbolTokenRef::SymbolId(sid))) => {
+                    symbol_table.text_for(sid) == Some(expected_text)
+                }
+            };
+            if !matches {
+                return Ok(false);
+            }
+        }
+        // All of the expected annotations were found; make sure there are no more.
+        Ok(actual.next().is_none())
+    }
+
     pub fn read(&self) -> IonResult<ExpandedValueRef<'top, D>> {
         use ExpandedValueSource::*;
         match &self.source {
@@ -558,6 +894,26 @@ impl<'top, D: LazyDecoder> LazyExpandedValue<'top, D> {
         }
     }
 
+    /// Returns the `[start, end)` offsets of this value's encoding in the input stream, if it
+    /// has one. Values produced by a template or constructed during macro evaluation do not
+    /// correspond to a span of the input and return `None`.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match &self.source {
+            ExpandedValueSource::ValueLiteral(value) => Some(value.range()),
+            _ => None,
+        }
+    }
+
+    /// Returns how this value's annotations were encoded in the input stream. Values produced by
+    /// a template or constructed during macro evaluation return
+    /// [`AnnotationEncoding::Synthesized`].
+    pub fn annotation_encoding(&self) -> AnnotationEncoding {
+        match &self.source {
+            ExpandedValueSource::ValueLiteral(value) => value.annotation_encoding(),
+            _ => AnnotationEncoding::Synthesized,
+        }
+    }
+
     pub fn context(&self) -> EncodingContext<'top> {
         self.context
     }
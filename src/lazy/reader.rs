@@ -72,6 +72,38 @@ pub(crate) enum NextApplicationValue<'top, D: LazyDecoder> {
 }
 
 impl<'data, D: LazyDecoder> LazyApplicationReader<'data, D> {
+    /// Configures the reader to return a decoding error when it encounters a top-level value whose
+    /// encoded body is longer than `max_value_len` bytes. See
+    /// [`LazySystemReader::max_value_len`] for details.
+    pub fn max_value_len(mut self, max_value_len: usize) -> Self {
+        self.system_reader = self.system_reader.max_value_len(max_value_len);
+        self
+    }
+
+    /// Configures the reader to return a decoding error when iterating over the annotations of a
+    /// value that has more than `max_annotations` of them. See
+    /// [`LazySystemReader::max_annotations_per_value`] for details.
+    pub fn max_annotations_per_value(mut self, max_annotations: usize) -> Self {
+        self.system_reader = self
+            .system_reader
+            .max_annotations_per_value(max_annotations);
+        self
+    }
+
+    /// Configures whether iterating over a struct's fields returns a decoding error upon finding a
+    /// repeated field name. See [`LazySystemReader::reject_duplicate_fields`] for details.
+    pub fn reject_duplicate_fields(mut self, reject: bool) -> Self {
+        self.system_reader = self.system_reader.reject_duplicate_fields(reject);
+        self
+    }
+
+    /// Configures whether [`LazyValue::read`] converts timestamps to UTC. See
+    /// [`LazySystemReader::normalize_timestamps_to_utc`] for details.
+    pub fn normalize_timestamps_to_utc(mut self, normalize: bool) -> Self {
+        self.system_reader = self.system_reader.normalize_timestamps_to_utc(normalize);
+        self
+    }
+
     /// Returns the next top-level value in the input stream as `Ok(Some(lazy_value))`.
     /// If there are no more top-level values in the stream, returns `Ok(None)`.
     /// If the next value is incomplete (that is: only part of it is in the input buffer) or if the
@@ -170,9 +202,11 @@ impl<'data, D: LazyDecoder> ElementReader for LazyApplicationReader<'data, D> {
 mod tests {
     use crate::element::writer::ElementWriter;
     use crate::element::Element;
+    use crate::ion_data::IonEq;
     use crate::lazy::value_ref::ValueRef;
     use crate::{
         ion_list, ion_sexp, ion_struct, BinaryWriterBuilder, Int, IonResult, IonType, IonWriter,
+        Timestamp,
     };
 
     use super::*;
@@ -268,4 +302,104 @@ mod tests {
         assert_eq!(reader.read_next_element()?, None);
         Ok(())
     }
+
+    #[test]
+    fn max_value_len() -> IonResult<()> {
+        let data = &to_binary_ion(r#""hello" "hello, world!" [1, 2, 3]"#)?;
+        let mut reader = LazyBinaryReader::new(data)?.max_value_len(6);
+        assert_eq!(
+            reader.expect_next()?.read()?.expect_string()?.text(),
+            "hello"
+        );
+        assert!(matches!(reader.next(), Err(IonError::Decoding(_))));
+
+        let mut reader = LazyReader::new(br#"[1, 2, 3] "hello, world!""#).max_value_len(9);
+        assert_eq!(reader.expect_next()?.ion_type(), IonType::List);
+        assert!(matches!(reader.next(), Err(IonError::Decoding(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn max_annotations_per_value() -> IonResult<()> {
+        let text = "a::b::1 a::b::c::2 [a::b::c::3]";
+        let binary = to_binary_ion(text)?;
+        for data in [text.as_bytes(), binary.as_slice()] {
+            let mut reader = LazyReader::new(data).max_annotations_per_value(2);
+            let value = reader.expect_next()?;
+            assert!(value.annotations().are(["a", "b"])?);
+            // The value can be advanced past without reading its annotations...
+            let value = reader.expect_next()?;
+            assert_eq!(value.read()?.expect_i64()?, 2);
+            // ...but reading them raises an error once the limit is exceeded.
+            let annotations: Vec<_> = value.annotations().collect();
+            assert!(matches!(
+                annotations.as_slice(),
+                [Ok(_), Ok(_), Err(IonError::Decoding(_))]
+            ));
+            // The limit also applies to nested values.
+            let value = reader.expect_next()?;
+            assert!(matches!(
+                Element::try_from(value),
+                Err(IonError::Decoding(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn reject_duplicate_fields() -> IonResult<()> {
+        // `$7` is the symbol ID of `symbols`.
+        let text = "{a: 1, b: 2} {a: 1, b: 2, a: 3} {symbols: 1, $7: 2} [{c: 1, c: 2}]";
+        let binary = to_binary_ion(text)?;
+        for data in [text.as_bytes(), binary.as_slice()] {
+            let mut reader = LazyReader::new(data).reject_duplicate_fields(true);
+            let unique = reader.expect_next()?.read()?.expect_struct()?;
+            assert_eq!(unique.iter().count(), 2);
+            let repeated = reader.expect_next()?.read()?.expect_struct()?;
+            let fields: Vec<_> = repeated.iter().collect();
+            assert!(matches!(
+                fields.as_slice(),
+                [Ok(_), Ok(_), Err(IonError::Decoding(_))]
+            ));
+            assert!(Element::try_from(reader.expect_next()?).is_err());
+            assert!(Element::try_from(reader.expect_next()?).is_err());
+        }
+        // Duplicates are allowed by default.
+        let mut reader = LazyReader::new(text.as_bytes());
+        let _ = reader.expect_next()?;
+        let repeated = reader.expect_next()?.read()?.expect_struct()?;
+        assert_eq!(repeated.iter().count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_timestamps_to_utc() -> IonResult<()> {
+        let ion_data = r#"
+            2023-11-09T22:30:15.250-05:00
+            [2023-11-10T01:00+01:00]
+            2023-11-09T22:30-00:00
+            2023-11-09T
+        "#;
+        let mut reader = LazyReader::new(ion_data.as_bytes()).normalize_timestamps_to_utc(true);
+        let expected = Element::read_all("2023-11-10T03:30:15.250Z [2023-11-10T00:00Z]")?;
+        for expected_element in &expected {
+            let actual: Element = reader.expect_next()?.try_into()?;
+            assert!(
+                actual.ion_eq(expected_element),
+                "{actual} != {expected_element}"
+            );
+        }
+        // Timestamps with an unknown offset are left as-is.
+        let unknown_offset = reader.expect_next()?.read()?.expect_timestamp()?;
+        assert_eq!(unknown_offset.offset(), None);
+        assert_eq!(unknown_offset.hour(), 22);
+        let date = reader.expect_next()?.read()?.expect_timestamp()?;
+        assert_eq!(date, Timestamp::with_ymd(2023, 11, 9).build()?);
+
+        // Normalization is disabled by default.
+        let mut reader = LazyReader::new(ion_data.as_bytes());
+        let timestamp = reader.expect_next()?.read()?.expect_timestamp()?;
+        assert_eq!(timestamp.offset(), Some(-300));
+        Ok(())
+    }
 }
@@ -62,6 +62,37 @@ impl<'top, D: LazyDecoder> LazyList<'top, D> {
         }
     }
 
+    /// Returns the number of values in this list.
+    ///
+    /// Counting the values requires visiting each of their headers, but the values' bodies are
+    /// skipped rather than decoded. In binary Ion, this means that only type descriptors and
+    /// lengths are read.
+    pub fn len(&self) -> IonResult<usize> {
+        self.iter().try_fold(0, |count, value| {
+            value?;
+            Ok(count + 1)
+        })
+    }
+
+    /// Returns an iterator over the values in this list that implements [`ExactSizeIterator`].
+    ///
+    /// The list's values are counted (see [`Self::len`]) before the iterator is returned, so
+    /// this visits each value's header twice. In exchange, operations like `collect()` can
+    /// allocate the destination collection's full size up front.
+    pub fn iter_counted(&self) -> IonResult<CountedIterator<ListIterator<'top, D>>> {
+        let remaining = self.len()?;
+        Ok(CountedIterator {
+            iter: self.iter(),
+            remaining,
+        })
+    }
+
+    /// Returns `true` if this list contains no values. Unlike [`Self::len`], this only
+    /// examines the list's first value (if any).
+    pub fn is_empty(&self) -> IonResult<bool> {
+        Ok(self.iter().next().transpose()?.is_none())
+    }
+
     /// Returns an iterator over the annotations on this value. If this value has no annotations,
     /// the resulting iterator will be empty.
     ///
@@ -91,10 +122,10 @@ impl<'top, D: LazyDecoder> LazyList<'top, D> {
     ///# }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_list.annotations(),
-            symbol_table: self.expanded_list.context.symbol_table,
-        }
+        AnnotationsIterator::new(
+            self.expanded_list.annotations(),
+            &self.expanded_list.context,
+        )
     }
 }
 
@@ -188,6 +219,37 @@ impl<'top, D: LazyDecoder> LazySExp<'top, D> {
         }
     }
 
+    /// Returns the number of values in this s-expression.
+    ///
+    /// Counting the values requires visiting each of their headers, but the values' bodies are
+    /// skipped rather than decoded. In binary Ion, this means that only type descriptors and
+    /// lengths are read.
+    pub fn len(&self) -> IonResult<usize> {
+        self.iter().try_fold(0, |count, value| {
+            value?;
+            Ok(count + 1)
+        })
+    }
+
+    /// Returns an iterator over the values in this s-expression that implements [`ExactSizeIterator`].
+    ///
+    /// The s-expression's values are counted (see [`Self::len`]) before the iterator is returned, so
+    /// this visits each value's header twice. In exchange, operations like `collect()` can
+    /// allocate the destination collection's full size up front.
+    pub fn iter_counted(&self) -> IonResult<CountedIterator<SExpIterator<'top, D>>> {
+        let remaining = self.len()?;
+        Ok(CountedIterator {
+            iter: self.iter(),
+            remaining,
+        })
+    }
+
+    /// Returns `true` if this s-expression contains no values. Unlike [`Self::len`], this only
+    /// examines the s-expression's first value (if any).
+    pub fn is_empty(&self) -> IonResult<bool> {
+        Ok(self.iter().next().transpose()?.is_none())
+    }
+
     /// Returns an iterator over the annotations on this value. If this value has no annotations,
     /// the resulting iterator will be empty.
     ///
@@ -217,10 +279,10 @@ impl<'top, D: LazyDecoder> LazySExp<'top, D> {
     ///# }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_sexp.annotations(),
-            symbol_table: self.expanded_sexp.context.symbol_table,
-        }
+        AnnotationsIterator::new(
+            self.expanded_sexp.annotations(),
+            &self.expanded_sexp.context,
+        )
     }
 }
 
@@ -276,6 +338,29 @@ impl<'top, D: LazyDecoder> Iterator for SExpIterator<'top, D> {
     }
 }
 
+/// An iterator over a sequence's values whose number of values was computed in advance.
+/// See [`LazyList::iter_counted`] and [`LazySExp::iter_counted`].
+pub struct CountedIterator<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I: Iterator> Iterator for CountedIterator<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for CountedIterator<I> {}
+
 #[cfg(test)]
 mod tests {
     use crate::element::Element;
@@ -293,6 +378,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn len_and_is_empty() -> IonResult<()> {
+        let binary_ion = to_binary_ion(r#"[1, "two", [3, 4], {five: 5}] [] (a b c) ()"#)?;
+        let mut reader = LazyBinaryReader::new(&binary_ion)?;
+        let list = reader.expect_next()?.read()?.expect_list()?;
+        assert_eq!(list.len()?, 4);
+        assert!(!list.is_empty()?);
+        let list = reader.expect_next()?.read()?.expect_list()?;
+        assert_eq!(list.len()?, 0);
+        assert!(list.is_empty()?);
+        let sexp = reader.expect_next()?.read()?.expect_sexp()?;
+        assert_eq!(sexp.len()?, 3);
+        assert!(!sexp.is_empty()?);
+        let sexp = reader.expect_next()?.read()?.expect_sexp()?;
+        assert_eq!(sexp.len()?, 0);
+        assert!(sexp.is_empty()?);
+        Ok(())
+    }
+
+    #[test]
+    fn iter_counted() -> IonResult<()> {
+        let binary_ion = to_binary_ion(r#"[1, "two", [3, 4], {five: 5}] (a b c)"#)?;
+        let mut reader = LazyBinaryReader::new(&binary_ion)?;
+        let list = reader.expect_next()?.read()?.expect_list()?;
+        let mut iter = list.iter_counted()?;
+        assert_eq!(iter.len(), 4);
+        iter.next().unwrap()?;
+        assert_eq!(iter.len(), 3);
+        let rest = iter.collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(rest.len(), 3);
+
+        let sexp = reader.expect_next()?.read()?.expect_sexp()?;
+        let symbols = sexp
+            .iter_counted()?
+            .map(|value| Ok(value?.read()?.expect_symbol()?.text().unwrap().to_owned()))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(symbols, vec!["a", "b", "c"]);
+        Ok(())
+    }
+
     #[test]
     fn try_into_element() -> IonResult<()> {
         let ion_text = "foo::baz::baz::[1, 2, 3]";
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 
@@ -11,8 +12,8 @@ use crate::lazy::value::{AnnotationsIterator, LazyValue};
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
 use crate::{
-    Annotations, Element, IntoAnnotatedElement, IonError, IonResult, RawSymbolTokenRef, Struct,
-    SymbolRef,
+    Annotations, Element, IntoAnnotatedElement, IonError, IonResult, IonType, RawSymbolTokenRef,
+    Struct, SymbolId, SymbolRef,
 };
 
 /// An as-of-yet unread binary Ion struct. `LazyStruct` is immutable; its fields and annotations
@@ -71,8 +72,14 @@ impl<'top, D: LazyDecoder> Debug for LazyStruct<'top, D> {
 impl<'top, D: LazyDecoder> LazyStruct<'top, D> {
     /// Returns an iterator over this struct's fields. See [`LazyField`].
     pub fn iter(&self) -> StructIterator<'top, D> {
+        let seen_field_names = self
+            .expanded_struct
+            .context
+            .reject_duplicate_fields
+            .then(BTreeSet::new);
         StructIterator {
             expanded_struct_iter: self.expanded_struct.iter(),
+            seen_field_names,
         }
     }
 
@@ -191,6 +198,113 @@ impl<'top, D: LazyDecoder> LazyStruct<'top, D> {
         })
     }
 
+    /// Returns an iterator over the values of every field with the specified name, in the order
+    /// in which they appear in the struct. Like [`LazyStruct::find`], this visits each of the
+    /// struct's fields.
+    ///
+    /// A field matches if its name is encoded as the text `name` or as any symbol ID that the
+    /// active symbol table maps to `name`.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    /// use ion_rs::lazy::reader::LazyBinaryReader;
+    ///
+    /// let ion_data = r#"{foo: 1, bar: 2, foo: 3, baz: 4, foo: 5}"#;
+    /// let ion_bytes = Element::read_one(ion_data)?.to_binary()?;
+    /// let mut reader = LazyBinaryReader::new(&ion_bytes)?;
+    ///
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    /// let mut foos = Vec::new();
+    /// for value in lazy_struct.get_all("foo") {
+    ///     foos.push(value?.read()?.expect_i64()?);
+    /// }
+    ///
+    /// assert_eq!(foos, vec![1, 3, 5]);
+    /// assert_eq!(lazy_struct.get_all("Ontario").count(), 0);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn get_all<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = IonResult<LazyValue<'top, D>>> + 'a {
+        self.iter().filter_map(move |field| {
+            let field = match field {
+                Ok(field) => field,
+                Err(e) => return Some(Err(e)),
+            };
+            match field.name() {
+                Ok(field_name) => (field_name.text() == Some(name)).then(|| Ok(field.value())),
+                Err(e) => Some(Err(e)),
+            }
+        })
+    }
+
+    /// Looks up several fields in a single pass over the struct. For each `(name, ion_type)` pair
+    /// in `spec`, the returned `Vec` holds the value of the first field with that name at the
+    /// same position, or `None` if the struct has no such field.
+    ///
+    /// Returns an [`IonError::Decoding`] if a requested field is present but its value is not of
+    /// the expected [`IonType`]. A typed null such as `null.int` has the type it names; an untyped
+    /// `null` has the type [`IonType::Null`].
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, IonType};
+    /// use ion_rs::lazy::reader::LazyBinaryReader;
+    ///
+    /// let ion_data = r#"{id: 7, name: "widget", tags: [a, b]}"#;
+    /// let ion_bytes = Element::read_one(ion_data)?.to_binary()?;
+    /// let mut reader = LazyBinaryReader::new(&ion_bytes)?;
+    ///
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    /// let fields = lazy_struct.extract(&[
+    ///     ("name", IonType::String),
+    ///     ("id", IonType::Int),
+    ///     ("price", IonType::Decimal),
+    /// ])?;
+    ///
+    /// assert_eq!(fields[0].as_ref().unwrap().read()?.expect_string()?, "widget");
+    /// assert_eq!(fields[1].as_ref().unwrap().read()?.expect_i64()?, 7);
+    /// assert!(fields[2].is_none());
+    ///
+    /// assert!(lazy_struct.extract(&[("id", IonType::String)]).is_err());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn extract(&self, spec: &[(&str, IonType)]) -> IonResult<Vec<Option<LazyValue<'top, D>>>> {
+        let mut values = vec![None; spec.len()];
+        let mut values_found = 0;
+        for field in self {
+            if values_found == spec.len() {
+                break;
+            }
+            let field = field?;
+            let name = field.name()?;
+            let Some(text) = name.text() else {
+                continue;
+            };
+            for ((expected_name, expected_type), slot) in spec.iter().zip(values.iter_mut()) {
+                if slot.is_some() || *expected_name != text {
+                    continue;
+                }
+                let value = field.value();
+                if value.ion_type() != *expected_type {
+                    return IonResult::decoding_error(format!(
+                        "expected field '{}' to be a(n) {}, but found a(n) {}",
+                        text,
+                        expected_type,
+                        value.ion_type()
+                    ));
+                }
+                *slot = Some(value);
+                values_found += 1;
+            }
+        }
+        Ok(values)
+    }
+
     /// Returns an iterator over the annotations on this value. If this value has no annotations,
     /// the resulting iterator will be empty.
     ///
@@ -221,10 +335,10 @@ impl<'top, D: LazyDecoder> LazyStruct<'top, D> {
     ///# }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_struct.annotations(),
-            symbol_table: self.expanded_struct.context.symbol_table,
-        }
+        AnnotationsIterator::new(
+            self.expanded_struct.annotations(),
+            &self.expanded_struct.context,
+        )
     }
 }
 
@@ -263,6 +377,18 @@ impl<'top, D: LazyDecoder> LazyField<'top, D> {
             })
     }
 
+    /// If this field's name was encoded as a symbol ID, returns that symbol ID without resolving
+    /// it. If the name was encoded as text (as is typical in text Ion), returns `None`.
+    ///
+    /// This is useful for re-encoding data faithfully without paying the cost of resolving the
+    /// field name.
+    pub fn raw_name_sid(&self) -> Option<SymbolId> {
+        match self.expanded_field.raw_name() {
+            RawSymbolTokenRef::SymbolId(sid) => Some(sid),
+            RawSymbolTokenRef::Text(_) => None,
+        }
+    }
+
     /// Returns a lazy value representing the value of this field. To access the value's data,
     /// see [`LazyValue::read`].
     pub fn value(&self) -> LazyValue<'top, D> {
@@ -274,6 +400,8 @@ impl<'top, D: LazyDecoder> LazyField<'top, D> {
 
 pub struct StructIterator<'top, D: LazyDecoder> {
     pub(crate) expanded_struct_iter: ExpandedStructIterator<'top, D>,
+    // If the reader rejects duplicate field names, the names of the fields visited so far.
+    seen_field_names: Option<BTreeSet<SymbolRef<'top>>>,
 }
 
 impl<'top, D: LazyDecoder> Iterator for StructIterator<'top, D> {
@@ -292,6 +420,17 @@ impl<'top, D: LazyDecoder> StructIterator<'top, D> {
         };
 
         let lazy_field = LazyField { expanded_field };
+        if let Some(seen_field_names) = &mut self.seen_field_names {
+            let name = lazy_field.name()?;
+            if let Some(text) = name.text() {
+                if seen_field_names.contains(&name) {
+                    return IonResult::decoding_error(format!(
+                        "found a struct with more than one field named '{text}'"
+                    ));
+                }
+                seen_field_names.insert(name.clone());
+            }
+        }
         Ok(Some(lazy_field))
     }
 }
@@ -331,7 +470,9 @@ impl<'a, 'top, 'data: 'top, D: LazyDecoder> IntoIterator for &'a LazyStruct<'top
 #[cfg(test)]
 mod tests {
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::lazy::reader::LazyBinaryReader;
+    use crate::lazy::reader::{LazyBinaryReader, LazyReader};
+    use crate::lazy::system_reader::LazySystemAnyReader;
+    use crate::SymbolTable;
 
     use super::*;
 
@@ -385,6 +526,83 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn get_all() -> IonResult<()> {
+        // The stream's symbol table leaves the text of `$11` unknown, and the fallback symbols
+        // define it as `foo`. This makes both `$10` and `$11` valid encodings of `foo`.
+        let ion_data = br#"
+            $ion_symbol_table::{symbols: ["foo", null]}
+            {$10: 1, bar: 2, $11: 3, foo: 4, $4: 5, 'foo': 6, name: 7}
+        "#;
+        let fallback = SymbolTable::from_symbols(&["bar", "foo"]);
+        let mut reader = LazySystemAnyReader::new(ion_data).with_fallback_symbols(fallback);
+        let struct_ = reader.next_value()?.unwrap().read()?.expect_struct()?;
+        let values_of = |name| {
+            struct_
+                .get_all(name)
+                .map(|value| value?.read()?.expect_i64())
+                .collect::<IonResult<Vec<_>>>()
+        };
+        assert_eq!(values_of("foo")?, vec![1, 3, 4, 6]);
+        assert_eq!(values_of("bar")?, vec![2]);
+        assert_eq!(values_of("name")?, vec![5, 7]);
+        assert_eq!(values_of("baz")?, vec![]);
+
+        let ion_data = to_binary_ion("{foo: 1, bar: 2, foo: 3}")?;
+        let mut reader = LazyBinaryReader::new(&ion_data)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        assert_eq!(struct_.get_all("foo").count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn extract() -> IonResult<()> {
+        let ion_data = to_binary_ion("{a: 1, b: null.string, a: true, c: [1, 2]}")?;
+        let mut reader = LazyBinaryReader::new(&ion_data)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let values = struct_.extract(&[
+            ("c", IonType::List),
+            ("missing", IonType::Int),
+            ("a", IonType::Int),
+            ("b", IonType::String),
+        ])?;
+        let values: Vec<Option<ValueRef<_>>> = values
+            .iter()
+            .map(|value| value.as_ref().map(|v| v.read()).transpose())
+            .collect::<IonResult<_>>()?;
+        assert!(matches!(values[0], Some(ValueRef::List(_))));
+        assert_eq!(values[1], None);
+        // Like `find`, the first field with a matching name is returned.
+        assert_eq!(values[2], Some(ValueRef::Int(1.into())));
+        assert_eq!(values[3], Some(ValueRef::Null(IonType::String)));
+
+        assert!(struct_.extract(&[("c", IonType::SExp)]).is_err());
+        assert!(struct_.extract(&[])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn raw_name_sid() -> IonResult<()> {
+        // `name` is $4 in the system symbol table; `foo` is the first local symbol, $10.
+        let ion_data = to_binary_ion("{name: 1, foo: 2}")?;
+        let mut reader = LazyBinaryReader::new(&ion_data)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let sids = struct_
+            .iter()
+            .map(|field| field.map(|f| f.raw_name_sid()))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(sids, vec![Some(4), Some(10)]);
+
+        let mut reader = LazyReader::new("{name: 1, $4: 2}".as_bytes());
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let sids = struct_
+            .iter()
+            .map(|field| field.map(|f| f.raw_name_sid()))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(sids, vec![None, Some(4)]);
+        Ok(())
+    }
+
     #[test]
     fn annotations() -> IonResult<()> {
         let ion_data = to_binary_ion("a::b::c::{foo: 1, bar: 2, baz: quux::quuz::3}")?;
@@ -1,16 +1,19 @@
 #![allow(non_camel_case_types)]
 
-use crate::lazy::any_encoding::{AnyEncoding, LazyRawAnyReader};
+use crate::lazy::any_encoding::{AnyEncoding, IonEncoding, LazyRawAnyReader};
 use crate::lazy::binary::raw::reader::LazyRawBinaryReader;
 use crate::lazy::decoder::LazyDecoder;
 use crate::lazy::decoder::LazyRawReader;
 use crate::lazy::encoding::{BinaryEncoding_1_0, TextEncoding_1_0, TextEncoding_1_1};
-use crate::lazy::expanded::{ExpandedValueRef, LazyExpandedValue, LazyExpandingReader};
-use crate::lazy::system_stream_item::SystemStreamItem;
+use crate::lazy::expanded::{
+    unexpanded_e_expression_error, ExpandedValueRef, LazyExpandedValue, LazyExpandingReader,
+};
+use crate::lazy::system_stream_item::{SystemStreamEvent, SystemStreamItem};
 use crate::lazy::text::raw::v1_1::reader::LazyRawTextReader_1_1;
 use crate::lazy::value::LazyValue;
 use crate::result::IonFailure;
-use crate::{IonResult, IonType, RawSymbolTokenRef, SymbolTable};
+use crate::{IonError, IonResult, IonType, RawSymbolTokenRef, Symbol, SymbolTable};
+use std::ops::Range;
 
 // Symbol IDs used for processing symbol table structs
 const ION_SYMBOL_TABLE: RawSymbolTokenRef = RawSymbolTokenRef::SymbolId(3);
@@ -71,6 +74,9 @@ const SYMBOLS: RawSymbolTokenRef = RawSymbolTokenRef::SymbolId(7);
 /// ```
 pub struct LazySystemReader<'data, D: LazyDecoder> {
     pub(crate) expanding_reader: LazyExpandingReader<'data, D>,
+    // If the last symbol table that `next_event` visited both reset the symbol table and defined
+    // new symbols, this holds the number of new symbols so it can be reported on the next call.
+    pending_symbols_appended: Option<usize>,
 }
 
 pub type LazySystemBinaryReader<'data> = LazySystemReader<'data, BinaryEncoding_1_0>;
@@ -79,6 +85,22 @@ pub type LazySystemTextReader_1_1<'data> = LazySystemReader<'data, TextEncoding_
 
 pub type LazySystemAnyReader<'data> = LazySystemReader<'data, AnyEncoding>;
 
+/// Aggregate statistics describing how a stream's symbol table changed as it was read. See
+/// [`LazySystemReader::symbol_table_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SymbolTableStats {
+    /// The number of symbol tables that replaced the active symbol table.
+    pub resets: usize,
+    /// The number of symbol tables that appended to the active symbol table.
+    pub appends: usize,
+    /// The total number of symbols defined by all of the symbol tables, including symbols whose
+    /// text was unknown.
+    pub symbols_interned: usize,
+    /// The largest number of symbols (including system symbols) that the active symbol table
+    /// has held at once.
+    pub peak_len: usize,
+}
+
 // If the reader encounters a symbol table in the stream, it will store all of the symbols that
 // the table defines in this structure so that they may be applied when the reader next advances.
 #[derive(Default)]
@@ -98,11 +120,96 @@ impl PendingLst {
     }
 }
 
+/// Describes the input that was skipped when [`LazySystemReader::next_item_recoverable`]
+/// encountered an error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ResumeToken {
+    error_offset: usize,
+    resume_offset: usize,
+}
+
+impl ResumeToken {
+    pub(crate) fn new(error_offset: usize, resume_offset: usize) -> Self {
+        Self {
+            error_offset,
+            resume_offset,
+        }
+    }
+
+    /// The stream offset at which the reader began reading the item that could not be read.
+    pub fn error_offset(&self) -> usize {
+        self.error_offset
+    }
+
+    /// The stream offset from which the reader will continue reading.
+    pub fn resume_offset(&self) -> usize {
+        self.resume_offset
+    }
+
+    /// The number of bytes of input that were skipped to reach the resume offset.
+    pub fn bytes_skipped(&self) -> usize {
+        self.resume_offset - self.error_offset
+    }
+}
+
+/// An error returned by [`LazySystemReader::next_item_recoverable`] after the reader has skipped
+/// past the data that caused it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecoverableError {
+    error: IonError,
+    resume_token: ResumeToken,
+}
+
+impl RecoverableError {
+    pub(crate) fn new(error: IonError, resume_token: ResumeToken) -> Self {
+        Self {
+            error,
+            resume_token,
+        }
+    }
+
+    /// The error that was encountered while reading.
+    pub fn error(&self) -> &IonError {
+        &self.error
+    }
+
+    /// Describes where the reader resumed after the error.
+    pub fn resume_token(&self) -> ResumeToken {
+        self.resume_token
+    }
+
+    /// Discards the resume token, returning the underlying error.
+    pub fn into_error(self) -> IonError {
+        self.error
+    }
+}
+
 impl<'data> LazySystemAnyReader<'data> {
     pub fn new(ion_data: &'data [u8]) -> LazySystemAnyReader<'data> {
         let raw_reader = LazyRawAnyReader::new(ion_data);
         let expanding_reader = LazyExpandingReader::new(raw_reader);
-        LazySystemReader { expanding_reader }
+        LazySystemReader {
+            expanding_reader,
+            pending_symbols_appended: None,
+        }
+    }
+
+    /// Returns the encoding of the reader's input, as determined from its first bytes: binary or
+    /// text, and Ion 1.0 or Ion 1.1. Returns `None` if the input is empty or contains only
+    /// whitespace. See [`LazyRawAnyReader::detected_encoding`] for details.
+    ///
+    /// ```
+    /// use ion_rs::lazy::any_encoding::IonEncoding;
+    /// use ion_rs::lazy::system_reader::LazySystemAnyReader;
+    ///
+    /// let reader = LazySystemAnyReader::new(b"{foo: bar}");
+    /// assert_eq!(reader.detected_encoding(), Some(IonEncoding::TextV1_0));
+    ///
+    /// let reader = LazySystemAnyReader::new(&[0xE0, 0x01, 0x00, 0xEA, 0x20]);
+    /// assert_eq!(reader.detected_encoding(), Some(IonEncoding::BinaryV1_0));
+    /// ```
+    pub fn detected_encoding(&self) -> Option<IonEncoding> {
+        self.expanding_reader.detected_encoding()
     }
 }
 
@@ -110,7 +217,10 @@ impl<'data> LazySystemBinaryReader<'data> {
     pub(crate) fn new(ion_data: &'data [u8]) -> LazySystemBinaryReader<'data> {
         let raw_reader = LazyRawBinaryReader::new(ion_data);
         let expanding_reader = LazyExpandingReader::new(raw_reader);
-        LazySystemReader { expanding_reader }
+        LazySystemReader {
+            expanding_reader,
+            pending_symbols_appended: None,
+        }
     }
 }
 
@@ -118,11 +228,177 @@ impl<'data> LazySystemTextReader_1_1<'data> {
     pub(crate) fn new(ion_data: &'data [u8]) -> LazySystemTextReader_1_1<'data> {
         let raw_reader = LazyRawTextReader_1_1::new(ion_data);
         let expanding_reader = LazyExpandingReader::new(raw_reader);
-        LazySystemReader { expanding_reader }
+        LazySystemReader {
+            expanding_reader,
+            pending_symbols_appended: None,
+        }
     }
 }
 
 impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
+    /// Configures the reader to return a decoding error when it encounters a top-level value whose
+    /// encoded body is longer than `max_value_len` bytes. The error is raised when the reader
+    /// advances to the value, before any of its data is read or materialized.
+    ///
+    /// Nested values are always contained within the encoding of their top-level value, so this
+    /// also bounds the size of any value nested inside a top-level container.
+    pub fn max_value_len(mut self, max_value_len: usize) -> Self {
+        self.expanding_reader.set_max_value_len(max_value_len);
+        self
+    }
+
+    /// Configures the reader to return a decoding error when iterating over the annotations of a
+    /// value that has more than `max_annotations` of them. This bounds the work done for a value
+    /// whose annotations wrapper declares an enormous annotations sequence.
+    ///
+    /// The error is raised by the value's [`AnnotationsIterator`](crate::lazy::value::AnnotationsIterator)
+    /// when it would yield annotation number `max_annotations + 1`, so values whose annotations
+    /// are never read are not affected. The limit applies to values at any depth.
+    pub fn max_annotations_per_value(mut self, max_annotations: usize) -> Self {
+        self.expanding_reader
+            .set_max_annotations_per_value(max_annotations);
+        self
+    }
+
+    /// Configures whether iterating over a struct's fields returns a decoding error upon finding a
+    /// field name that appeared earlier in the same struct. Field names are compared by their
+    /// text, so a name encoded as a symbol ID matches the same name encoded as text. Names with
+    /// unknown text are not compared.
+    ///
+    /// The check applies to application structs at any depth as they are iterated by
+    /// [`LazyStruct`](crate::lazy::r#struct::LazyStruct). This is disabled by default.
+    pub fn reject_duplicate_fields(mut self, reject: bool) -> Self {
+        self.expanding_reader.set_reject_duplicate_fields(reject);
+        self
+    }
+
+    /// Configures the reader to return a decoding error instead of yielding more than
+    /// `max_total_values` application values. This allows input that contains an excessive
+    /// number of (possibly tiny) values to be rejected before all of them are processed.
+    ///
+    /// Only top-level application values count toward the limit; system values like symbol tables
+    /// and values nested inside containers do not. Once the limit has been exceeded, each
+    /// subsequent attempt to read another value also fails.
+    pub fn max_total_values(mut self, max_total_values: usize) -> Self {
+        self.expanding_reader.set_max_total_values(max_total_values);
+        self
+    }
+
+    /// Begins reading `ion_data` as a new stream, reusing the memory that the reader has already
+    /// allocated for its symbol table and scratch space. This is cheaper than constructing a new
+    /// reader for each of many small documents.
+    ///
+    /// The symbol table is reset to the system symbols, any symbol table that was being processed
+    /// is discarded, and the reader's statistics and [`max_total_values`](Self::max_total_values)
+    /// count start over. The reader's configuration (limits, fallback symbols, and so on) is kept.
+    /// Values read from the previous stream borrow the reader, so they must be dropped before it
+    /// can be reset.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::system_reader::LazySystemAnyReader;
+    ///
+    /// let mut reader = LazySystemAnyReader::new(b"$ion_symbol_table::{symbols: [\"foo\"]} $10");
+    /// assert_eq!(reader.next_value()?.unwrap().read()?.expect_symbol()?, "foo");
+    /// assert_eq!(reader.symbol_table_stats().peak_len, 11);
+    ///
+    /// reader.reset(b"1 2");
+    /// assert_eq!(reader.next_value()?.unwrap().read()?.expect_i64()?, 1);
+    /// assert_eq!(reader.symbol_table_stats().peak_len, 10);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn reset(&mut self, ion_data: &'data [u8]) {
+        self.expanding_reader.reset(ion_data);
+        self.pending_symbols_appended = None;
+    }
+
+    /// Returns statistics about the symbol tables that the reader has applied so far, including
+    /// the number of resets and appends, the total number of symbols they defined, and the
+    /// largest size that the active symbol table has reached.
+    ///
+    /// A symbol table is applied when the reader advances past it, so a table that the reader is
+    /// currently positioned on is not yet reflected in the statistics.
+    pub fn symbol_table_stats(&self) -> SymbolTableStats {
+        self.expanding_reader.symbol_table_stats()
+    }
+
+    /// Returns the symbol table that is currently in effect, including the system symbols. This
+    /// can be used to look up the text of symbol IDs found in the stream.
+    ///
+    /// As with [`Self::symbol_table_stats`], a symbol table is applied when the reader advances
+    /// past it, so a table that the reader is currently positioned on is not yet reflected.
+    /// Symbols provided by [`Self::with_fallback_symbols`] are not included.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::system_reader::LazySystemAnyReader;
+    ///
+    /// let data = br#"
+    ///     $ion_symbol_table::{symbols: ["foo", "bar"]}
+    ///     $10
+    /// "#;
+    /// let mut reader = LazySystemAnyReader::new(data);
+    /// reader.next_value()?;
+    /// assert_eq!(reader.symbol_table().text_for(11), Some("bar"));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn symbol_table(&self) -> &SymbolTable {
+        self.expanding_reader.symbol_table()
+    }
+
+    /// Configures whether e-expressions are expanded. By default, the reader evaluates each
+    /// e-expression it encounters and returns the resulting values in its place. If `expand_macros`
+    /// is `false`, the reader instead returns each e-expression unevaluated as a
+    /// [`SystemStreamItem::EExpression`], which is useful for tools that analyze macro usage.
+    ///
+    /// When expansion is disabled, e-expressions can only be read with [`Self::next_item`]. Methods
+    /// that only return values or encoding context changes, such as [`Self::next_value`] and
+    /// [`Self::next_event`], return an illegal operation error if they encounter one.
+    pub fn expand_macros(mut self, expand_macros: bool) -> Self {
+        self.expanding_reader.set_expand_macros(expand_macros);
+        self
+    }
+
+    /// Configures whether [`LazyValue::read`] converts timestamps to UTC. When enabled, a
+    /// timestamp with a known offset is returned as the same instant with an offset of `+00:00`.
+    /// Timestamps with an unknown offset (including those with less than minute precision, which
+    /// never have an offset) cannot be normalized and are returned as-is.
+    ///
+    /// This is disabled by default.
+    pub fn normalize_timestamps_to_utc(mut self, normalize: bool) -> Self {
+        self.expanding_reader
+            .set_normalize_timestamps_to_utc(normalize);
+        self
+    }
+
+    /// Configures the reader to resolve symbol IDs using `fallback_symbols` when the stream's own
+    /// symbol table does not define them. This allows data whose symbol table was stored
+    /// separately (and lost) to be read given an externally supplied mapping of symbol IDs to text.
+    ///
+    /// The stream's symbol table always takes precedence: `fallback_symbols` is only consulted
+    /// for symbol IDs that are beyond the end of the stream's table or whose text the stream's table
+    /// leaves unknown. Symbol tables in the stream continue to be processed as usual; the fallback
+    /// symbols are never appended to or replaced.
+    pub fn with_fallback_symbols(mut self, fallback_symbols: SymbolTable) -> Self {
+        self.expanding_reader.set_fallback_symbols(fallback_symbols);
+        self
+    }
+
+    /// Configures the reader to treat a symbol table whose `imports` field is the symbol `text` as
+    /// an append to the current symbol table, just as it would if the field were the symbol
+    /// `$ion_symbol_table`. This can be called more than once to accept several such symbols.
+    ///
+    /// This exists for interoperability with non-conforming producers that use a custom symbol to
+    /// mean "append". By default, only `$ion_symbol_table` is recognized, as the Ion specification
+    /// requires. If the sentinel is encoded as a symbol ID, its text is resolved using the symbol
+    /// table in effect when the new symbol table is encountered.
+    pub fn add_append_sentinel(&mut self, text: &str) {
+        self.expanding_reader.add_append_sentinel(text);
+    }
+
     // Returns `true` if the provided [`LazyRawValue`] is a struct whose first annotation is
     // `$ion_symbol_table`.
     pub fn is_symbol_table_struct(lazy_value: &'_ LazyExpandedValue<'_, D>) -> IonResult<bool> {
@@ -144,8 +420,59 @@ impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
         self.expanding_reader.next_item()
     }
 
+    /// Like [`Self::next_item`], but attempts to recover from errors encountered while reading
+    /// the next top-level item.
+    ///
+    /// If the next item cannot be read, the reader skips ahead to the next plausible top-level
+    /// boundary and returns a [`RecoverableError`] containing both the original error and a
+    /// [`ResumeToken`] describing the range of input that was skipped. Calling this method again
+    /// will continue reading from the resume offset.
+    ///
+    /// Recovery is best-effort:
+    /// * In binary Ion, values are not self-delimiting and a corrupt header makes it impossible to
+    ///   know where the value ends. The only reliable boundary is an Ion version marker, so the
+    ///   reader skips to the next IVM in the stream (or the end of the input if there isn't one).
+    ///   Any valid values between the bad value and that IVM are lost. Because the IVM resets the
+    ///   symbol table, data written with symbol tables that were declared earlier in the stream
+    ///   cannot be recovered.
+    /// * In text Ion, the reader skips to the next whitespace following the first token of the
+    ///   bad value. If the bad value was a container, the reader may resume in the middle of it and
+    ///   report additional errors before it is realigned with the top level.
+    /// * Because the lazy reader does not read a value's body until it is requested, errors inside
+    ///   a value's body (as opposed to its header) will typically surface when the value is read
+    ///   rather than when the reader advances.
+    pub fn next_item_recoverable<'top>(
+        &'top mut self,
+    ) -> Result<SystemStreamItem<'top, D>, RecoverableError>
+    where
+        'data: 'top,
+    {
+        self.expanding_reader.next_item_recoverable()
+    }
+
     /// Returns the next value that is part of the application data model, bypassing all encoding
     /// artifacts (IVMs, symbol tables).
+    ///
+    /// The returned value is lazy: its body is not read until it is requested. Calling
+    /// `next_value` again without reading the value skips it cheaply. In binary Ion, the value's
+    /// length prefix is used to jump to the next value; in text Ion, the value's tokens are
+    /// matched but not decoded. This makes `next_value` suitable for finding the Nth value in a
+    /// stream or for counting values.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::system_reader::LazySystemAnyReader;
+    ///
+    /// let mut reader = LazySystemAnyReader::new(b"{a: [1, 2, 3]} \"skipped\" 3");
+    /// // Skip the first two values without reading them.
+    /// reader.next_value()?;
+    /// reader.next_value()?;
+    /// assert_eq!(reader.next_value()?.unwrap().read()?.expect_i64()?, 3);
+    /// assert!(reader.next_value()?.is_none());
+    ///# Ok(())
+    ///# }
+    /// ```
     pub fn next_value<'top>(&'top mut self) -> IonResult<Option<LazyValue<'top, D>>>
     where
         'data: 'top,
@@ -153,6 +480,184 @@ impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
         self.expanding_reader.next_value()
     }
 
+    /// Advances past the next value that is part of the application data model without reading
+    /// it. Returns `Ok(true)` if a value was skipped, or `Ok(false)` if the end of the stream was
+    /// reached.
+    ///
+    /// This is a convenience wrapper around [`Self::next_value`] that discards the value; it
+    /// costs the same as calling `next_value` and not reading the result. Symbol tables are
+    /// still processed so that values later in the stream can be resolved.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::system_reader::LazySystemAnyReader;
+    ///
+    /// let mut reader = LazySystemAnyReader::new(b"{a: [1, 2, 3]} \"skipped\" 3");
+    /// assert!(reader.skip_next()?);
+    /// assert!(reader.skip_next()?);
+    /// assert_eq!(reader.next_value()?.unwrap().read()?.expect_i64()?, 3);
+    /// assert!(!reader.skip_next()?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn skip_next(&mut self) -> IonResult<bool> {
+        Ok(self.next_value()?.is_some())
+    }
+
+    /// Like [`Self::next_value`], but also returns the `[start, end)` offsets of the value's
+    /// encoding in the original input. The range includes the value's annotations and header
+    /// (if any).
+    ///
+    /// Values produced by macro evaluation do not correspond to a single span of the input;
+    /// if one is encountered, this method returns an illegal operation error.
+    pub fn next_value_with_span<'top>(
+        &'top mut self,
+    ) -> IonResult<Option<(LazyValue<'top, D>, Range<usize>)>>
+    where
+        'data: 'top,
+    {
+        let Some(value) = self.expanding_reader.next_value()? else {
+            return Ok(None);
+        };
+        let Some(range) = value.expanded_value.range() else {
+            return IonResult::illegal_operation(
+                "the current value was produced by macro evaluation and has no encoded span",
+            );
+        };
+        Ok(Some((value, range)))
+    }
+
+    /// Like [`Self::next_value`], but also returns the value's annotations, resolved to owned
+    /// [`Symbol`]s. This allows the annotations to be inspected alongside the value without
+    /// iterating over them separately.
+    ///
+    /// If the annotations cannot be resolved, returns the error that was encountered.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::system_reader::LazySystemAnyReader;
+    ///
+    /// let mut reader = LazySystemAnyReader::new(b"foo::bar::1 2");
+    /// let (annotations, value) = reader.next_annotated_value()?.unwrap();
+    /// assert_eq!(annotations, vec!["foo", "bar"]);
+    /// assert_eq!(value.read()?.expect_i64()?, 1);
+    /// let (annotations, _value) = reader.next_annotated_value()?.unwrap();
+    /// assert!(annotations.is_empty());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn next_annotated_value<'top>(
+        &'top mut self,
+    ) -> IonResult<Option<(Vec<Symbol>, LazyValue<'top, D>)>>
+    where
+        'data: 'top,
+    {
+        let Some(value) = self.expanding_reader.next_value()? else {
+            return Ok(None);
+        };
+        let annotations = value
+            .annotations()
+            .map(|annotation| annotation.map(|symbol_ref| symbol_ref.to_owned()))
+            .collect::<IonResult<Vec<Symbol>>>()?;
+        Ok(Some((annotations, value)))
+    }
+
+    /// Reads the remainder of the stream, calling `f` on each value that is part of the
+    /// application data model. Encoding artifacts (IVMs, symbol tables) are processed but not
+    /// passed to `f`.
+    ///
+    /// Each value borrows the reader and is only valid for the duration of the call to `f`.
+    /// Reading stops at the end of the stream or at the first error, whether it was raised while
+    /// reading or returned by `f`.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::system_reader::LazySystemAnyReader;
+    ///
+    /// let mut reader = LazySystemAnyReader::new(b"1 2 3");
+    /// let mut sum = 0;
+    /// reader.for_each_value(|value| {
+    ///     sum += value.read()?.expect_i64()?;
+    ///     Ok(())
+    /// })?;
+    /// assert_eq!(sum, 6);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn for_each_value<F>(&mut self, mut f: F) -> IonResult<()>
+    where
+        F: for<'top> FnMut(LazyValue<'top, D>) -> IonResult<()>,
+    {
+        while let Some(value) = self.next_value()? {
+            f(value)?;
+        }
+        Ok(())
+    }
+
+    /// In binary Ion, returns the first byte of the encoding of the top-level value (including
+    /// symbol tables) that the reader most recently advanced to. This is the value's type
+    /// descriptor or, if the value is annotated, the type descriptor of its annotations wrapper.
+    /// This is intended for diagnosing why a value was decoded unexpectedly.
+    ///
+    /// Returns `None` if the reader is not positioned on a value, if the input is text, or if the
+    /// value was produced by macro evaluation rather than read directly from the input. Values
+    /// borrow the reader, so this can only be called once the current value is no longer in use.
+    pub fn current_type_descriptor(&self) -> Option<u8> {
+        self.expanding_reader.current_type_descriptor()
+    }
+
+    /// Returns the next [`SystemStreamEvent`]: either an application value or a change to the
+    /// encoding context (an IVM or a symbol table) in the order in which they appear in the stream.
+    /// Returns `None` at the end of the stream.
+    ///
+    /// A symbol table that appends to the active table is reported as
+    /// [`SystemStreamEvent::SymbolTableAppended`]. A symbol table that replaces the active table is
+    /// reported as [`SystemStreamEvent::SymbolTableReset`], followed by
+    /// [`SystemStreamEvent::SymbolTableAppended`] if it defined any symbols.
+    pub fn next_event<'top>(&'top mut self) -> IonResult<Option<SystemStreamEvent<'top, D>>>
+    where
+        'data: 'top,
+    {
+        if let Some(count) = self.pending_symbols_appended.take() {
+            return Ok(Some(SystemStreamEvent::SymbolTableAppended(count)));
+        }
+        let event = match self.expanding_reader.next_item()? {
+            SystemStreamItem::VersionMarker(major, minor) => SystemStreamEvent::Ivm(major, minor),
+            SystemStreamItem::SymbolTable(_) => {
+                let pending_lst = self.expanding_reader.pending_lst();
+                let count = pending_lst.symbols.len();
+                if pending_lst.is_lst_append {
+                    SystemStreamEvent::SymbolTableAppended(count)
+                } else {
+                    if count > 0 {
+                        self.pending_symbols_appended = Some(count);
+                    }
+                    SystemStreamEvent::SymbolTableReset
+                }
+            }
+            SystemStreamItem::Value(value) => SystemStreamEvent::Value(value),
+            SystemStreamItem::EExpression(_) => return unexpanded_e_expression_error(),
+            SystemStreamItem::EndOfStream => return Ok(None),
+        };
+        Ok(Some(event))
+    }
+
+    /// Returns an iterator over the symbol tables defined in the stream. For each local symbol
+    /// table, the iterator yields the complete [`SymbolTable`] that is in effect once it has been
+    /// applied, including the system symbols and any symbols it appended to.
+    ///
+    /// Application values are skipped without being read, so this is a fast way to find which
+    /// symbols a stream uses. Iteration ends at the end of the stream or after the first error.
+    pub fn symbol_tables_only(&mut self) -> SymbolTablesIterator<'_, 'data, D> {
+        SymbolTablesIterator {
+            reader: self,
+            has_failed: false,
+        }
+    }
+
     // If the last stream item the reader visited was a symbol table, its `PendingLst` will
     // contain new symbols that need to be added to the local symbol table.
     fn apply_pending_lst(symbol_table: &mut SymbolTable, pending_lst: &mut PendingLst) {
@@ -176,9 +681,15 @@ impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
 
     // Traverses a symbol table, processing the `symbols` and `imports` fields as needed to
     // populate the `PendingLst`.
+    //
+    // `append_sentinels` lists any symbol text other than `$ion_symbol_table` that should be
+    // treated as an append signal in the `imports` field. `current_symbols` is the symbol table in
+    // effect, which is used to resolve the text of a sentinel encoded as a symbol ID.
     pub(crate) fn process_symbol_table(
         pending_lst: &mut PendingLst,
         symbol_table: &LazyExpandedValue<'_, D>,
+        append_sentinels: &[String],
+        current_symbols: &SymbolTable,
     ) -> IonResult<()> {
         // We've already confirmed this is an annotated struct
         let symbol_table = symbol_table.read()?.expect_struct()?;
@@ -186,9 +697,13 @@ impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
         let mut found_symbols_field = false;
         let mut found_imports_field = false;
 
+        // The fields may appear in either order. Processing `imports` only records whether the new
+        // symbols are appended; `apply_pending_lst` always resolves that before adding the symbols.
+
         for field_result in symbol_table.iter() {
             let field = field_result?;
-            if field.raw_name().matches_sid_or_text(7, "symbols") {
+            let field_name = field.raw_name();
+            if field_name.matches_sid_or_text(7, "symbols") {
                 if found_symbols_field {
                     return IonResult::decoding_error(
                         "found symbol table with multiple 'symbols' fields",
@@ -197,14 +712,19 @@ impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
                 found_symbols_field = true;
                 Self::process_symbols(pending_lst, field.value())?;
             }
-            if field.raw_name().matches_sid_or_text(6, "imports") {
+            if field_name.matches_sid_or_text(6, "imports") {
                 if found_imports_field {
                     return IonResult::decoding_error(
                         "found symbol table with multiple 'imports' fields",
                     );
                 }
                 found_imports_field = true;
-                Self::process_imports(pending_lst, field.value())?;
+                Self::process_imports(
+                    pending_lst,
+                    field.value(),
+                    append_sentinels,
+                    current_symbols,
+                )?;
             }
             // Ignore other fields
         }
@@ -233,11 +753,23 @@ impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
     fn process_imports(
         pending_lst: &mut PendingLst,
         imports: &LazyExpandedValue<'_, D>,
+        append_sentinels: &[String],
+        current_symbols: &SymbolTable,
     ) -> IonResult<()> {
         match imports.read()? {
             ExpandedValueRef::Symbol(symbol_ref) => {
                 if symbol_ref.matches_sid_or_text(3, "$ion_symbol_table") {
                     pending_lst.is_lst_append = true;
+                } else if !append_sentinels.is_empty() {
+                    let text = match &symbol_ref {
+                        RawSymbolTokenRef::SymbolId(sid) => current_symbols.text_for(*sid),
+                        RawSymbolTokenRef::Text(text) => Some(text.as_ref()),
+                    };
+                    if let Some(text) = text {
+                        if append_sentinels.iter().any(|sentinel| sentinel == text) {
+                            pending_lst.is_lst_append = true;
+                        }
+                    }
                 }
                 // Any other symbol is ignored
             }
@@ -256,11 +788,52 @@ impl<'data, D: LazyDecoder> LazySystemReader<'data, D> {
     }
 }
 
+/// An iterator over the symbol tables defined in a stream. See
+/// [`LazySystemReader::symbol_tables_only`].
+pub struct SymbolTablesIterator<'iter, 'data, D: LazyDecoder> {
+    reader: &'iter mut LazySystemReader<'data, D>,
+    has_failed: bool,
+}
+
+impl<'iter, 'data, D: LazyDecoder> Iterator for SymbolTablesIterator<'iter, 'data, D> {
+    type Item = IonResult<SymbolTable>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.has_failed {
+            return None;
+        }
+        let expanding_reader = &self.reader.expanding_reader;
+        loop {
+            match expanding_reader.next_item() {
+                Ok(SystemStreamItem::SymbolTable(_)) => {
+                    let pending_lst = expanding_reader.pending_lst();
+                    let mut symbol_table = expanding_reader.symbol_table().clone();
+                    if !pending_lst.is_lst_append {
+                        symbol_table.reset();
+                    }
+                    for symbol in &pending_lst.symbols {
+                        symbol_table.intern_or_add_placeholder(symbol.as_ref());
+                    }
+                    return Some(Ok(symbol_table));
+                }
+                Ok(SystemStreamItem::EndOfStream) => return None,
+                // IVMs and values are skipped. The lazy reader only reads a value's header to find
+                // where it ends.
+                Ok(_) => {}
+                Err(e) => {
+                    self.has_failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::lazy::binary::test_utilities::to_binary_ion;
     use crate::lazy::system_stream_item::SystemStreamItem;
-    use crate::IonResult;
+    use crate::{Element, IonResult};
 
     use super::*;
 
@@ -345,4 +918,612 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn recover_from_bad_binary_value() -> IonResult<()> {
+        let mut data = to_binary_ion("1")?;
+        // A type descriptor byte with the reserved type code 15, followed by a stray byte
+        data.extend_from_slice(&[0xF0, 0xFF]);
+        data.extend_from_slice(&to_binary_ion("2")?);
+        let mut system_reader = LazySystemBinaryReader::new(&data);
+        assert_eq!(
+            system_reader
+                .next_item_recoverable()
+                .unwrap()
+                .version_marker(),
+            Some((1, 0))
+        );
+        let value = system_reader
+            .next_item_recoverable()
+            .unwrap()
+            .expect_value()?;
+        assert_eq!(value.read()?.expect_i64()?, 1);
+        let error = system_reader.next_item_recoverable().unwrap_err();
+        let token = error.resume_token();
+        assert_eq!(token.error_offset(), 6);
+        assert_eq!(token.resume_offset(), 8);
+        assert_eq!(token.bytes_skipped(), 2);
+        assert_eq!(
+            system_reader
+                .next_item_recoverable()
+                .unwrap()
+                .version_marker(),
+            Some((1, 0))
+        );
+        let value = system_reader
+            .next_item_recoverable()
+            .unwrap()
+            .expect_value()?;
+        assert_eq!(value.read()?.expect_i64()?, 2);
+        assert!(matches!(
+            system_reader.next_item_recoverable(),
+            Ok(SystemStreamItem::EndOfStream)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn skip_next() -> IonResult<()> {
+        let text = r#"$ion_symbol_table::{symbols: ["foo"]} {a: [1, 2]} foo::(x y) "bar" foo"#;
+        let binary = to_binary_ion(text)?;
+        for data in [text.as_bytes(), binary.as_slice()] {
+            let mut system_reader = LazySystemAnyReader::new(data);
+            let mut skipped = 0;
+            while system_reader.skip_next()? {
+                skipped += 1;
+            }
+            assert_eq!(skipped, 4);
+
+            // Symbol tables are applied even when the values that follow them are skipped.
+            let mut system_reader = LazySystemAnyReader::new(data);
+            for _ in 0..3 {
+                assert!(system_reader.skip_next()?);
+            }
+            let value = system_reader.next_value()?.unwrap();
+            assert_eq!(value.read()?.expect_symbol()?, "foo");
+            assert!(!system_reader.skip_next()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn reset() -> IonResult<()> {
+        let first = to_binary_ion("$ion_symbol_table::{symbols: [\"foo\"]} foo::1")?;
+        let second = to_binary_ion("bar::2")?;
+        let mut system_reader = LazySystemBinaryReader::new(&first);
+        let (annotations, _value) = system_reader.next_annotated_value()?.unwrap();
+        assert_eq!(annotations, vec!["foo"]);
+        assert_eq!(system_reader.symbol_table_stats().symbols_interned, 1);
+
+        system_reader.reset(&second);
+        assert_eq!(
+            system_reader.symbol_table_stats(),
+            SymbolTableStats {
+                peak_len: 10,
+                ..Default::default()
+            }
+        );
+        let (annotations, value) = system_reader.next_annotated_value()?.unwrap();
+        assert_eq!(annotations, vec!["bar"]);
+        assert_eq!(value.read()?.expect_i64()?, 2);
+        assert!(system_reader.next_value()?.is_none());
+
+        // Resetting in the middle of a stream discards the rest of it, including a symbol table
+        // that was read but not yet applied.
+        let mut system_reader = LazySystemBinaryReader::new(&first);
+        assert!(matches!(
+            system_reader.next_item()?,
+            SystemStreamItem::VersionMarker(1, 0)
+        ));
+        assert!(matches!(
+            system_reader.next_item()?,
+            SystemStreamItem::SymbolTable(_)
+        ));
+        system_reader.reset(&second);
+        let (annotations, _value) = system_reader.next_annotated_value()?.unwrap();
+        assert_eq!(annotations, vec!["bar"]);
+        assert_eq!(system_reader.symbol_table_stats().symbols_interned, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn next_annotated_value() -> IonResult<()> {
+        let ion_data = to_binary_ion("$ion_symbol_table::{symbols: [\"a\"]} a::b::5 [c::6] $0::7")?;
+        let mut system_reader = LazySystemBinaryReader::new(&ion_data);
+        let (annotations, value) = system_reader.next_annotated_value()?.unwrap();
+        assert_eq!(annotations, vec![Symbol::from("a"), Symbol::from("b")]);
+        assert_eq!(value.read()?.expect_i64()?, 5);
+        let (annotations, value) = system_reader.next_annotated_value()?.unwrap();
+        assert!(annotations.is_empty());
+        assert_eq!(value.ion_type(), IonType::List);
+        let (annotations, _value) = system_reader.next_annotated_value()?.unwrap();
+        assert_eq!(annotations, vec![Symbol::unknown_text()]);
+        assert!(system_reader.next_annotated_value()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn next_value_with_span() -> IonResult<()> {
+        let mut system_reader = LazySystemAnyReader::new(b"1 foo::\"hi\"  [a, b]");
+        let mut spans = vec![];
+        while let Some((_value, span)) = system_reader.next_value_with_span()? {
+            spans.push(span);
+        }
+        assert_eq!(spans, vec![0..1, 2..11, 13..19]);
+
+        #[rustfmt::skip]
+        let ion_data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0x21, 0x05, // 5
+            0xE4, 0x81, 0x84, 0x21, 0x07, // name::7
+        ];
+        let mut system_reader = LazySystemBinaryReader::new(ion_data);
+        let (value, span) = system_reader.next_value_with_span()?.unwrap();
+        assert_eq!(value.read()?.expect_i64()?, 5);
+        assert_eq!(span, 4..6);
+        let (value, span) = system_reader.next_value_with_span()?.unwrap();
+        assert_eq!(value.read()?.expect_i64()?, 7);
+        assert_eq!(span, 6..11);
+        assert!(system_reader.next_value_with_span()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn next_event() -> IonResult<()> {
+        let mut system_reader = LazySystemAnyReader::new(
+            br#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            1
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            $ion_symbol_table::{}
+            $ion_1_0
+            2
+            "#,
+        );
+        let mut events = vec![];
+        while let Some(event) = system_reader.next_event()? {
+            let event = match event {
+                SystemStreamEvent::Value(value) => format!("value {}", value.read()?.expect_i64()?),
+                other => format!("{other:?}"),
+            };
+            events.push(event);
+        }
+        assert_eq!(
+            events,
+            vec![
+                "symbol table reset",
+                "2 symbols appended",
+                "value 1",
+                "1 symbols appended",
+                "symbol table reset",
+                "version marker v1.0",
+                "value 2",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn annotated_symbols_and_imports_fields_are_processed() -> IonResult<()> {
+        let mut system_reader = LazySystemAnyReader::new(
+            br#"
+            $ion_symbol_table::{symbols: foo::["a", "b"]}
+            $10 $11
+            $ion_symbol_table::{imports: bar::$ion_symbol_table, symbols: baz::["c"]}
+            $10 $12
+            "#,
+        );
+        for expected in ["a", "b", "a", "c"] {
+            let value = system_reader.next_value()?.unwrap();
+            assert_eq!(value.read()?.expect_symbol()?.text(), Some(expected));
+        }
+        assert!(system_reader.next_value()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn fallback_symbols() -> IonResult<()> {
+        let data = br#"
+            $11::$10
+            $ion_symbol_table::{symbols: ["inline", null]}
+            $11::$10
+        "#;
+        let fallback = SymbolTable::from_symbols(&["foo", "bar"]);
+        let mut system_reader = LazySystemAnyReader::new(data).with_fallback_symbols(fallback);
+        // With no symbol table in the stream, both symbol IDs are resolved using the fallback.
+        let value = system_reader.next_value()?.unwrap();
+        assert!(value.annotations().are(["bar"])?);
+        assert_eq!(value.read()?.expect_symbol()?.text(), Some("foo"));
+        // The stream's table defines `$10` and leaves the text of `$11` unknown.
+        let value = system_reader.next_value()?.unwrap();
+        assert!(value.annotations().are(["bar"])?);
+        assert_eq!(value.read()?.expect_symbol()?.text(), Some("inline"));
+        assert!(system_reader.next_value()?.is_none());
+
+        // Without fallback symbols, the first value cannot be resolved.
+        let mut system_reader = LazySystemAnyReader::new(data);
+        let value = system_reader.next_value()?.unwrap();
+        assert!(value.read().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn for_each_value() -> IonResult<()> {
+        let data = br#"
+            $ion_symbol_table::{symbols: ["a"]}
+            $10 [1, 2]
+            $ion_1_0
+            "text"
+        "#;
+        let mut system_reader = LazySystemAnyReader::new(data);
+        let mut values = Vec::new();
+        system_reader.for_each_value(|value| {
+            values.push(Element::try_from(value)?);
+            Ok(())
+        })?;
+        assert_eq!(Element::read_all(r#"a [1, 2] "text""#)?, values.into());
+
+        // Reading stops at the first error returned by the callback.
+        let mut system_reader = LazySystemAnyReader::new(data);
+        let mut values_visited = 0;
+        let result = system_reader.for_each_value(|value| {
+            values_visited += 1;
+            if value.ion_type() == IonType::List {
+                return IonResult::decoding_error("found a list");
+            }
+            Ok(())
+        });
+        assert!(result.is_err());
+        assert_eq!(values_visited, 2);
+        // The reader is left positioned after the value that caused the error.
+        let value = system_reader.next_value()?.unwrap();
+        assert_eq!(value.read()?.expect_string()?.text(), "text");
+        Ok(())
+    }
+
+    #[test]
+    fn current_type_descriptor() -> IonResult<()> {
+        let text = "1 foo::\"hi\" [2, 3]";
+        let binary = to_binary_ion(text)?;
+        let mut system_reader = LazySystemAnyReader::new(&binary);
+        assert_eq!(system_reader.current_type_descriptor(), None);
+        let mut type_descriptors = Vec::new();
+        loop {
+            let is_end = matches!(system_reader.next_item()?, SystemStreamItem::EndOfStream);
+            type_descriptors.push(system_reader.current_type_descriptor());
+            if is_end {
+                break;
+            }
+        }
+        assert_eq!(
+            type_descriptors,
+            vec![
+                None,       // IVM
+                Some(0xEC), // Symbol table, which is annotated
+                Some(0x21), // 1
+                Some(0xE5), // foo::"hi"
+                Some(0xB4), // [2, 3]
+                None,       // End of stream
+            ]
+        );
+
+        let mut system_reader = LazySystemAnyReader::new(text.as_bytes());
+        system_reader.next_value()?;
+        assert_eq!(system_reader.current_type_descriptor(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn ion_1_1_symbol_tables() -> IonResult<()> {
+        // `$ion_symbol_table`, `imports`, and `symbols` have the same symbol IDs in Ion 1.1 as in
+        // Ion 1.0, so a 1.1 stream's symbol tables can refer to them by text or by ID.
+        let ion_data = br#"
+            $ion_1_1
+            $ion_symbol_table::{symbols: ["foo", "bar"]}
+            $10 $11
+            $3::{$6: $3, $7: ["baz"]}
+            $12
+        "#;
+        let mut reader = LazySystemTextReader_1_1::new(ion_data);
+        let mut symbols = Vec::new();
+        while let Some(value) = reader.next_value()? {
+            symbols.push(value.read()?.expect_symbol()?.text().unwrap().to_owned());
+        }
+        assert_eq!(symbols, vec!["foo", "bar", "baz"]);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_field_order() -> IonResult<()> {
+        // Each stream defines the same symbols, but with the `imports` and `symbols` fields of
+        // the second table in a different order.
+        let imports_first = r#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            $10 $11 $12
+            $ion_symbol_table::{imports: other, symbols: ["d"]}
+            $10
+        "#;
+        let symbols_first = r#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            $ion_symbol_table::{symbols: ["c"], imports: $ion_symbol_table}
+            $10 $11 $12
+            $ion_symbol_table::{symbols: ["d"], imports: other}
+            $10
+        "#;
+        fn symbol_texts(data: &[u8]) -> IonResult<Vec<String>> {
+            let mut system_reader = LazySystemAnyReader::new(data);
+            let mut texts = Vec::new();
+            while let Some(value) = system_reader.next_value()? {
+                texts.push(value.read()?.expect_symbol()?.text().unwrap().to_owned());
+            }
+            Ok(texts)
+        }
+        let expected = vec!["a", "b", "c", "d"];
+        for data in [imports_first, symbols_first] {
+            assert_eq!(symbol_texts(data.as_bytes())?, expected);
+            assert_eq!(symbol_texts(&to_binary_ion(data)?)?, expected);
+
+            // The binary re-encoding defines all of its symbols in a single table, so only the
+            // text stream is checked for the append.
+            let mut system_reader = LazySystemAnyReader::new(data.as_bytes());
+            let mut events = vec![];
+            while let Some(event) = system_reader.next_event()? {
+                if !matches!(event, SystemStreamEvent::Value(_)) {
+                    events.push(format!("{event:?}"));
+                }
+            }
+            assert_eq!(
+                events,
+                vec![
+                    "symbol table reset",
+                    "2 symbols appended",
+                    "1 symbols appended",
+                    "symbol table reset",
+                    "1 symbols appended",
+                ]
+            );
+            assert_eq!(system_reader.symbol_table_stats().appends, 1);
+
+            // The append is applied to the symbol table that the reader reports, too.
+            let tables = LazySystemAnyReader::new(data.as_bytes())
+                .symbol_tables_only()
+                .collect::<IonResult<Vec<_>>>()?;
+            let texts: Vec<_> = tables[1].symbols()[10..]
+                .iter()
+                .map(|symbol| symbol.text().unwrap())
+                .collect();
+            assert_eq!(texts, vec!["a", "b", "c"]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn append_sentinels() -> IonResult<()> {
+        let data = br#"
+            $ion_symbol_table::{symbols: ["append", "a"]}
+            $ion_symbol_table::{imports: append, symbols: ["b"]}
+            $ion_symbol_table::{imports: $10, symbols: ["c"]}
+            $ion_symbol_table::{imports: other, symbols: ["d"]}
+        "#;
+        fn user_symbols(system_reader: &mut LazySystemAnyReader) -> IonResult<Vec<Vec<String>>> {
+            system_reader
+                .symbol_tables_only()
+                .map(|table| {
+                    Ok(table?.symbols()[10..]
+                        .iter()
+                        .map(|symbol| symbol.text().unwrap().to_owned())
+                        .collect())
+                })
+                .collect()
+        }
+
+        // By default, only `$ion_symbol_table` signals an append; each table replaces the last.
+        let mut system_reader = LazySystemAnyReader::new(data);
+        assert_eq!(
+            user_symbols(&mut system_reader)?,
+            vec![
+                vec!["append", "a"],
+                vec!["b"],
+                // `$10` is resolved using the table in effect, which defines it as `b`.
+                vec!["c"],
+                vec!["d"],
+            ]
+        );
+
+        let mut system_reader = LazySystemAnyReader::new(data);
+        system_reader.add_append_sentinel("append");
+        assert_eq!(
+            user_symbols(&mut system_reader)?,
+            vec![
+                vec!["append", "a"],
+                // The text `append` is a sentinel...
+                vec!["append", "a", "b"],
+                // ...as is a symbol ID whose text is `append`...
+                vec!["append", "a", "b", "c"],
+                // ...but other symbols are not.
+                vec!["d"],
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_tables_only() -> IonResult<()> {
+        let mut system_reader = LazySystemAnyReader::new(
+            br#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            $10 [1, 2, 3]
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            {foo: $12}
+            $ion_symbol_table::{symbols: ["d"]}
+            $10
+            "#,
+        );
+        let tables = system_reader
+            .symbol_tables_only()
+            .collect::<IonResult<Vec<_>>>()?;
+        let user_symbols: Vec<Vec<&str>> = tables
+            .iter()
+            .map(|table| {
+                table.symbols()[10..]
+                    .iter()
+                    .map(|symbol| symbol.text().unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            user_symbols,
+            vec![vec!["a", "b"], vec!["a", "b", "c"], vec!["d"]]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_reflects_advanced_items() -> IonResult<()> {
+        let mut system_reader = LazySystemAnyReader::new(
+            br#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            $12
+            "#,
+        );
+        assert_eq!(system_reader.symbol_table().len(), 10);
+        // Positioned on the first symbol table; it has not been applied yet.
+        assert!(matches!(
+            system_reader.next_item()?,
+            SystemStreamItem::SymbolTable(_)
+        ));
+        assert_eq!(system_reader.symbol_table().text_for(10), None);
+        assert!(matches!(
+            system_reader.next_item()?,
+            SystemStreamItem::Value(_)
+        ));
+        assert_eq!(system_reader.symbol_table().text_for(10), Some("a"));
+        assert_eq!(system_reader.symbol_table().text_for(11), Some("b"));
+        // Advance past the appending symbol table to the value that follows it.
+        system_reader.next_item()?;
+        system_reader.next_item()?;
+        let symbol_table = system_reader.symbol_table();
+        assert_eq!(symbol_table.len(), 13);
+        assert_eq!(symbol_table.text_for(12), Some("c"));
+        assert_eq!(symbol_table.text_for(10), Some("a"));
+        Ok(())
+    }
+
+    #[test]
+    fn recover_from_bad_text_value() -> IonResult<()> {
+        let mut system_reader = LazySystemAnyReader::new(b"1 ]oops 2");
+        let value = system_reader
+            .next_item_recoverable()
+            .unwrap()
+            .expect_value()?;
+        assert_eq!(value.read()?.expect_i64()?, 1);
+        let error = system_reader.next_item_recoverable().unwrap_err();
+        assert_eq!(error.resume_token().error_offset(), 1);
+        assert_eq!(error.resume_token().resume_offset(), 7);
+        let value = system_reader
+            .next_item_recoverable()
+            .unwrap()
+            .expect_value()?;
+        assert_eq!(value.read()?.expect_i64()?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn expand_macros() -> IonResult<()> {
+        use crate::lazy::expanded::macro_evaluator::RawEExpression;
+        use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+
+        let ion_data = b"1 (:values 2 3) 4";
+        let mut reader = LazySystemTextReader_1_1::new(ion_data);
+        let mut values = Vec::new();
+        while let Some(value) = reader.next_value()? {
+            values.push(value.read()?.expect_i64()?);
+        }
+        assert_eq!(values, vec![1, 2, 3, 4]);
+
+        let mut reader = LazySystemTextReader_1_1::new(ion_data).expand_macros(false);
+        let value = reader.next_item()?.expect_value()?;
+        assert_eq!(value.read()?.expect_i64()?, 1);
+        let SystemStreamItem::EExpression(e_exp) = reader.next_item()? else {
+            panic!("expected an e-expression");
+        };
+        assert_eq!(e_exp.id(), MacroIdRef::LocalName("values"));
+        assert_eq!(e_exp.raw_arguments().count(), 2);
+        let value = reader.next_item()?.expect_value()?;
+        assert_eq!(value.read()?.expect_i64()?, 4);
+        assert!(matches!(reader.next_item()?, SystemStreamItem::EndOfStream));
+
+        // Unexpanded e-expressions are not silently dropped by the value and event APIs.
+        let mut reader = LazySystemTextReader_1_1::new(ion_data).expand_macros(false);
+        assert_eq!(reader.next_value()?.unwrap().read()?.expect_i64()?, 1);
+        assert!(reader.next_value().is_err());
+        let mut reader = LazySystemTextReader_1_1::new(ion_data).expand_macros(false);
+        assert!(matches!(
+            reader.next_event()?,
+            Some(SystemStreamEvent::Value(_))
+        ));
+        assert!(reader.next_event().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_stats() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{symbols: ["a", "b", "c"]}
+            $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["d", null]}
+            $13
+            $ion_symbol_table::{symbols: ["e"]}
+            $10
+        "#;
+        let mut reader = LazySystemAnyReader::new(ion_data.as_bytes());
+        assert_eq!(
+            reader.symbol_table_stats(),
+            SymbolTableStats {
+                peak_len: 10,
+                ..Default::default()
+            }
+        );
+        let mut symbols = Vec::new();
+        while let Some(value) = reader.next_value()? {
+            symbols.push(value.read()?.expect_symbol()?.text().unwrap().to_owned());
+        }
+        assert_eq!(symbols, ["a", "d", "e"]);
+        assert_eq!(
+            reader.symbol_table_stats(),
+            SymbolTableStats {
+                resets: 2,
+                appends: 1,
+                symbols_interned: 6,
+                peak_len: 15,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn max_total_values() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{symbols: ["a"]}
+            [1, 2, 3]
+            $10
+            4
+        "#;
+        let mut reader = LazySystemAnyReader::new(ion_data.as_bytes()).max_total_values(2);
+        assert!(reader.next_value()?.is_some());
+        assert!(reader.next_value()?.is_some());
+        assert!(reader.next_value().is_err());
+        assert!(reader.next_value().is_err());
+
+        let mut reader = LazySystemAnyReader::new(ion_data.as_bytes()).max_total_values(3);
+        let mut count = 0;
+        while reader.next_value()?.is_some() {
+            count += 1;
+        }
+        assert_eq!(count, 3);
+        Ok(())
+    }
 }
@@ -15,6 +15,10 @@ pub enum SystemStreamItem<'top, D: LazyDecoder> {
     SymbolTable(LazyStruct<'top, D>),
     /// An application-level Ion value
     Value(LazyValue<'top, D>),
+    /// An unexpanded e-expression. This is only surfaced when the reader has been configured not
+    /// to expand macros; see
+    /// [`LazySystemReader::expand_macros`](crate::lazy::system_reader::LazySystemReader::expand_macros).
+    EExpression(D::EExpression<'top>),
     /// The end of the stream
     EndOfStream,
 }
@@ -27,6 +31,7 @@ impl<'top, D: LazyDecoder> Debug for SystemStreamItem<'top, D> {
             }
             SystemStreamItem::SymbolTable(_) => write!(f, "a symbol table"),
             SystemStreamItem::Value(value) => write!(f, "{}", value.ion_type()),
+            SystemStreamItem::EExpression(_) => write!(f, "an e-expression"),
             SystemStreamItem::EndOfStream => write!(f, "<nothing>"),
         }
     }
@@ -70,3 +75,35 @@ impl<'top, D: LazyDecoder> SystemStreamItem<'top, D> {
         }
     }
 }
+
+/// An ordered view of the changes that a [`LazySystemReader`](crate::lazy::system_reader::LazySystemReader)
+/// observes in a stream: application values interleaved with the changes to the symbol context
+/// that they are read in. See
+/// [`LazySystemReader::next_event`](crate::lazy::system_reader::LazySystemReader::next_event).
+#[non_exhaustive]
+pub enum SystemStreamEvent<'top, D: LazyDecoder> {
+    /// An Ion Version Marker (IVM). This also resets the symbol table to the system symbols.
+    Ivm(u8, u8),
+    /// A symbol table that replaced the active symbol table with the system symbols. If the table
+    /// also defined new symbols, this is followed by a [`SystemStreamEvent::SymbolTableAppended`].
+    SymbolTableReset,
+    /// The given number of symbols were appended to the active symbol table.
+    SymbolTableAppended(usize),
+    /// An application-level Ion value
+    Value(LazyValue<'top, D>),
+}
+
+impl<'top, D: LazyDecoder> Debug for SystemStreamEvent<'top, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SystemStreamEvent::Ivm(major, minor) => {
+                write!(f, "version marker v{}.{}", major, minor)
+            }
+            SystemStreamEvent::SymbolTableReset => write!(f, "symbol table reset"),
+            SystemStreamEvent::SymbolTableAppended(count) => {
+                write!(f, "{} symbols appended", count)
+            }
+            SystemStreamEvent::Value(value) => write!(f, "{}", value.ion_type()),
+        }
+    }
+}
@@ -87,6 +87,31 @@ impl<'data> LazyRawReader<'data, TextEncoding_1_0> for LazyRawTextReader_1_0<'da
     {
         self.next(allocator)
     }
+
+    fn position(&self) -> usize {
+        self.offset
+    }
+
+    fn resynchronize(&mut self, position: usize) -> usize {
+        self.offset = skip_past_next_token(self.input, position);
+        self.offset
+    }
+}
+
+/// Returns the index of the first whitespace byte that follows the first non-whitespace token
+/// at or after `position`. Used by the text readers to skip over unreadable data at the top level.
+pub(crate) fn skip_past_next_token(input: &[u8], position: usize) -> usize {
+    let position = position.min(input.len());
+    let remaining = &input[position..];
+    let token_start = remaining
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(remaining.len());
+    let token_length = remaining[token_start..]
+        .iter()
+        .position(|byte| byte.is_ascii_whitespace())
+        .unwrap_or(remaining.len() - token_start);
+    position + token_start + token_length
 }
 
 #[cfg(test)]
@@ -15,6 +15,7 @@ use crate::lazy::encoding::TextEncoding_1_1;
 use crate::lazy::raw_stream_item::{LazyRawStreamItem, RawStreamItem};
 use crate::lazy::text::buffer::TextBufferView;
 use crate::lazy::text::parse_result::{AddContext, ToIteratorOutput};
+use crate::lazy::text::raw::reader::skip_past_next_token;
 use crate::lazy::text::value::{LazyRawTextValue_1_1, RawTextAnnotationsIterator};
 use crate::result::IonFailure;
 use crate::{IonResult, IonType};
@@ -140,6 +141,15 @@ impl<'data> LazyRawReader<'data, TextEncoding_1_1> for LazyRawTextReader_1_1<'da
         self.offset = remaining.offset();
         Ok(matched_item)
     }
+
+    fn position(&self) -> usize {
+        self.offset
+    }
+
+    fn resynchronize(&mut self, position: usize) -> usize {
+        self.offset = skip_past_next_token(self.input, position);
+        self.offset
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -2,9 +2,10 @@
 
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use std::ops::Range;
 
 use crate::lazy::decoder::private::{LazyContainerPrivate, LazyRawValuePrivate};
-use crate::lazy::decoder::{LazyDecoder, LazyRawValue};
+use crate::lazy::decoder::{AnnotationEncoding, LazyDecoder, LazyRawValue};
 use crate::lazy::encoding::{TextEncoding, TextEncoding_1_0, TextEncoding_1_1};
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::text::buffer::TextBufferView;
@@ -136,6 +137,28 @@ impl<'top, E: TextEncoding<'top>> LazyRawValue<'top, E> for MatchedRawTextValue<
         };
         Ok(value_ref)
     }
+
+    fn value_length(&self) -> usize {
+        self.encoded_value.data_length()
+    }
+
+    fn range(&self) -> Range<usize> {
+        let data_range = self.encoded_value.data_range();
+        let start = self
+            .encoded_value
+            .annotations_range()
+            .map(|annotations| annotations.start)
+            .unwrap_or(data_range.start);
+        start..data_range.end
+    }
+
+    fn annotation_encoding(&self) -> AnnotationEncoding {
+        if self.encoded_value.has_annotations() {
+            AnnotationEncoding::Text
+        } else {
+            AnnotationEncoding::Unannotated
+        }
+    }
 }
 
 impl<'top, E: TextEncoding<'top>> LazyRawValuePrivate<'top> for LazyRawTextValue<'top, E> {
@@ -160,6 +183,18 @@ impl<'top, E: TextEncoding<'top>> LazyRawValue<'top, E> for LazyRawTextValue<'to
     fn read(&self) -> IonResult<RawValueRef<'top, E>> {
         self.matched.read()
     }
+
+    fn value_length(&self) -> usize {
+        self.matched.value_length()
+    }
+
+    fn range(&self) -> Range<usize> {
+        self.matched.range()
+    }
+
+    fn annotation_encoding(&self) -> AnnotationEncoding {
+        self.matched.annotation_encoding()
+    }
 }
 
 pub struct RawTextAnnotationsIterator<'data> {
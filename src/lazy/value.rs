@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 
-use crate::lazy::decoder::LazyDecoder;
+use crate::lazy::decoder::{AnnotationEncoding, LazyDecoder};
 use crate::lazy::encoding::BinaryEncoding_1_0;
-use crate::lazy::expanded::{ExpandedAnnotationsIterator, ExpandedValueRef, LazyExpandedValue};
+use crate::lazy::expanded::{
+    EncodingContext, ExpandedAnnotationsIterator, ExpandedValueRef, LazyExpandedValue,
+};
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::value_ref::ValueRef;
@@ -10,7 +12,7 @@ use crate::result::IonFailure;
 use crate::symbol_ref::AsSymbolRef;
 use crate::{
     Annotations, Element, IntoAnnotatedElement, IonError, IonResult, IonType, RawSymbolTokenRef,
-    SymbolRef, SymbolTable, Value,
+    SymbolRef, SymbolTable, TextKind, Value,
 };
 
 /// A value in a binary Ion stream whose header has been parsed but whose body (i.e. its data) has
@@ -65,9 +67,77 @@ impl<'top, D: LazyDecoder> LazyValue<'top, D> {
         LazyValue { expanded_value }
     }
 
-    fn symbol_table(&'top self) -> &'top SymbolTable {
+    /// Returns the symbol table that was active when this value was read. Symbol IDs found in this
+    /// value's annotations, field names, and symbol values resolve against this table.
+    ///
+    /// The returned reference shares this value's `'top` lifetime, which ends when the reader
+    /// that produced the value advances. Because advancing the reader may modify its symbol
+    /// table, the borrow checker will not allow the reader to advance while the table (or the
+    /// value) is still in use.
+    pub fn symbol_table(&self) -> &'top SymbolTable {
         self.expanded_value.context.symbol_table
     }
+
+    /// Returns this value alongside the symbol table that was active when it was read, allowing
+    /// both to be handed to code that needs to resolve symbols without also passing the reader.
+    /// See [`LazyValue::symbol_table`] for details about the table reference's lifetime.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, SymbolId, SymbolTable};
+    /// use ion_rs::lazy::reader::LazyBinaryReader;
+    /// use ion_rs::lazy::value::LazyBinaryValue;
+    ///
+    /// // Looks up the symbol ID of a symbol value's text.
+    /// fn symbol_id(symbols: &SymbolTable, value: &LazyBinaryValue<'_>) -> IonResult<Option<SymbolId>> {
+    ///     let symbol = value.read()?.expect_symbol()?;
+    ///     Ok(symbol.text().and_then(|text| symbols.sid_for(&text)))
+    /// }
+    ///
+    /// let binary_ion = Element::read_one("hello")?.to_binary()?;
+    /// let mut lazy_reader = LazyBinaryReader::new(&binary_ion)?;
+    /// let lazy_value = lazy_reader.expect_next()?;
+    /// let (symbols, value) = lazy_value.with_symbols();
+    /// // `hello` is the first local symbol
+    /// assert_eq!(symbol_id(symbols, value)?, Some(10));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn with_symbols(&self) -> (&'top SymbolTable, &Self) {
+        (self.symbol_table(), self)
+    }
+
+    /// Renders this value as multi-line, indented Ion text. This is intended as a debugging aid;
+    /// unlike this type's `Debug` output, nested containers are read and each symbol ID is
+    /// resolved to its text.
+    ///
+    /// Symbol IDs are resolved using `symtab`, which would typically be this value's own
+    /// [`symbol_table`](Self::symbol_table). Returns an error if the value cannot be read or it
+    /// contains a symbol ID that `symtab` does not define.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    /// use ion_rs::lazy::reader::LazyBinaryReader;
+    ///
+    /// let binary_ion = Element::read_one("foo::{bar: [1, baz]}")?.to_binary()?;
+    /// let mut lazy_reader = LazyBinaryReader::new(&binary_ion)?;
+    /// let lazy_value = lazy_reader.expect_next()?;
+    ///
+    /// let text = lazy_value.to_pretty_string(lazy_value.symbol_table())?;
+    /// assert!(text.lines().count() > 1);
+    /// assert_eq!(Element::read_one(text)?, Element::read_one("foo::{bar: [1, baz]}")?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn to_pretty_string(&self, symtab: &'top SymbolTable) -> IonResult<String> {
+        let mut value = self.clone();
+        value.expanded_value.context.symbol_table = symtab;
+        Element::try_from(value)?.to_text(TextKind::Pretty)
+    }
+
     /// Returns the [`IonType`] of this value.
     /// ```
     ///# use ion_rs::IonResult;
@@ -124,10 +194,57 @@ impl<'top, D: LazyDecoder> LazyValue<'top, D> {
     ///# }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_value.annotations(),
-            symbol_table: self.expanded_value.context.symbol_table,
-        }
+        AnnotationsIterator::new(
+            self.expanded_value.annotations(),
+            &self.expanded_value.context,
+        )
+    }
+
+    /// Returns `Ok(true)` if this value's annotations are exactly the provided sequence, or
+    /// `Ok(false)` if not. Comparison stops at the first annotation that does not match. This is
+    /// equivalent to calling [`AnnotationsIterator::are`] on [`Self::annotations`].
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::reader::LazyReader;
+    ///
+    /// let mut reader = LazyReader::new(b"foo::bar::1");
+    /// let value = reader.expect_next()?;
+    /// assert!(value.annotations_are(["foo", "bar"])?);
+    /// assert!(!value.annotations_are(["foo"])?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn annotations_are<A: AsSymbolRef, I: IntoIterator<Item = A>>(
+        &self,
+        expected: I,
+    ) -> IonResult<bool> {
+        self.annotations().are(expected)
+    }
+
+    /// Returns how this value's annotations were physically encoded in the input stream: for
+    /// example, whether a binary Ion 1.0 annotations wrapper stored its length in its type
+    /// descriptor or in a separate VarUInt. This allows tools that re-encode data to reproduce the
+    /// original framing exactly.
+    ///
+    /// Values produced by macro evaluation were not read from the input and return
+    /// [`AnnotationEncoding::Synthesized`].
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::lazy::decoder::AnnotationEncoding;
+    /// use ion_rs::lazy::reader::LazyReader;
+    ///
+    /// let mut reader = LazyReader::new(b"foo::1 2");
+    /// assert_eq!(reader.expect_next()?.annotation_encoding(), AnnotationEncoding::Text);
+    /// assert_eq!(reader.expect_next()?.annotation_encoding(), AnnotationEncoding::Unannotated);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn annotation_encoding(&self) -> AnnotationEncoding {
+        self.expanded_value.annotation_encoding()
     }
 
     /// Reads the body of this value (that is: its data) and returns it as a [`ValueRef`].
@@ -166,6 +283,9 @@ impl<'top, D: LazyDecoder> LazyValue<'top, D> {
             Int(i) => ValueRef::Int(i),
             Float(f) => ValueRef::Float(f),
             Decimal(d) => ValueRef::Decimal(d),
+            Timestamp(t) if self.expanded_value.context.normalize_timestamps_to_utc => {
+                ValueRef::Timestamp(t.normalized_to_utc())
+            }
             Timestamp(t) => ValueRef::Timestamp(t),
             String(s) => ValueRef::String(s),
             Symbol(s) => {
@@ -221,9 +341,24 @@ impl<'top, D: LazyDecoder> TryFrom<LazyValue<'top, D>> for Element {
 pub struct AnnotationsIterator<'top, D: LazyDecoder> {
     pub(crate) expanded_annotations: ExpandedAnnotationsIterator<'top, D>,
     pub(crate) symbol_table: &'top SymbolTable,
+    // The maximum number of annotations this iterator will yield before raising an error, if any.
+    max_annotations: Option<usize>,
+    annotations_read: usize,
 }
 
 impl<'top, D: LazyDecoder> AnnotationsIterator<'top, D> {
+    pub(crate) fn new(
+        expanded_annotations: ExpandedAnnotationsIterator<'top, D>,
+        context: &EncodingContext<'top>,
+    ) -> Self {
+        Self {
+            expanded_annotations,
+            symbol_table: context.symbol_table,
+            max_annotations: context.max_annotations_per_value,
+            annotations_read: 0,
+        }
+    }
+
     /// Returns `Ok(true)` if this annotations iterator matches the provided sequence exactly, or
     /// `Ok(false)` if not. If a decoding error occurs while visiting and resolving each annotation,
     /// returns an `Err(IonError)`.
@@ -311,7 +446,17 @@ impl<'top, D: LazyDecoder> Iterator for AnnotationsIterator<'top, D> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let raw_annotation = self.expanded_annotations.next()?;
+        if let Some(max_annotations) = self.max_annotations {
+            if self.annotations_read == max_annotations {
+                return Some(IonResult::decoding_error(format!(
+                    "found a value with more than the configured maximum of {max_annotations} annotations"
+                )));
+            }
+        }
+        self.annotations_read += 1;
         match raw_annotation {
+            // `$0` is always the unknown symbol, regardless of the active symbol table.
+            Ok(RawSymbolTokenRef::SymbolId(0)) => Some(Ok(SymbolRef::with_unknown_text())),
             Ok(RawSymbolTokenRef::SymbolId(sid)) => match self.symbol_table.symbol_for(sid) {
                 None => Some(IonResult::decoding_error(
                     "found a symbol ID that was not in the symbol table",
@@ -346,7 +491,7 @@ mod tests {
     use crate::lazy::binary::test_utilities::to_binary_ion;
     use crate::lazy::reader::LazyBinaryReader;
     use crate::{ion_list, ion_sexp, ion_struct, Decimal, IonResult, IonType, Symbol, Timestamp};
-    use crate::{Element, IntoAnnotatedElement};
+    use crate::{Element, IntoAnnotatedElement, SymbolTable};
 
     #[test]
     fn annotations_are() -> IonResult<()> {
@@ -368,6 +513,89 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn value_annotations_are() -> IonResult<()> {
+        let ion_data = to_binary_ion("foo::bar::$ion::5 6")?;
+        let mut reader = LazyBinaryReader::new(&ion_data)?;
+        let first = reader.expect_next()?;
+        assert!(first.annotations_are(["foo", "bar", "$ion"])?);
+        assert!(!first.annotations_are(["foo", "bar"])?);
+        assert!(!first.annotations_are(["foo", "baz", "$ion"])?);
+        assert!(first
+            .expanded_value
+            .annotations_are(["foo", "bar", "$ion"])?);
+        assert!(!first.expanded_value.annotations_are(["foo", "bar"])?);
+        assert!(!first
+            .expanded_value
+            .annotations_are(["foo", "bar", "$ion", "quux"])?);
+
+        let second = reader.expect_next()?;
+        assert!(second.annotations_are(Vec::<&str>::new())?);
+        assert!(second.expanded_value.annotations_are(Vec::<&str>::new())?);
+        assert!(!second.expanded_value.annotations_are(["foo"])?);
+        Ok(())
+    }
+
+    #[test]
+    fn annotation_encoding() -> IonResult<()> {
+        use crate::lazy::decoder::AnnotationEncoding::*;
+        use crate::lazy::reader::LazyTextReader_1_1;
+        #[rustfmt::skip]
+        let ion_data = [
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0xE4, 0x81, 0x84, 0x21, 0x05, // $4::5, inline-length wrapper
+            0xEE, 0x84, 0x81, 0x84, 0x21, 0x05, // $4::5, VarUInt-length wrapper
+            0x21, 0x05, // 5
+        ];
+        let mut reader = LazyBinaryReader::new(&ion_data)?;
+        assert_eq!(
+            reader.expect_next()?.annotation_encoding(),
+            InlineLengthWrapper
+        );
+        assert_eq!(
+            reader.expect_next()?.annotation_encoding(),
+            VarUIntLengthWrapper
+        );
+        assert_eq!(reader.expect_next()?.annotation_encoding(), Unannotated);
+
+        let mut reader = LazyTextReader_1_1::new(b"(:make_string foo::\"a\" \"b\")")?;
+        assert_eq!(reader.expect_next()?.annotation_encoding(), Synthesized);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_symbol_annotation() -> IonResult<()> {
+        let ion_data = to_binary_ion("$0::foo::5")?;
+        let mut reader = LazyBinaryReader::new(&ion_data)?;
+        let value = reader.expect_next()?;
+        let annotations = value.annotations().collect::<IonResult<Vec<_>>>()?;
+        assert!(annotations[0].is_unknown());
+        assert_eq!(annotations[0].text(), None);
+        assert!(!annotations[1].is_unknown());
+        // `$0` has no text, so it cannot be matched by text.
+        assert!(!value.annotations().are(["", "foo"])?);
+        Ok(())
+    }
+
+    #[test]
+    fn to_pretty_string() -> IonResult<()> {
+        let other_symbols = SymbolTable::from_symbols(&["x", "y", "z"]);
+        let ion_data = to_binary_ion("a::{b: [1, (c)]}")?;
+        let mut reader = LazyBinaryReader::new(&ion_data)?;
+        let value = reader.expect_next()?;
+
+        let text = value.to_pretty_string(value.symbol_table())?;
+        assert_eq!(text, "a::{\n  b: [\n    1,\n    (\n      c\n    )\n  ]\n}");
+
+        // Symbol IDs are resolved using the provided table rather than the value's own.
+        let text = value.to_pretty_string(&other_symbols)?;
+        assert_eq!(
+            Element::read_one(text)?,
+            Element::read_one("x::{y: [1, (z)]}")?
+        );
+        Ok(())
+    }
+
     fn lazy_value_equals(ion_text: &str, expected: impl Into<Element>) -> IonResult<()> {
         let binary_ion = &to_binary_ion(ion_text)?;
         let mut reader = LazyBinaryReader::new(binary_ion)?;
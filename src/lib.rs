@@ -162,6 +162,8 @@
 use rstest_reuse;
 
 // Private modules that serve to organize implementation details.
+#[cfg(feature = "tokio")]
+mod async_reader;
 mod binary;
 mod blocking_reader;
 mod catalog;
@@ -199,12 +201,13 @@ pub mod thunk;
 #[cfg(feature = "experimental-streaming")]
 pub mod tokens;
 
+pub use constants::SystemSymbol;
 pub use element::builders::{SequenceBuilder, StructBuilder};
 pub use element::{
-    reader::ElementReader, writer::ElementWriter, Annotations, Element, IntoAnnotatedElement,
-    IntoAnnotations, Sequence, Value,
+    reader::ElementReader, symbol_table_for_elements, writer::ElementWriter, Annotations, Element,
+    IntoAnnotatedElement, IntoAnnotations, Sequence, Value,
 };
-pub use ion_data::IonData;
+pub use ion_data::{compare_ion, IonComparison, IonData, IonPath, IonPathComponent};
 pub use symbol_ref::SymbolRef;
 #[doc(inline)]
 pub use types::{
@@ -233,6 +236,11 @@ pub use {
     text::raw_text_writer::{RawTextWriter, RawTextWriterBuilder},
 };
 
+// These re-exports are only visible if both the "experimental-reader" and "tokio" features are
+// enabled.
+#[cfg(all(feature = "experimental-reader", feature = "tokio"))]
+pub use async_reader::{AsyncIonReader, AsyncRawBinaryReader, AsyncRawTextReader};
+
 // These re-exports are only visible if the "experimental-writer" feature is enabled.
 #[cfg(feature = "experimental-writer")]
 pub use {
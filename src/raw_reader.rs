@@ -3,32 +3,8 @@ use crate::raw_symbol_token::RawSymbolToken;
 use std::fmt::{Display, Formatter};
 use std::io::Read;
 
-// Assuming these types are defined elsewhere in your crate.
-use crate::{Blob, Clob, Decimal, Int, Timestamp};
-use crate::{IonType, Str};
-
-// Custom error type for improved error handling.
-#[derive(Debug)]
-pub enum IonError {
-    ReadError(std::io::Error),
-    ParseError(String),
-    // Other error types as needed.
-}
-
-impl std::fmt::Display for IonError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            IonError::ReadError(err) => write!(f, "Read error: {}", err),
-            IonError::ParseError(err) => write!(f, "Parse error: {}", err),
-            // Handle other errors.
-        }
-    }
-}
-
-impl std::error::Error for IonError {}
-
-// Custom result type using the IonError.
-type IonResult<T> = std::result::Result<T, IonError>;
+use crate::result::IonResult;
+use crate::{Blob, Clob, Decimal, Int, IonType, Str, Timestamp};
 
 /// Represents a reader that processes raw Ion data, emitting `RawStreamItem`s and using `RawSymbolToken`.
 pub trait RawReader: IonReader<Item = RawStreamItem, Symbol = RawSymbolToken> {}
@@ -46,7 +22,97 @@ impl<R: RawReader + ?Sized> IonReader for Box<R> {
         (**self).ion_version()
     }
 
-    // Other method implementations...
+    fn next(&mut self) -> IonResult<Self::Item> {
+        (**self).next()
+    }
+
+    fn current(&self) -> Self::Item {
+        (**self).current()
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        (**self).ion_type()
+    }
+
+    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'a> {
+        (**self).annotations()
+    }
+
+    fn field_name(&self) -> IonResult<Self::Symbol> {
+        (**self).field_name()
+    }
+
+    fn is_null(&self) -> bool {
+        (**self).is_null()
+    }
+
+    fn read_null(&mut self) -> IonResult<IonType> {
+        (**self).read_null()
+    }
+
+    fn read_bool(&mut self) -> IonResult<bool> {
+        (**self).read_bool()
+    }
+
+    fn read_i64(&mut self) -> IonResult<i64> {
+        (**self).read_i64()
+    }
+
+    fn read_int(&mut self) -> IonResult<Int> {
+        (**self).read_int()
+    }
+
+    fn read_f32(&mut self) -> IonResult<f32> {
+        (**self).read_f32()
+    }
+
+    fn read_f64(&mut self) -> IonResult<f64> {
+        (**self).read_f64()
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Decimal> {
+        (**self).read_decimal()
+    }
+
+    fn read_string(&mut self) -> IonResult<Str> {
+        (**self).read_string()
+    }
+
+    fn read_str(&mut self) -> IonResult<&str> {
+        (**self).read_str()
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
+        (**self).read_symbol()
+    }
+
+    fn read_blob(&mut self) -> IonResult<Blob> {
+        (**self).read_blob()
+    }
+
+    fn read_clob(&mut self) -> IonResult<Clob> {
+        (**self).read_clob()
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
+        (**self).read_timestamp()
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        (**self).step_in()
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        (**self).step_out()
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        (**self).parent_type()
+    }
+
+    fn depth(&self) -> usize {
+        (**self).depth()
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -67,6 +133,37 @@ impl RawStreamItem {
             RawStreamItem::Value(ion_type)
         }
     }
+
+    /// Returns the [`IonType`] of the value this item represents, whether or not it is null.
+    /// Returns `None` for version markers and [`RawStreamItem::Nothing`].
+    ///
+    /// ```
+    /// use ion_rs::{IonType, RawStreamItem};
+    ///
+    /// assert_eq!(RawStreamItem::Value(IonType::Int).ion_type(), Some(IonType::Int));
+    /// assert_eq!(RawStreamItem::Null(IonType::List).ion_type(), Some(IonType::List));
+    /// assert_eq!(RawStreamItem::VersionMarker(1, 0).ion_type(), None);
+    /// assert_eq!(RawStreamItem::Nothing.ion_type(), None);
+    /// ```
+    pub fn ion_type(&self) -> Option<IonType> {
+        match self {
+            RawStreamItem::Value(ion_type) | RawStreamItem::Null(ion_type) => Some(*ion_type),
+            RawStreamItem::VersionMarker(..) | RawStreamItem::Nothing => None,
+        }
+    }
+
+    /// Returns `true` if this item is a null value of any type.
+    ///
+    /// ```
+    /// use ion_rs::{IonType, RawStreamItem};
+    ///
+    /// assert!(RawStreamItem::Null(IonType::String).is_null());
+    /// assert!(!RawStreamItem::Value(IonType::String).is_null());
+    /// assert!(!RawStreamItem::Nothing.is_null());
+    /// ```
+    pub fn is_null(&self) -> bool {
+        matches!(self, RawStreamItem::Null(_))
+    }
 }
 
 impl Display for RawStreamItem {
@@ -106,5 +203,3 @@ impl Expandable for Vec<u8> {
         true
     }
 }
-
-// Add any other implementations or logic as needed.
@@ -1,5 +1,5 @@
 use crate::raw_symbol_token::RawSymbolToken;
-use crate::{Symbol, SymbolId};
+use crate::{Symbol, SymbolId, SystemSymbol};
 use std::borrow::Cow;
 
 /// Like RawSymbolToken, but the Text variant holds a borrowed reference instead of a String.
@@ -13,8 +13,11 @@ impl<'a> RawSymbolTokenRef<'a> {
     /// Returns `true` if this token matches either the specified symbol ID or text value.
     /// This is useful for comparing tokens that represent system symbol values of an unknown
     /// encoding.
+    ///
+    /// Symbol ID 0 (`$0`) represents a symbol with unknown text; it never matches.
     pub fn matches_sid_or_text(&self, symbol_id: SymbolId, symbol_text: &str) -> bool {
         match self {
+            RawSymbolTokenRef::SymbolId(0) => false,
             RawSymbolTokenRef::SymbolId(sid) => symbol_id == *sid,
             RawSymbolTokenRef::Text(text) => symbol_text == text,
         }
@@ -38,6 +41,12 @@ impl AsRawSymbolTokenRef for SymbolId {
     }
 }
 
+impl AsRawSymbolTokenRef for SystemSymbol {
+    fn as_raw_symbol_token_ref(&self) -> RawSymbolTokenRef {
+        RawSymbolTokenRef::SymbolId(self.sid())
+    }
+}
+
 impl AsRawSymbolTokenRef for String {
     fn as_raw_symbol_token_ref(&self) -> RawSymbolTokenRef {
         RawSymbolTokenRef::Text(Cow::from(self.as_str()))
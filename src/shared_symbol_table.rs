@@ -1,7 +1,8 @@
 use crate::element::Element;
 use crate::result::IonFailure;
-use crate::{ion_seq, IonResult};
+use crate::{ion_seq, ion_struct, Format, IonResult, IonType, List, Sequence};
 use crate::{Int, IonError, Symbol};
+use std::io;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Stores [`SharedSymbolTable`] with the table name, version and imports
@@ -117,11 +118,77 @@ impl TryFrom<Element> for SharedSymbolTable {
     }
 }
 
+/// Writes shared symbol tables in their serialized form: a struct annotated with
+/// `$ion_shared_symbol_table` containing `name`, `version`, and `symbols` fields. Readers compute
+/// the table's `max_id` from the length of the `symbols` list, so no `max_id` field is written.
+///
+/// Each table is written as its own Ion stream, so the output of several calls to
+/// [`SharedSymbolTableWriter::write`] can be read back with [`Element::read_all`].
+pub struct SharedSymbolTableWriter<W: io::Write> {
+    format: Format,
+    output: W,
+}
+
+impl<W: io::Write> SharedSymbolTableWriter<W> {
+    pub fn new(format: Format, output: W) -> Self {
+        Self { format, output }
+    }
+
+    /// Writes a shared symbol table with the provided `name`, `version`, and `symbols`. Symbols
+    /// with unknown text are written as `null.string`, which readers treat as a gap in the table.
+    ///
+    /// As per the Ion specification, `name` must be a non-empty string and `version` must be a
+    /// positive integer; if either is not, an error is returned and nothing is written.
+    pub fn write(&mut self, name: &str, version: usize, symbols: &[Symbol]) -> IonResult<()> {
+        Self::to_element(name, version, symbols)?.write_as(self.format, &mut self.output)
+    }
+
+    /// Writes the provided [`SharedSymbolTable`]. See [`SharedSymbolTableWriter::write`].
+    pub fn write_table(&mut self, table: &SharedSymbolTable) -> IonResult<()> {
+        self.write(table.name(), table.version(), table.symbols())
+    }
+
+    /// Consumes the writer, returning the underlying output.
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+
+    fn to_element(name: &str, version: usize, symbols: &[Symbol]) -> IonResult<Element> {
+        if name.is_empty() {
+            return IonResult::illegal_operation(
+                "shared symbol table with empty name is not allowed",
+            );
+        }
+        if version < 1 {
+            return IonResult::illegal_operation(format!(
+                "shared symbol table version must be a positive integer, found {version}"
+            ));
+        }
+        let version = i64::try_from(version).map_err(|_| {
+            IonError::illegal_operation("shared symbol table version was too large to write")
+        })?;
+        let symbols: Vec<Element> = symbols
+            .iter()
+            .map(|symbol| match symbol.text() {
+                Some(text) => Element::string(text),
+                None => Element::null(IonType::String),
+            })
+            .collect();
+        let sst_struct = ion_struct! {
+            "name": name,
+            "version": version,
+            "symbols": List::from(Sequence::new(symbols)),
+        };
+        Ok(Element::from(sst_struct).with_annotations(["$ion_shared_symbol_table"]))
+    }
+}
+
 #[cfg(test)]
 mod shared_symbol_table_tests {
+    use crate::catalog::{Catalog, MapCatalog};
     use crate::element::Element;
-    use crate::shared_symbol_table::SharedSymbolTable;
-    use crate::{IonResult, Symbol};
+    use crate::shared_symbol_table::{SharedSymbolTable, SharedSymbolTableWriter};
+    use crate::{Format, IonResult, Symbol, TextKind};
 
     #[test]
     fn shared_symbol_table_read_test() -> IonResult<()> {
@@ -147,4 +214,45 @@ mod shared_symbol_table_tests {
         assert_eq!(sst.symbols()[2], Symbol::owned("foe"));
         Ok(())
     }
+
+    #[test]
+    fn shared_symbol_table_write_round_trip() -> IonResult<()> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(SharedSymbolTable::new("T", 1, ["fee", "fie"])?);
+        catalog.insert_table(SharedSymbolTable::new(
+            "T",
+            2,
+            [
+                Symbol::owned("fee"),
+                Symbol::unknown_text(),
+                Symbol::owned("foe"),
+            ],
+        )?);
+
+        for format in [Format::Binary, Format::Text(TextKind::Compact)] {
+            let mut writer = SharedSymbolTableWriter::new(format, Vec::new());
+            for version in [1, 2] {
+                writer.write_table(catalog.get_table_with_version("T", version).unwrap())?;
+            }
+            let elements = Element::read_all(writer.into_inner())?;
+            assert_eq!(elements.len(), 2);
+            for element in elements {
+                assert!(element.annotations().contains("$ion_shared_symbol_table"));
+                let table: SharedSymbolTable = element.try_into()?;
+                let expected = catalog
+                    .get_table_with_version(table.name(), table.version())
+                    .unwrap();
+                assert_eq!(&table, expected);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_write_validates_name_and_version() {
+        let mut writer = SharedSymbolTableWriter::new(Format::Binary, Vec::new());
+        assert!(writer.write("", 1, &[]).is_err());
+        assert!(writer.write("T", 0, &[]).is_err());
+        assert!(writer.into_inner().is_empty());
+    }
 }
@@ -23,6 +23,11 @@ impl<'a> SymbolRef<'a> {
         self.text.as_ref().map(|t| t.as_ref())
     }
 
+    /// Returns `true` if this symbol's text is unknown (as is the case for `$0`).
+    pub fn is_unknown(&self) -> bool {
+        self.text.is_none()
+    }
+
     /// Constructs a `SymbolRef` with unknown text.
     pub fn with_unknown_text() -> Self {
         SymbolRef { text: None }
@@ -158,12 +163,14 @@ mod tests {
     fn symbol_ref_with_text() {
         let symbol_ref = SymbolRef::with_text("foo");
         assert_eq!(Some("foo"), symbol_ref.text());
+        assert!(!symbol_ref.is_unknown());
     }
 
     #[test]
     fn symbol_ref_with_unknown_text() {
         let symbol_ref = SymbolRef::with_unknown_text();
         assert_eq!(None, symbol_ref.text());
+        assert!(symbol_ref.is_unknown());
     }
 
     #[test]
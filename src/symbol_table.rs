@@ -7,7 +7,7 @@ use crate::{Symbol, SymbolId};
 /// Stores mappings from Symbol IDs to text and vice-versa.
 // SymbolTable instances always have at least system symbols; they are never empty.
 #[allow(clippy::len_without_is_empty)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SymbolTable {
     symbols_by_id: Vec<Symbol>,
     ids_by_text: HashMap<Symbol, SymbolId>,
@@ -21,7 +21,7 @@ impl Default for SymbolTable {
 
 impl SymbolTable {
     /// Constructs a new symbol table pre-populated with the system symbols defined in the spec.
-    pub(crate) fn new() -> SymbolTable {
+    pub fn new() -> SymbolTable {
         let mut symbol_table = SymbolTable {
             symbols_by_id: Vec::with_capacity(v1_0::SYSTEM_SYMBOLS.len()),
             ids_by_text: HashMap::new(),
@@ -30,6 +30,20 @@ impl SymbolTable {
         symbol_table
     }
 
+    /// Constructs a new symbol table pre-populated with the system symbols followed by each of
+    /// the provided `symbols` in order.
+    ///
+    /// The system symbols occupy SIDs 1 through 9 (SID 0 is reserved for symbols with unknown
+    /// text), so the first user symbol is assigned SID 10. As with [`SymbolTable::intern`], text
+    /// that is already present in the table is not assigned a new SID.
+    pub fn from_symbols<A: AsRef<str>>(symbols: &[A]) -> SymbolTable {
+        let mut symbol_table = SymbolTable::new();
+        for symbol in symbols {
+            symbol_table.intern(symbol);
+        }
+        symbol_table
+    }
+
     // Interns the v1.0 system symbols
     pub(crate) fn initialize(&mut self) {
         for &text in v1_0::SYSTEM_SYMBOLS.iter() {
@@ -45,7 +59,7 @@ impl SymbolTable {
 
     /// If `text` is already in the symbol table, returns the corresponding [SymbolId].
     /// Otherwise, adds `text` to the symbol table and returns the newly assigned [SymbolId].
-    pub(crate) fn intern<A: AsRef<str>>(&mut self, text: A) -> SymbolId {
+    pub fn intern<A: AsRef<str>>(&mut self, text: A) -> SymbolId {
         let text = text.as_ref();
         // If the text is already in the symbol table, return the ID associated with it.
         if let Some(id) = self.ids_by_text.get(text) {
@@ -61,6 +75,41 @@ impl SymbolTable {
         id
     }
 
+    /// Interns each of the provided `texts` in order, returning the [SymbolId] associated with each.
+    /// Text that is already in the symbol table reuses its existing [SymbolId].
+    pub fn intern_all(
+        &mut self,
+        texts: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Vec<SymbolId> {
+        texts
+            .into_iter()
+            .map(|text| self.intern(text.into()))
+            .collect()
+    }
+
+    /// Adds each of the symbols in `other` to this table, returning a mapping from each of
+    /// `other`'s symbol IDs to the corresponding [SymbolId] in this table. That is, the symbol at
+    /// `other`'s SID `n` can be found in this table at SID `merge(other)[n]`.
+    ///
+    /// Text that is already in this table reuses its existing [SymbolId]; new text is interned.
+    /// SID 0 always maps to SID 0. Each other symbol in `other` with unknown text is assigned
+    /// a new placeholder SID, since its text cannot be shown to match any existing entry.
+    ///
+    /// This is useful when concatenating data encoded with independent symbol tables, as the
+    /// mapping can be used to rewrite the symbol IDs found in `other`'s data.
+    pub fn merge(&mut self, other: &SymbolTable) -> Vec<SymbolId> {
+        other
+            .symbols_by_id
+            .iter()
+            .enumerate()
+            .map(|(sid, symbol)| match symbol.text() {
+                Some(text) => self.intern(text),
+                None if sid == 0 => 0,
+                None => self.add_placeholder(),
+            })
+            .collect()
+    }
+
     /// Assigns unknown text to the next available symbol ID. This is used when an Ion reader
     /// encounters null or non-string values in a stream's symbol table.
     pub(crate) fn add_placeholder(&mut self) -> SymbolId {
@@ -81,6 +130,32 @@ impl SymbolTable {
         }
     }
 
+    /// Returns a copy of this table in which each symbol ID whose text is unknown or not defined
+    /// has been resolved using `fallback`, if possible. Symbol IDs that this table defines with
+    /// known text are unaffected; the result is as long as the longer of the two tables.
+    pub(crate) fn with_fallback(&self, fallback: &SymbolTable) -> SymbolTable {
+        let len = self.len().max(fallback.len());
+        let mut resolved = SymbolTable {
+            symbols_by_id: Vec::with_capacity(len),
+            ids_by_text: HashMap::new(),
+        };
+        for sid in 0..len {
+            let symbol = match self.symbols_by_id.get(sid) {
+                Some(symbol) if symbol.text().is_some() => symbol.clone(),
+                _ => fallback
+                    .symbols_by_id
+                    .get(sid)
+                    .cloned()
+                    .unwrap_or_else(Symbol::unknown_text),
+            };
+            if symbol.text().is_some() {
+                resolved.ids_by_text.entry(symbol.clone()).or_insert(sid);
+            }
+            resolved.symbols_by_id.push(symbol);
+        }
+        resolved
+    }
+
     /// If defined, returns the Symbol ID associated with the provided text.
     pub fn sid_for<A: AsRef<str>>(&self, text: &A) -> Option<SymbolId> {
         self.ids_by_text.get(text.as_ref()).copied()
@@ -137,3 +212,44 @@ impl SymbolTable {
         self.symbols_by_id.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_symbols() {
+        let symbol_table = SymbolTable::from_symbols(&["a", "b", "c"]);
+        assert_eq!(symbol_table.len(), v1_0::SYSTEM_SYMBOLS.len() + 3);
+        assert_eq!(symbol_table.sid_for(&"a"), Some(10));
+        assert_eq!(symbol_table.sid_for(&"b"), Some(11));
+        assert_eq!(symbol_table.text_for(12), Some("c"));
+        assert_eq!(symbol_table.sid_for(&"name"), Some(4));
+    }
+
+    #[test]
+    fn intern_all() {
+        let mut symbol_table = SymbolTable::from_symbols(&["a"]);
+        let sids = symbol_table.intern_all(["b", "a", "name", "c", "b"]);
+        assert_eq!(sids, vec![11, 10, 4, 12, 11]);
+        assert_eq!(symbol_table.len(), v1_0::SYSTEM_SYMBOLS.len() + 3);
+    }
+
+    #[test]
+    fn merge() {
+        let mut symbol_table = SymbolTable::from_symbols(&["a", "b"]);
+        let mut other = SymbolTable::from_symbols(&["c", "a"]);
+        other.add_placeholder();
+        other.intern("d");
+        let mapping = symbol_table.merge(&other);
+        assert_eq!(mapping.len(), other.len());
+        // System symbols keep their SIDs
+        assert_eq!(mapping[..10], (0..10).collect::<Vec<_>>()[..]);
+        assert_eq!(mapping[10..], [12, 10, 13, 14]);
+        assert_eq!(symbol_table.text_for(12), Some("c"));
+        assert!(symbol_table.sid_is_valid(13));
+        assert_eq!(symbol_table.text_for(13), None);
+        assert_eq!(symbol_table.text_for(14), Some("d"));
+        assert_eq!(symbol_table.len(), 15);
+    }
+}
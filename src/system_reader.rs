@@ -13,6 +13,11 @@ use crate::system_reader::LstPosition::*;
 use crate::IonType;
 use crate::{Blob, Clob, Decimal, Int, Str, Symbol, Timestamp};
 
+// The largest `max_id` an import may declare beyond the size of the shared table it refers to.
+// Symbol IDs past the end of the shared table are materialized as unknown text, so an unbounded
+// `max_id` would let a tiny stream allocate an arbitrary amount of memory.
+const MAX_IMPORT_MAX_ID: usize = 1 << 20;
+
 /// Tracks where the [SystemReader] is in the process of reading a local symbol table.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum LstPosition {
@@ -115,7 +120,7 @@ struct LstData {
     imported_symbols: Vec<Option<String>>,
     // Current import related information that will be used when processing shared symbol table from catalog
     current_import_name: Option<String>,
-    current_import_version: Option<usize>,
+    current_import_version: Option<i64>,
     current_import_max_id: Option<usize>,
     // Whether we've already encountered a `symbols` field.
     has_found_symbols: bool,
@@ -183,6 +188,50 @@ impl<R: RawReader> SystemReader<R> {
         }
     }
 
+    // Adds the symbols from the shared symbol table described by the import that was just read
+    // to the pending imported symbols, then clears the import information.
+    //
+    // Per the spec, an import without a valid `version` refers to version 1 of the named table,
+    // and an import without a valid `max_id` imports all of the table's symbols. If `max_id` is
+    // smaller than the table, only the first `max_id` symbols are imported; if it is larger, the
+    // remaining symbol IDs are assigned unknown text. Because those unknown-text slots are
+    // materialized, a `max_id` that would pad the table past `MAX_IMPORT_MAX_ID` is rejected.
+    fn import_shared_symbol_table(&mut self) -> IonResult<()> {
+        // Imports without a name are ignored.
+        let Some(import_name) = self.lst.current_import_name.take() else {
+            return Ok(());
+        };
+        let version = match self.lst.current_import_version.take() {
+            Some(version) if version >= 1 => version as usize,
+            _ => 1,
+        };
+        let max_id = self.lst.current_import_max_id.take();
+        // TODO: add a fallback mechanism that always returns a shared symbol table i.e. provides dummy table when it doesn't exist.
+        let sst = self
+            .catalog
+            .get_table_with_version(&import_name, version)
+            .ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "symbol table with name {import_name} doesn't exist for version {version}"
+                ))
+            })?;
+        let symbols = sst.symbols();
+        let max_id = max_id.unwrap_or(symbols.len());
+        if max_id > symbols.len() && max_id > MAX_IMPORT_MAX_ID {
+            return IonResult::decoding_error(format!(
+                "symbol table import max_id {max_id} exceeds the supported maximum ({MAX_IMPORT_MAX_ID})"
+            ));
+        }
+        let imported_symbols = &mut self.lst.imported_symbols;
+        for sym in symbols.iter().take(max_id) {
+            imported_symbols.push(sym.text().map(|s| s.to_string()))
+        }
+        for _ in symbols.len()..max_id {
+            imported_symbols.push(None);
+        }
+        Ok(())
+    }
+
     // Returns true if the raw reader is positioned over a top-level struct whose first annotation
     // is $ion_symbol_table.
     fn current_value_is_symbol_table(&self) -> IonResult<bool> {
@@ -242,21 +291,22 @@ impl<R: RawReader> SystemReader<R> {
             }
             AtImportName => {
                 // We're inside a shared symbol table import processing `name`, otherwise do nothing for open content.
-                if let Some(IonType::String) = self.raw_reader.ion_type() {
+                if self.raw_reader.ion_type() == Some(IonType::String) && !self.raw_reader.is_null()
+                {
                     self.lst.current_import_name = Some(self.raw_reader.read_str()?.to_string());
                 }
                 self.lst.state = BetweenLstImportFields;
             }
             AtImportVersion => {
                 // We're inside a shared symbol table import processing either `version` otherwise do nothing for open content.
-                if let Some(IonType::Int) = self.raw_reader.ion_type() {
-                    self.lst.current_import_version = Some(self.raw_reader.read_i64()? as usize);
+                if self.raw_reader.ion_type() == Some(IonType::Int) && !self.raw_reader.is_null() {
+                    self.lst.current_import_version = Some(self.raw_reader.read_i64()?);
                 }
                 self.lst.state = BetweenLstImportFields;
             }
             AtImportMaxId => {
                 // We're inside a shared symbol table import processing either `max_id` otherwise do nothing for open content.
-                if let Some(IonType::Int) = self.raw_reader.ion_type() {
+                if self.raw_reader.ion_type() == Some(IonType::Int) && !self.raw_reader.is_null() {
                     let max_id = self.raw_reader.read_i64()?;
                     if max_id < 0 {
                         return IonResult::decoding_error(
@@ -265,6 +315,7 @@ impl<R: RawReader> SystemReader<R> {
                     }
                     self.lst.current_import_max_id = Some(max_id as usize);
                 }
+                self.lst.state = BetweenLstImportFields;
             }
             _ => {
                 // Allow values at depths > 1 to be skipped.
@@ -674,28 +725,7 @@ impl<R: RawReader> IonReader for SystemReader<R> {
                     new_lst_state = ProcessingLstImports;
                 }
                 // Finish processing LST shared symbol table import by retrieving shared symbol table from catalog.
-                if self.lst.current_import_name.is_some()
-                    && self.lst.current_import_version.is_some()
-                {
-                    let catalog = self.catalog.as_ref();
-                    let version = self.lst.current_import_version.unwrap();
-                    let import_name = self.lst.current_import_name.clone().unwrap();
-                    // TODO: add a fallback mechanism that always returns a shared symbol table i.e. provides dummy table when it doesn't exist.
-                    let sst = catalog
-                        .get_table_with_version(&import_name, version)
-                        .ok_or(IonError::decoding_error(format!(
-                        "symbol table with name {import_name} doesn't exist for version {version}"
-                    )))?;
-                    for sym in sst.symbols() {
-                        self.lst
-                            .imported_symbols
-                            .push(sym.text().map(|s| s.to_string()))
-                    }
-                    // Clear all the import information once it is processed.
-                    self.lst.current_import_name = None;
-                    self.lst.current_import_max_id = None;
-                    self.lst.current_import_version = None;
-                }
+                self.import_shared_symbol_table()?;
             }
             ProcessingLstImports => {
                 // We're inside one of the LST fields. Finish processing the current level before
@@ -712,30 +742,7 @@ impl<R: RawReader> IonReader for SystemReader<R> {
                 // stepping out.
                 self.finish_reading_current_level()?;
                 // Finish processing LST shared symbol table import by retrieving shared symbol table from catalog.
-                if self.lst.current_import_name.is_some()
-                    && self.lst.current_import_version.is_some()
-                {
-                    let catalog = self.catalog.as_ref();
-                    let import_name = self.lst.current_import_name.clone().unwrap();
-                    // TODO: add a fallback mechanism that always returns a shared symbol table i.e. provides dummy table when it doesn't exist.
-                    let sst = catalog
-                        .get_table_with_version(
-                            &import_name,
-                            self.lst.current_import_version.unwrap(),
-                        )
-                        .ok_or(IonError::illegal_operation(format!(
-                            "symbol table with name: {import_name} does not exist"
-                        )))?;
-                    for sym in sst.symbols() {
-                        self.lst
-                            .imported_symbols
-                            .push(sym.text().map(|s| s.to_string()))
-                    }
-                    // Clear all the import information once it is processed.
-                    self.lst.current_import_name = None;
-                    self.lst.current_import_max_id = None;
-                    self.lst.current_import_version = None;
-                }
+                self.import_shared_symbol_table()?;
                 if self.depth() == 3 {
                     new_lst_state = ProcessingLstImports;
                 }
@@ -1077,6 +1084,91 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn shared_symbol_table_import_defaults() -> IonResult<()> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("table_a", 1, ["a1", "a2"])?);
+        map_catalog.insert_table(SharedSymbolTable::new("table_a", 2, ["a3"])?);
+        map_catalog.insert_table(SharedSymbolTable::new("table_b", 1, ["b1", "b2"])?);
+        map_catalog.insert_table(SharedSymbolTable::new("table_c", 1, ["c1", "c2"])?);
+        map_catalog.insert_table(SharedSymbolTable::new("table_d", 1, ["d1", "d2"])?);
+        // A missing or invalid `version` refers to version 1. A missing or non-int `max_id`
+        // imports the whole table; an explicit `max_id` truncates or pads the imported symbols.
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+            $ion_symbol_table::{
+                imports: [
+                    { name: "table_a" },
+                    { name: "table_b", max_id: null.int },
+                    { max_id: 1, name: "table_c" },
+                    { name: "table_d", version: 0, max_id: 3 },
+                ],
+                symbols: [ "local_symbol" ]
+            }
+            $10 $11 $12 $13 $14 $15 $16 $17 $18 $19
+          "#,
+            Box::new(map_catalog),
+        );
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Struct));
+        for expected in [
+            Some("a1"),
+            Some("a2"),
+            Some("b1"),
+            Some("b2"),
+            Some("c1"),
+            Some("d1"),
+            Some("d2"),
+            None,
+            Some("local_symbol"),
+        ] {
+            assert_eq!(reader.next()?, Value(IonType::Symbol));
+            assert_eq!(reader.read_symbol()?.text(), expected);
+        }
+        // $19 is out of range
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert!(reader.read_symbol().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_import_negative_version() -> IonResult<()> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("shared_table", 1, ["foo"])?);
+        // A negative `version` is invalid and refers to version 1.
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+            $ion_symbol_table::{
+                imports: [ { name: "shared_table", version: -1 } ],
+            }
+            $10 // "foo"
+          "#,
+            Box::new(map_catalog),
+        );
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Struct));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, "foo");
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_import_huge_max_id() -> IonResult<()> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("shared_table", 1, ["foo"])?);
+        // A `max_id` this large would pad the symbol table with a trillion unknown-text symbols.
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+            $ion_symbol_table::{
+                imports: [ { name: "shared_table", version: 1, max_id: 1000000000000 } ],
+            }
+            $10
+          "#,
+            Box::new(map_catalog),
+        );
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Struct));
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
     #[test]
     fn non_existing_shared_symbol_table_imports() -> IonResult<()> {
         let mut map_catalog = MapCatalog::new();
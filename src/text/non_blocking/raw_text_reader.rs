@@ -139,9 +139,11 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
         if self.parents.is_empty() {
             // The `parents` stack is empty. We're at the top level.
 
-            // If the reader has already found EOF (the end of the top level), there's no need to
-            // try to read more data. Return Ok(None).
-            if self.is_eof {
+            // If the reader has already found EOF (the end of the top level) and the stream is
+            // complete, there's no need to try to read more data. Return Ok(None).
+            // `is_eof` is also set when the buffer runs dry before the stream has been marked
+            // complete; that only means more data is needed, so the buffer must be parsed again.
+            if self.is_eof && self.is_eos {
                 self.current_value = None;
                 return Ok(());
             }
@@ -655,6 +657,9 @@ impl BufferedRawReader for RawTextReader<Vec<u8>> {
     // into the reader.
     fn stream_complete(&mut self) {
         self.is_eos = true;
+        // Any EOF found before now only meant that the buffer needed more data. Now that we know
+        // there isn't any, the buffer's remaining contents need to be parsed again.
+        self.is_eof = false;
     }
 
     // Returns true if the stream has been marked as completely loaded via `stream_complete`.
@@ -1025,6 +1030,36 @@ mod reader_tests {
         Ok(())
     }
 
+    #[test]
+    fn incomplete_top_level_value_is_not_end_of_stream() -> IonResult<()> {
+        // `123` could be the beginning of a longer number, so the reader can't return it until
+        // it knows that no more data is coming.
+        let mut reader = RawTextReader::new(b"123".to_vec());
+        assert!(matches!(reader.next(), Err(IonError::Incomplete { .. })));
+        // Asking again without providing more data must still report that the input is
+        // incomplete rather than claiming that the stream has ended.
+        assert!(matches!(reader.next(), Err(IonError::Incomplete { .. })));
+        reader.append_bytes(b"4")?;
+        reader.stream_complete();
+        next_type(&mut reader, IonType::Int, false);
+        assert_eq!(reader.read_i64()?, 1234);
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_complete_releases_held_back_top_level_value() -> IonResult<()> {
+        let mut reader = RawTextReader::new(b"123".to_vec());
+        assert!(matches!(reader.next(), Err(IonError::Incomplete { .. })));
+        // Once the stream is marked complete, the buffered `123` is known to be the whole value.
+        reader.stream_complete();
+        next_type(&mut reader, IonType::Int, false);
+        assert_eq!(reader.read_i64()?, 123);
+        assert_eq!(reader.next()?, Nothing);
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
     #[test]
     fn test_skipping_containers() -> IonResult<()> {
         let ion_data = r#"
@@ -1,7 +1,9 @@
 use std::io::{BufWriter, Write};
 
 use chrono::{DateTime, FixedOffset};
+use num_bigint::BigInt;
 
+use crate::decimal::coefficient::Coefficient;
 use crate::ion_writer::IonWriter;
 use crate::raw_symbol_token::RawSymbolToken;
 use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
@@ -12,6 +14,7 @@ use crate::{Decimal, Int, IonType, TextKind, Timestamp};
 
 pub struct RawTextWriterBuilder {
     whitespace_config: WhitespaceConfig,
+    ints_as_decimals: bool,
 }
 
 impl RawTextWriterBuilder {
@@ -33,6 +36,7 @@ impl RawTextWriterBuilder {
     pub fn compact() -> RawTextWriterBuilder {
         RawTextWriterBuilder {
             whitespace_config: COMPACT_WHITESPACE_CONFIG.clone(),
+            ints_as_decimals: false,
         }
     }
 
@@ -51,6 +55,7 @@ impl RawTextWriterBuilder {
     pub fn lines() -> RawTextWriterBuilder {
         RawTextWriterBuilder {
             whitespace_config: LINES_WHITESPACE_CONFIG.clone(),
+            ints_as_decimals: false,
         }
     }
 
@@ -74,6 +79,7 @@ impl RawTextWriterBuilder {
     pub fn pretty() -> RawTextWriterBuilder {
         RawTextWriterBuilder {
             whitespace_config: PRETTY_WHITESPACE_CONFIG.clone(),
+            ints_as_decimals: false,
         }
     }
 
@@ -114,6 +120,15 @@ impl RawTextWriterBuilder {
         self
     }
 
+    /// If `ints_as_decimals` is `true`, integers passed to `write_i64` and `write_int` will be
+    /// written as decimals with an exponent of `0` (for example: `7.` instead of `7`). This is
+    /// useful when the output is destined for a consumer that treats all numbers uniformly.
+    /// Defaults to `false`.
+    pub fn with_ints_as_decimals(mut self, ints_as_decimals: bool) -> RawTextWriterBuilder {
+        self.ints_as_decimals = ints_as_decimals;
+        self
+    }
+
     /// Constructs a new instance of [RawTextWriter] that writes values to the provided io::Write
     /// implementation.
     pub fn build<W: Write>(self, sink: W) -> IonResult<RawTextWriter<W>> {
@@ -126,6 +141,7 @@ impl RawTextWriterBuilder {
             // semantically whitespace?
             //TODO: https://github.com/amazon-ion/ion-rust/issues/438
             whitespace_config: Box::new(self.whitespace_config),
+            ints_as_decimals: self.ints_as_decimals,
         };
         // This method cannot currently fail. It returns an IonResult<_> to be consistent with the
         // other builder APIs and to allow for fallible setup operations in the future.
@@ -198,6 +214,8 @@ pub struct RawTextWriter<W: Write> {
     field_name: Option<RawSymbolToken>,
     containers: Vec<EncodingLevel>,
     whitespace_config: Box<WhitespaceConfig>,
+    // If true, integers are written as decimals with an exponent of zero.
+    ints_as_decimals: bool,
 }
 
 impl<W: Write> RawTextWriter<W> {
@@ -313,6 +331,33 @@ impl<W: Write> RawTextWriter<W> {
         first == '$' && chars.all(|c| c.is_numeric())
     }
 
+    /// Returns `true` if the provided text is an operator symbol: a sequence of one or more of
+    /// the characters `!#%&*+-./;<=>?@^`|~`. Inside an s-expression, operators can be written
+    /// without quotes. Operators containing `//` or `/*` would be read as the start of a comment,
+    /// so they are not considered operators for the purposes of writing.
+    pub(crate) fn token_is_operator(token: &str) -> bool {
+        const OPERATOR_CHARS: &str = "!#%&*+-./;<=>?@^`|~";
+        !token.is_empty()
+            && token.chars().all(|c| OPERATOR_CHARS.contains(c))
+            && !token.contains("//")
+            && !token.contains("/*")
+    }
+
+    /// Like [`Self::write_symbol_token`], but writes operator symbols (`+`, `<=`, etc.) without
+    /// quotes. This is only legal inside an s-expression.
+    pub(crate) fn write_sexp_symbol_token<O: Write, A: AsRawSymbolTokenRef>(
+        output: &mut O,
+        token: A,
+    ) -> IonResult<()> {
+        match token.as_raw_symbol_token_ref() {
+            RawSymbolTokenRef::Text(text) if Self::token_is_operator(text.as_ref()) => {
+                write!(output, "{text}")?;
+                Ok(())
+            }
+            token => Self::write_symbol_token(output, token),
+        }
+    }
+
     pub(crate) fn write_symbol_token<O: Write, A: AsRawSymbolTokenRef>(
         output: &mut O,
         token: A,
@@ -427,6 +472,12 @@ impl<W: Write> RawTextWriter<W> {
                 '\x08' => r"\b", // backspace
                 '\x0B' => r"\v", // vertical tab
                 '\x0C' => r"\f", // form feed
+                c if c.is_ascii_control() => {
+                    // Remaining control characters don't have a short escape; use `\xHH`.
+                    write!(output, "{}\\x{:02x}", &text[start..byte_index], c as u8)?;
+                    start = byte_index + character.len_utf8();
+                    continue;
+                }
                 _ => {
                     // Other characters can be left as-is
                     continue;
@@ -510,6 +561,9 @@ impl<W: Write> IonWriter for RawTextWriter<W> {
 
     /// Writes the provided i64 value as an Ion integer.
     fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        if self.ints_as_decimals {
+            return self.write_decimal(&Decimal::new(value, 0));
+        }
         self.write_scalar(|output| {
             write!(output, "{value}")?;
             Ok(())
@@ -518,6 +572,10 @@ impl<W: Write> IonWriter for RawTextWriter<W> {
 
     /// Writes an Ion `integer` with the specified value to the output stream.
     fn write_int(&mut self, value: &Int) -> IonResult<()> {
+        if self.ints_as_decimals {
+            let coefficient: Coefficient = BigInt::from(value.clone()).try_into()?;
+            return self.write_decimal(&Decimal::new(coefficient, 0));
+        }
         self.write_scalar(|output| {
             write!(output, "{value}")?;
             Ok(())
@@ -740,12 +798,13 @@ mod tests {
     use std::str;
 
     use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
 
     use crate::ion_writer::IonWriter;
     use crate::result::IonResult;
     use crate::text::raw_text_writer::{RawTextWriter, RawTextWriterBuilder};
     use crate::types::Timestamp;
-    use crate::IonType;
+    use crate::{Decimal, Int, IonType};
 
     fn writer_test_with_builder<F>(builder: RawTextWriterBuilder, mut commands: F, expected: &str)
     where
@@ -827,6 +886,21 @@ mod tests {
         write_scalar_test(|w| w.write_i64(7), "7");
     }
 
+    #[test]
+    fn write_ints_as_decimals() {
+        let builder = RawTextWriterBuilder::default().with_ints_as_decimals(true);
+        writer_test_with_builder(
+            builder,
+            |w| {
+                w.write_i64(7)?;
+                w.write_i64(-7)?;
+                w.write_int(&Int::from(BigInt::from(u64::MAX) * 2))?;
+                w.write_decimal(&Decimal::new(15, -1))
+            },
+            "7. -7. 3.6893488147419103230d19 1.5",
+        );
+    }
+
     #[test]
     fn write_f32() {
         write_scalar_test(|w| w.write_f32(700f32), "7e2");
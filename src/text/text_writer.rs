@@ -12,12 +12,16 @@ use std::io::Write;
 
 pub struct TextWriterBuilder {
     text_kind: TextKind,
+    ints_as_decimals: bool,
 }
 
 impl TextWriterBuilder {
     /// Constructs a text Ion writer with the specified formatting. See [`TextKind`] for details.
     pub fn new(format: TextKind) -> TextWriterBuilder {
-        TextWriterBuilder { text_kind: format }
+        TextWriterBuilder {
+            text_kind: format,
+            ints_as_decimals: false,
+        }
     }
 
     /// Constructs a text Ion writer that serializes data with modest (but not strictly minimal)
@@ -30,6 +34,7 @@ impl TextWriterBuilder {
     pub fn compact() -> TextWriterBuilder {
         TextWriterBuilder {
             text_kind: TextKind::Compact,
+            ints_as_decimals: false,
         }
     }
 
@@ -46,6 +51,7 @@ impl TextWriterBuilder {
     pub fn lines() -> TextWriterBuilder {
         TextWriterBuilder {
             text_kind: TextKind::Lines,
+            ints_as_decimals: false,
         }
     }
 
@@ -69,9 +75,17 @@ impl TextWriterBuilder {
     pub fn pretty() -> TextWriterBuilder {
         TextWriterBuilder {
             text_kind: TextKind::Pretty,
+            ints_as_decimals: false,
         }
     }
 
+    /// If `ints_as_decimals` is `true`, integers will be written as decimals with an exponent of
+    /// `0`. See [`RawTextWriterBuilder::with_ints_as_decimals`] for details. Defaults to `false`.
+    pub fn with_ints_as_decimals(mut self, ints_as_decimals: bool) -> TextWriterBuilder {
+        self.ints_as_decimals = ints_as_decimals;
+        self
+    }
+
     /// Constructs a new instance of TextWriter that writes values to the provided io::Write
     /// implementation.
     pub fn build<W: Write>(self, sink: W) -> IonResult<TextWriter<W>> {
@@ -80,7 +94,9 @@ impl TextWriterBuilder {
             TextKind::Pretty => RawTextWriterBuilder::pretty(),
             TextKind::Lines => RawTextWriterBuilder::lines(),
         };
-        let raw_writer = builder.build(sink)?;
+        let raw_writer = builder
+            .with_ints_as_decimals(self.ints_as_decimals)
+            .build(sink)?;
         let text_writer = TextWriter {
             raw_writer,
             symbol_table: SymbolTable::new(),
@@ -280,6 +280,49 @@ impl Decimal {
 
         ((d1_int - d2_int), (d1_frac - d2_frac))
     }
+
+    /// Creates the `Decimal` with the fewest significant digits that converts back to exactly
+    /// `value`. Unlike the `TryFrom<f64>` implementation, the precision of the result is
+    /// well-defined: `0.1f64` becomes `0.1` and `1.5e300` becomes `15d299`.
+    ///
+    /// Returns an error if `value` is NaN or infinite, as decimals cannot represent them.
+    pub(crate) fn shortest_from_f64(value: f64) -> IonResult<Decimal> {
+        if !value.is_finite() {
+            return IonResult::illegal_operation(format!(
+                "cannot represent f64 {value} as a decimal"
+            ));
+        }
+        // Rust's `LowerExp` formatting produces the shortest digit sequence that round-trips to
+        // the same f64, e.g. `-1.2345e-7`.
+        let formatted = format!("{value:e}");
+        let (mantissa, exponent) = formatted
+            .split_once('e')
+            .expect("LowerExp output always includes an exponent");
+        let mut exponent: i64 = exponent.parse().expect("LowerExp exponent is a valid i64");
+        let mantissa = mantissa.trim_start_matches('-');
+        let digits = match mantissa.split_once('.') {
+            Some((whole, fraction)) => {
+                exponent -= fraction.len() as i64;
+                format!("{whole}{fraction}")
+            }
+            None => mantissa.to_owned(),
+        };
+        // An f64 has at most 17 significant decimal digits, so the coefficient always fits in a u64.
+        let magnitude: u64 = digits.parse().expect("LowerExp digits are a valid u64");
+        if magnitude == 0 {
+            return Ok(if value.is_sign_negative() {
+                Decimal::negative_zero_with_exponent(exponent)
+            } else {
+                Decimal::new(0, exponent)
+            });
+        }
+        let sign = if value.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        Ok(Decimal::new(Coefficient::new(sign, magnitude), exponent))
+    }
 }
 
 impl PartialEq for Decimal {
@@ -752,6 +795,29 @@ mod decimal_tests {
         assert!(conversion_result.is_err());
     }
 
+    #[rstest]
+    #[case(0f64, Decimal::new(0, 0))]
+    #[case(f64::neg_zero(), Decimal::negative_zero())]
+    #[case(10f64, Decimal::new(1, 1))]
+    #[case(0.1f64, Decimal::new(1, -1))]
+    #[case(-1.5f64, Decimal::new(-15, -1))]
+    #[case(123.456f64, Decimal::new(123456, -3))]
+    #[case(1.5e300f64, Decimal::new(15, 299))]
+    #[case(-2.5e-300f64, Decimal::new(-25, -301))]
+    #[case(f64::MAX, Decimal::new(17976931348623157u64, 292))]
+    fn test_decimal_shortest_from_f64(#[case] value: f64, #[case] expected: Decimal) {
+        let actual = Decimal::shortest_from_f64(value).unwrap();
+        assert!(actual.ion_eq(&expected), "{actual} != {expected}");
+    }
+
+    #[rstest]
+    #[case::positive_infinity(f64::infinity())]
+    #[case::negative_infinity(f64::neg_infinity())]
+    #[case::nan(f64::nan())]
+    fn test_decimal_shortest_from_f64_err(#[case] value: f64) {
+        assert!(Decimal::shortest_from_f64(value).is_err());
+    }
+
     #[rstest]
     #[case(Decimal::new(-24601, -3), 3)]
     #[case(Decimal::new(u64::MAX, -5), 5)]
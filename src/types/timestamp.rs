@@ -600,6 +600,18 @@ impl Timestamp {
         self.date_time.into()
     }
 
+    /// If this [Timestamp] has a known offset, returns the same instant with an offset of
+    /// `+00:00`, preserving its precision. Otherwise, returns the [Timestamp] unchanged.
+    pub(crate) fn normalized_to_utc(self) -> Timestamp {
+        match self.offset {
+            Some(_) => Timestamp {
+                offset: Some(offset_east(0)),
+                ..self
+            },
+            None => self,
+        }
+    }
+
     /// Returns this Timestamp's fractional seconds in nanoseconds
     ///
     /// NOTE: This is a potentially lossy operation. A Timestamp with picoseconds would return a